@@ -0,0 +1,22 @@
+//! `bytes` crate feature module
+//!
+//! This module detects `bytes::Bytes` and `bytes::BytesMut`, which serde serializes
+//! as byte sequences, so they can be mapped like `Vec<u8>` when the "bytes" feature is enabled.
+
+/// Detects if a type name represents a `bytes` buffer
+pub fn is_bytes_type(type_name: &str) -> bool {
+    matches!(type_name, "Bytes" | "BytesMut")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes_detection() {
+        assert!(is_bytes_type("Bytes"));
+        assert!(is_bytes_type("BytesMut"));
+        assert!(!is_bytes_type("ByteBuf"));
+        assert!(!is_bytes_type("String"));
+    }
+}
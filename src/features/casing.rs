@@ -0,0 +1,136 @@
+//! The case conversions of `rename_all`, as serde applies them
+//!
+//! Field names are converted from snake_case and variant names from PascalCase, so the two
+//! have their own functions. Both are used with and without the "serde" feature, since the
+//! casing can also be set with `#[model_schema(rename_all = "...")]`.
+
+/// The rename_all rules serde accepts.
+pub const RENAME_ALL_RULES: [&str; 8] = [
+    "lowercase",
+    "UPPERCASE",
+    "PascalCase",
+    "camelCase",
+    "snake_case",
+    "SCREAMING_SNAKE_CASE",
+    "kebab-case",
+    "SCREAMING-KEBAB-CASE",
+];
+
+/// Applies a rename_all rule to a snake_case field name. Unknown rules leave it unchanged.
+pub fn apply_rename_all(field_name: &str, rename_all: &Option<String>) -> String {
+    match rename_all.as_deref() {
+        Some("camelCase") => {
+            let pascal = snake_to_pascal(field_name);
+            let mut chars = pascal.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+                None => pascal,
+            }
+        }
+        Some("PascalCase") => snake_to_pascal(field_name),
+        Some("SCREAMING_SNAKE_CASE") | Some("UPPERCASE") => field_name.to_ascii_uppercase(),
+        Some("kebab-case") => field_name.replace('_', "-"),
+        Some("SCREAMING-KEBAB-CASE") => field_name.to_ascii_uppercase().replace('_', "-"),
+        _ => field_name.to_string(),
+    }
+}
+
+/// Applies a rename_all rule to a PascalCase variant name. Unknown rules leave it unchanged.
+pub fn apply_variant_rename_all(variant_name: &str, rename_all: &Option<String>) -> String {
+    match rename_all.as_deref() {
+        Some("lowercase") => variant_name.to_ascii_lowercase(),
+        Some("UPPERCASE") => variant_name.to_ascii_uppercase(),
+        Some("camelCase") => {
+            let mut chars = variant_name.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        }
+        Some("snake_case") => pascal_to_snake(variant_name),
+        Some("SCREAMING_SNAKE_CASE") => pascal_to_snake(variant_name).to_ascii_uppercase(),
+        Some("kebab-case") => pascal_to_snake(variant_name).replace('_', "-"),
+        Some("SCREAMING-KEBAB-CASE") => pascal_to_snake(variant_name).to_ascii_uppercase().replace('_', "-"),
+        _ => variant_name.to_string(),
+    }
+}
+
+/// Converts snake_case to PascalCase
+fn snake_to_pascal(s: &str) -> String {
+    let mut result = String::new();
+    let mut capitalize_next = true;
+
+    for c in s.chars() {
+        if c == '_' {
+            capitalize_next = true;
+        } else if capitalize_next {
+            result.push(c.to_ascii_uppercase());
+            capitalize_next = false;
+        } else {
+            result.push(c);
+        }
+    }
+
+    result
+}
+
+/// Converts PascalCase to snake_case, starting a word at each uppercase letter
+fn pascal_to_snake(s: &str) -> String {
+    let mut result = String::new();
+
+    for (i, c) in s.chars().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            result.push('_');
+        }
+        result.push(c.to_ascii_lowercase());
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(name: &str) -> Option<String> {
+        Some(name.to_string())
+    }
+
+    #[test]
+    fn test_rename_all_transformations() {
+        // Test camelCase
+        assert_eq!(apply_rename_all("user_name", &rule("camelCase")), "userName");
+        assert_eq!(apply_rename_all("first_name", &rule("camelCase")), "firstName");
+
+        // Test PascalCase
+        assert_eq!(apply_rename_all("user_name", &rule("PascalCase")), "UserName");
+
+        // Test kebab-case
+        assert_eq!(apply_rename_all("user_name", &rule("kebab-case")), "user-name");
+
+        // Test the upper case rules
+        assert_eq!(apply_rename_all("user_name", &rule("SCREAMING_SNAKE_CASE")), "USER_NAME");
+        assert_eq!(apply_rename_all("user_name", &rule("SCREAMING-KEBAB-CASE")), "USER-NAME");
+        assert_eq!(apply_rename_all("user_name", &rule("UPPERCASE")), "USER_NAME");
+
+        // Test rules that keep snake_case field names as they are
+        assert_eq!(apply_rename_all("user_name", &rule("snake_case")), "user_name");
+        assert_eq!(apply_rename_all("user_name", &rule("lowercase")), "user_name");
+
+        // Test no transformation
+        assert_eq!(apply_rename_all("user_name", &None), "user_name");
+    }
+
+    #[test]
+    fn test_variant_rename_all_transformations() {
+        assert_eq!(apply_variant_rename_all("InProgress", &rule("camelCase")), "inProgress");
+        assert_eq!(apply_variant_rename_all("InProgress", &rule("PascalCase")), "InProgress");
+        assert_eq!(apply_variant_rename_all("InProgress", &rule("lowercase")), "inprogress");
+        assert_eq!(apply_variant_rename_all("InProgress", &rule("UPPERCASE")), "INPROGRESS");
+        assert_eq!(apply_variant_rename_all("InProgress", &rule("snake_case")), "in_progress");
+        assert_eq!(apply_variant_rename_all("InProgress", &rule("SCREAMING_SNAKE_CASE")), "IN_PROGRESS");
+        assert_eq!(apply_variant_rename_all("InProgress", &rule("kebab-case")), "in-progress");
+        assert_eq!(apply_variant_rename_all("InProgress", &rule("SCREAMING-KEBAB-CASE")), "IN-PROGRESS");
+        assert_eq!(apply_variant_rename_all("InProgress", &None), "InProgress");
+    }
+}
@@ -0,0 +1,81 @@
+//! CSV headers feature module
+//!
+//! This module generates the `csv_headers()` method when the "csv" feature is enabled: the
+//! serialized names of a struct's fields in declaration order, for the header row of a CSV
+//! export that matches the schemas.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::field_type::FieldDef;
+
+/// Whether a field's value fits in a single CSV cell. Nested types, maps, tuples and sequences
+/// don't, and neither does ObjectId, which serializes as a `{ "$oid": ... }` object.
+fn is_flat(fld: &FieldDef) -> bool {
+    #[cfg(feature = "object_id")]
+    if matches!(fld.field_type, crate::field_type::FieldDefType::ObjectId) {
+        return false;
+    }
+    !fld.is_array && fld.field_type.is_scalar()
+}
+
+/// Generates `csv_headers()` for a struct, listing its fields in declaration order. The
+/// method's docs name the fields that can't be flattened into a column.
+pub fn generate_csv_headers_method(fields: &[FieldDef]) -> TokenStream {
+    let names = fields.iter().map(|fld| &fld.name);
+    let nested = fields
+        .iter()
+        .filter(|fld| !is_flat(fld))
+        .map(|fld| format!("`{}`", fld.name))
+        .collect::<Vec<_>>();
+    let note = (!nested.is_empty()).then(|| {
+        let note = format!(
+            " Nested and collection fields can't be flattened into a single column: {}.",
+            nested.join(", ")
+        );
+        quote! {
+            ///
+            #[doc = #note]
+        }
+    });
+    quote! {
+        /// The serialized field names, in declaration order, for the header row of a CSV export.
+        #note
+        pub fn csv_headers() -> Vec<String> {
+            vec![#(#names.to_string()),*]
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field_type::FieldDefType;
+
+    fn field(name: &str, field_type: FieldDefType) -> FieldDef {
+        FieldDef {
+            is_optional: false,
+            name: name.to_string(),
+            docs: String::new(),
+            field_type,
+            is_array: false,
+            array_num: None,
+            model_schema_prop_meta: None,
+            is_non_zero: false,
+            is_set: false,
+            description: None,
+        }
+    }
+
+    #[test]
+    fn test_only_scalar_fields_are_flat() {
+        assert!(is_flat(&field("name", FieldDefType::String)));
+        assert!(is_flat(&FieldDef { is_optional: true, ..field("seats", FieldDefType::U32) }));
+        assert!(!is_flat(&FieldDef { is_array: true, ..field("tags", FieldDefType::String) }));
+        assert!(!is_flat(&field("venue", FieldDefType::SiblingType("Venue".to_string(), vec![], None))));
+        assert!(!is_flat(&field(
+            "prices",
+            FieldDefType::Map(Box::new(field("", FieldDefType::String)), Box::new(field("", FieldDefType::F64)))
+        )));
+    }
+}
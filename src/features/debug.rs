@@ -0,0 +1,132 @@
+//! Debug diagnostics module
+//!
+//! This module builds the field tree `#[model_schema(debug)]` generates as the `FIELD_TREE`
+//! constant: one line per field with the type the macro resolved it to, after `as` overrides, `extern_map` and serde's
+//! attributes, so an unexpected TypeScript type or schema can be traced back to its cause.
+
+use crate::field_type::{FieldDef, FieldDefType};
+
+/// Returns a line for each field of `container` describing its resolved type.
+pub fn field_tree_notes<'a>(container: &str, fields: impl IntoIterator<Item = &'a FieldDef>) -> Vec<String> {
+    fields
+        .into_iter()
+        .map(|fld| format!("`{container}` field `{}`: {}", fld.name, describe(fld)))
+        .collect()
+}
+
+/// Describes a field's resolved type, e.g. `optional array of sibling `Venue``.
+fn describe(fld: &FieldDef) -> String {
+    let mut description = match &fld.field_type {
+        FieldDefType::Unknown => "any JSON".to_string(),
+        FieldDefType::SiblingType(name, lst, module) => {
+            let mut sibling = format!("sibling `{name}`");
+            if !lst.is_empty() {
+                sibling = format!("{sibling} of <{}>", lst.iter().map(describe).collect::<Vec<_>>().join(", "));
+            }
+            if let Some(module) = module {
+                sibling = format!("{sibling} in `{}`", quote::quote!(#module).to_string().replace(' ', ""));
+            }
+            sibling
+        }
+        FieldDefType::Map(key, value) => format!("map of {} to {}", describe(key), describe(value)),
+        FieldDefType::Tuple(lst) => format!("tuple ({})", lst.iter().map(describe).collect::<Vec<_>>().join(", ")),
+        FieldDefType::Nested(inner) => describe(inner),
+        FieldDefType::Boolean => "bool".to_string(),
+        FieldDefType::String => "String".to_string(),
+        FieldDefType::Char => "char".to_string(),
+        FieldDefType::StringLiteral(literal) => format!("literal {literal:?}"),
+        FieldDefType::U8 => "u8".to_string(),
+        FieldDefType::U16 => "u16".to_string(),
+        FieldDefType::U32 => "u32".to_string(),
+        FieldDefType::U64 => "u64".to_string(),
+        FieldDefType::I8 => "i8".to_string(),
+        FieldDefType::I16 => "i16".to_string(),
+        FieldDefType::I32 => "i32".to_string(),
+        FieldDefType::I64 => "i64".to_string(),
+        FieldDefType::Usize => "usize".to_string(),
+        FieldDefType::Isize => "isize".to_string(),
+        FieldDefType::F32 => "f32".to_string(),
+        FieldDefType::F64 => "f64".to_string(),
+        FieldDefType::Number => "serde_json::Number".to_string(),
+        FieldDefType::FormattedString(format) => format!("{format:?} string"),
+        FieldDefType::External(extern_type) => format!("extern `{}`", extern_type.typescript),
+        #[cfg(feature = "object_id")]
+        FieldDefType::ObjectId => "ObjectId".to_string(),
+    };
+    if fld.is_non_zero {
+        description = format!("non-zero {description}");
+    }
+    if fld.is_array {
+        description = match fld.array_num {
+            Some(len) => format!("array of {len} {description}"),
+            None => format!("array of {description}"),
+        };
+    }
+    if fld.is_optional {
+        description = format!("optional {description}");
+    }
+    description
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(name: &str, field_type: FieldDefType) -> FieldDef {
+        FieldDef {
+            is_optional: false,
+            name: name.to_string(),
+            docs: String::new(),
+            field_type,
+            is_array: false,
+            array_num: None,
+            model_schema_prop_meta: None,
+            is_non_zero: false,
+            is_set: false,
+            description: None,
+        }
+    }
+
+    #[test]
+    fn test_lists_each_field_with_its_resolved_type() {
+        let fields = vec![
+            field("name", FieldDefType::String),
+            FieldDef { is_optional: true, ..field("seats", FieldDefType::U32) },
+            FieldDef { is_array: true, ..field("venues", FieldDefType::SiblingType("Venue".to_string(), vec![], None)) },
+            field(
+                "prices",
+                FieldDefType::Map(Box::new(field("", FieldDefType::String)), Box::new(field("", FieldDefType::F64))),
+            ),
+        ];
+
+        assert_eq!(
+            field_tree_notes("EventJson", &fields),
+            vec![
+                "`EventJson` field `name`: String",
+                "`EventJson` field `seats`: optional u32",
+                "`EventJson` field `venues`: array of sibling `Venue`",
+                "`EventJson` field `prices`: map of String to f64",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_describes_nested_and_qualified_types() {
+        let module: syn::Path = syn::parse_quote!(super::venues);
+        let row = FieldDef { is_array: true, ..field("", FieldDefType::U8) };
+        let fields = vec![
+            FieldDef { is_array: true, ..field("grid", FieldDefType::Nested(Box::new(row))) },
+            field("venue", FieldDefType::SiblingType("Venue".to_string(), vec![], Some(module))),
+            FieldDef { is_non_zero: true, ..field("capacity", FieldDefType::U16) },
+        ];
+
+        assert_eq!(
+            field_tree_notes("HallJson", &fields),
+            vec![
+                "`HallJson` field `grid`: array of array of u8",
+                "`HallJson` field `venue`: sibling `Venue` in `super::venues`",
+                "`HallJson` field `capacity`: non-zero u16",
+            ]
+        );
+    }
+}
@@ -0,0 +1,259 @@
+//! Fixtures feature module
+//!
+//! This module generates the `default_json()` method requested with
+//! `#[model_schema(derive_default_instance)]`: a skeleton instance of the type, built from
+//! zero values, for seeding examples, API docs and tests.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::field_type::{FieldDef, FieldDefType, sibling_json_type};
+#[cfg(feature = "serde")]
+use crate::field_type::VariantShape;
+
+/// Returns an expression building the zero value of a field: `""`, `0`, `false`, `[]` for
+/// sequences, `{}` for maps, and the sibling type's own `default_json()` for nested types.
+/// Constrained values start from their constraint instead: the first of a field's
+/// `enum_values`, its `ts_template` with empty placeholders, its `min` bound. A `pattern` can't
+/// be satisfied in general, so those strings stay empty.
+pub fn default_value(fld: &FieldDef) -> TokenStream {
+    if fld.is_array {
+        return quote! { serde_json::json!([]) };
+    }
+
+    match &fld.field_type {
+        // A `raw_json(parse)` field is a string, which has to hold valid JSON itself
+        FieldDefType::Unknown if fld.parses_raw_json() => quote! { serde_json::json!("null") },
+        FieldDefType::Unknown | FieldDefType::External(_) => quote! { serde_json::Value::Null },
+        FieldDefType::SiblingType(name, lst, module) if lst.is_empty() => {
+            let name_json = sibling_json_type(name, module);
+            quote! { #name_json::default_json() }
+        }
+        FieldDefType::SiblingType(..) | FieldDefType::Tuple(_) => unreachable!("checked by check_field_types"),
+        FieldDefType::Map(_, _) => quote! { serde_json::json!({}) },
+        FieldDefType::Nested(inner) => default_value(inner),
+        FieldDefType::Boolean => quote! { serde_json::json!(false) },
+        FieldDefType::String | FieldDefType::Char if fld.enum_values().is_some_and(|values| !values.is_empty()) => {
+            let first = &fld.enum_values().expect("checked above")[0];
+            quote! { serde_json::json!(#first) }
+        }
+        // A known format needs a value its validator accepts
+        FieldDefType::String => match (fld.string_format(), fld.ts_template()) {
+            (Some(format), _) => {
+                let placeholder = format.placeholder();
+                quote! { serde_json::json!(#placeholder) }
+            }
+            (None, Some(template)) => {
+                let value = template.replace("${string}", "").replace("${number}", "0");
+                quote! { serde_json::json!(#value) }
+            }
+            (None, None) => quote! { serde_json::json!("") },
+        },
+        // char::default()
+        FieldDefType::Char => quote! { serde_json::json!("\0") },
+        FieldDefType::StringLiteral(literal) => quote! { serde_json::json!(#literal) },
+        FieldDefType::U8
+        | FieldDefType::U16
+        | FieldDefType::U32
+        | FieldDefType::U64
+        | FieldDefType::I8
+        | FieldDefType::I16
+        | FieldDefType::I32
+        | FieldDefType::I64
+        | FieldDefType::Usize
+        | FieldDefType::Isize
+        | FieldDefType::Number
+        | FieldDefType::F32
+        | FieldDefType::F64 => numeric_default_value(fld),
+        FieldDefType::FormattedString(format) => {
+            let placeholder = format.placeholder();
+            quote! { serde_json::json!(#placeholder) }
+        }
+        #[cfg(feature = "object_id")]
+        FieldDefType::ObjectId => quote! { serde_json::json!({ "$oid": "000000000000000000000000" }) },
+    }
+}
+
+/// The lowest valid value of a numeric field: its `min` bound, zero, or one for `NonZero*`
+/// integers, or its `max` bound when that is below zero.
+fn numeric_default_value(fld: &FieldDef) -> TokenStream {
+    let is_float = matches!(fld.field_type, FieldDefType::F32 | FieldDefType::F64);
+    let zero = if is_float { "0.0" } else { "0" };
+    let value = fld
+        .numeric_minimum()
+        .or_else(|| fld.numeric_maximum().filter(|max| max.parse::<f64>().is_ok_and(|max| max < 0.0)))
+        .unwrap_or_else(|| zero.to_string());
+    // Zero isn't a valid NonZero* value
+    let value = if fld.is_non_zero && value.parse::<f64>().is_ok_and(|value| value == 0.0) { "1".to_string() } else { value };
+    // The bounds are validated number literals, e.g. `-10` or `99.5`
+    let literal: TokenStream = value.parse().unwrap_or_else(|err| panic!("invalid numeric bound `{value}`: {err}"));
+    quote! { serde_json::json!(#literal) }
+}
+
+/// Returns statements inserting the zero value of each required field into `object`.
+/// Optional fields are left out, as they may be absent.
+fn insert_default_fields(fields: &[FieldDef]) -> Vec<TokenStream> {
+    fields
+        .iter()
+        .filter(|fld| !fld.is_optional)
+        .map(|fld| {
+            let name = &fld.name;
+            let value = default_value(fld);
+            quote! { object.insert(#name.to_string(), #value); }
+        })
+        .collect()
+}
+
+/// Generates `default_json()` for a struct.
+pub fn generate_struct_default_json_method(fields: &[FieldDef]) -> TokenStream {
+    let inserts = insert_default_fields(fields);
+    quote! {
+        pub fn default_json() -> serde_json::Value {
+            let mut object = serde_json::Map::new();
+            #(#inserts)*
+            serde_json::Value::Object(object)
+        }
+    }
+}
+
+/// Generates `default_json()` for a plain enum, returning its first variant.
+pub fn generate_plain_enum_default_json_method(first_variant: Option<&String>) -> TokenStream {
+    let value = match first_variant {
+        Some(variant) => quote! { serde_json::json!(#variant) },
+        None => quote! { serde_json::Value::Null },
+    };
+    quote! {
+        pub fn default_json() -> serde_json::Value {
+            #value
+        }
+    }
+}
+
+/// Generates `default_json()` for a discriminated enum, returning its first variant with
+/// the tag set.
+pub fn generate_discriminated_enum_default_json_method(
+    tag_name: &str,
+    first_variant: Option<&(String, Vec<FieldDef>)>,
+) -> TokenStream {
+    let value = match first_variant {
+        Some((discriminator_value, fields)) => {
+            let inserts = insert_default_fields(fields);
+            quote! {
+                let mut object = serde_json::Map::new();
+                object.insert(#tag_name.to_string(), serde_json::json!(#discriminator_value));
+                #(#inserts)*
+                serde_json::Value::Object(object)
+            }
+        }
+        None => quote! { serde_json::Value::Null },
+    };
+    quote! {
+        pub fn default_json() -> serde_json::Value {
+            #value
+        }
+    }
+}
+
+/// Generates `default_json()` for a `#[serde(transparent)]` struct, its only field's value.
+#[cfg(feature = "serde")]
+pub fn generate_transparent_struct_default_json_method(inner: &FieldDef) -> TokenStream {
+    let value = default_value(inner);
+    quote! {
+        pub fn default_json() -> serde_json::Value {
+            #value
+        }
+    }
+}
+
+/// Generates `default_json()` for an untagged enum, returning its first variant's value.
+#[cfg(feature = "serde")]
+pub fn generate_untagged_enum_default_json_method(first_variant: Option<&VariantShape>) -> TokenStream {
+    let value = match first_variant {
+        Some(VariantShape::Newtype(payload)) => default_value(payload),
+        Some(VariantShape::Struct(fields)) => {
+            let inserts = insert_default_fields(fields);
+            quote! {
+                let mut object = serde_json::Map::new();
+                #(#inserts)*
+                serde_json::Value::Object(object)
+            }
+        }
+        Some(VariantShape::Unit) | None => quote! { serde_json::Value::Null },
+    };
+    quote! {
+        pub fn default_json() -> serde_json::Value {
+            #value
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::model_schema_prop::ModelSchemaPropMeta;
+
+    fn field(name: &str, field_type: FieldDefType) -> FieldDef {
+        FieldDef {
+            is_optional: false,
+            name: name.to_string(),
+            docs: String::new(),
+            field_type,
+            is_array: false,
+            array_num: None,
+            model_schema_prop_meta: None,
+            is_non_zero: false,
+            is_set: false,
+            description: None,
+        }
+    }
+
+    #[test]
+    fn test_default_values() {
+        assert_eq!(
+            default_value(&field("a", FieldDefType::String)).to_string(),
+            quote! { serde_json::json!("") }.to_string()
+        );
+        assert_eq!(
+            default_value(&field("a", FieldDefType::U32)).to_string(),
+            quote! { serde_json::json!(0) }.to_string()
+        );
+
+        let mut non_zero = field("a", FieldDefType::U32);
+        non_zero.is_non_zero = true;
+        assert_eq!(default_value(&non_zero).to_string(), quote! { serde_json::json!(1) }.to_string());
+
+        let mut sequence = field("a", FieldDefType::Boolean);
+        sequence.is_array = true;
+        assert_eq!(default_value(&sequence).to_string(), quote! { serde_json::json!([]) }.to_string());
+    }
+
+    #[test]
+    fn test_bounded_numbers_start_at_their_minimum() {
+        let mut bounded = field("a", FieldDefType::I32);
+        bounded.model_schema_prop_meta =
+            Some(ModelSchemaPropMeta { min: Some("-10".to_string()), ..Default::default() });
+        assert_eq!(default_value(&bounded).to_string(), quote! { serde_json::json!(-10) }.to_string());
+
+        bounded.is_non_zero = true;
+        bounded.model_schema_prop_meta = Some(ModelSchemaPropMeta { min: Some("0".to_string()), ..Default::default() });
+        assert_eq!(default_value(&bounded).to_string(), quote! { serde_json::json!(1) }.to_string());
+    }
+
+    #[test]
+    fn test_nested_types_use_their_own_default() {
+        let nested = field("address", FieldDefType::SiblingType("Address".to_string(), vec![], None));
+        assert_eq!(
+            default_value(&nested).to_string(),
+            quote! { AddressJson::default_json() }.to_string()
+        );
+    }
+
+    #[test]
+    fn test_optional_fields_are_skipped() {
+        let mut optional = field("nickname", FieldDefType::String);
+        optional.is_optional = true;
+        let inserts = insert_default_fields(&[field("name", FieldDefType::String), optional]);
+        assert_eq!(inserts.len(), 1);
+        assert!(inserts[0].to_string().contains("\"name\""));
+    }
+}
@@ -0,0 +1,159 @@
+//! io-ts codec generation feature module
+//!
+//! This module generates the `io_ts_codec()` method when the "io_ts" feature is enabled, for
+//! codebases that validate with io-ts instead of Zod. Codecs are named after the type
+//! (`export const User = t.type({ ... })`), as is usual with io-ts, so the generated code expects
+//! `import * as t from "io-ts"` in scope.
+//!
+//! io-ts has no counterpart for part of what the Zod schemas check: integers, string formats,
+//! `min_length` and `required_with` aren't validated, unknown keys are allowed, and generic types
+//! become `t.unknown`.
+
+use std::collections::BTreeMap;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::field_type::FieldDef;
+#[cfg(feature = "serde")]
+use crate::field_type::VariantShape;
+
+/// Returns the `t.type({ ... })` codec of an object with the given fields, plus `extra` lines.
+fn object_codec(fields: &[FieldDef], extra: Option<String>) -> String {
+    let properties = extra
+        .into_iter()
+        .chain(fields.iter().map(|fld| format!("{}: {}", fld.property_key(), fld.io_ts_type())))
+        .map(|p| format!("  {p},\n"))
+        .collect::<String>();
+    format!("t.type({{\n{properties}}})")
+}
+
+/// Returns `t.union([...])`, or the only member itself since io-ts unions need two, or
+/// `t.never` without members.
+fn union_codec(members: Vec<String>) -> String {
+    match members.as_slice() {
+        [] => "t.never".to_string(),
+        [member] => member.clone(),
+        _ => format!("t.union([{}])", members.join(", ")),
+    }
+}
+
+fn codec_method(item_name: &str, codec: &str) -> TokenStream {
+    let declaration = format!("export const {item_name} = {codec};");
+    quote! {
+        pub fn io_ts_codec() -> String {
+            #declaration.to_string()
+        }
+    }
+}
+
+/// Generates `io_ts_codec()` for a struct.
+pub fn generate_struct_io_ts_codec_method(item_name: &str, fields: &[FieldDef]) -> TokenStream {
+    codec_method(item_name, &object_codec(fields, None))
+}
+
+/// Generates `io_ts_codec()` for a `#[serde(transparent)]` struct, the codec of its only field.
+#[cfg(feature = "serde")]
+pub fn generate_transparent_struct_io_ts_codec_method(item_name: &str, inner: &FieldDef) -> TokenStream {
+    codec_method(item_name, &inner.io_ts_type())
+}
+
+/// Generates `io_ts_codec()` for a plain enum, as a `t.keyof` of its values. A
+/// `#[serde(other)]` catch-all widens it to any string.
+pub fn generate_plain_enum_io_ts_codec_method(
+    item_name: &str,
+    values: &[String],
+    has_catch_all: bool,
+) -> TokenStream {
+    let keys = values
+        .iter()
+        .map(|v| format!("\"{v}\": null"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let codec = match (values.is_empty(), has_catch_all) {
+        (true, _) => "t.string".to_string(),
+        (false, true) => format!("t.union([t.keyof({{ {keys} }}), t.string])"),
+        (false, false) => format!("t.keyof({{ {keys} }})"),
+    };
+    codec_method(item_name, &codec)
+}
+
+/// Generates `io_ts_codec()` for a discriminated enum, as a `t.union` of one object per
+/// variant with its tag as a `t.literal`. Newtype variants intersect the tag with their payload.
+pub fn generate_discriminated_enum_io_ts_codec_method<'a>(
+    item_name: &str,
+    tag_name: &str,
+    variants: impl Iterator<Item = (&'a String, &'a Vec<FieldDef>)>,
+    newtype_variants: &BTreeMap<String, FieldDef>,
+    untagged_fallback: bool,
+) -> TokenStream {
+    let mut members = variants
+        .map(|(discriminator_value, fields)| {
+            let tag = format!("{tag_name}: t.literal(\"{discriminator_value}\")");
+            if let Some(payload) = newtype_variants.get(discriminator_value) {
+                return format!("t.intersection([t.type({{ {tag} }}), {}])", payload.io_ts_type());
+            }
+            object_codec(fields, Some(tag))
+        })
+        .collect::<Vec<_>>();
+    if untagged_fallback {
+        members.push(format!("t.type({{ {tag_name}: t.string }})"));
+    }
+    codec_method(item_name, &union_codec(members))
+}
+
+/// Generates `io_ts_codec()` for an untagged enum, as a `t.union` of its variants' values
+/// without a tag.
+#[cfg(feature = "serde")]
+pub fn generate_untagged_enum_io_ts_codec_method(item_name: &str, variants: &[VariantShape]) -> TokenStream {
+    let members = variants
+        .iter()
+        .map(|variant| match variant {
+            VariantShape::Unit => "t.null".to_string(),
+            VariantShape::Newtype(payload) => payload.io_ts_type(),
+            VariantShape::Struct(fields) => object_codec(fields, None),
+        })
+        .collect();
+    codec_method(item_name, &union_codec(members))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field_type::FieldDefType;
+
+    fn field(name: &str, field_type: FieldDefType) -> FieldDef {
+        FieldDef {
+            is_optional: false,
+            name: name.to_string(),
+            docs: String::new(),
+            field_type,
+            is_array: false,
+            array_num: None,
+            model_schema_prop_meta: None,
+            is_non_zero: false,
+            is_set: false,
+            description: None,
+        }
+    }
+
+    #[test]
+    fn test_object_codec() {
+        let mut age = field("age", FieldDefType::U32);
+        age.is_optional = true;
+        assert_eq!(
+            object_codec(&[field("id", FieldDefType::String), age], None),
+            "t.type({\n  id: t.string,\n  age: t.union([t.number, t.undefined]),\n})"
+        );
+    }
+
+    #[test]
+    fn test_union_codec_of_one_member() {
+        assert_eq!(union_codec(Vec::new()), "t.never");
+        assert_eq!(union_codec(vec!["t.string".to_string()]), "t.string");
+        assert_eq!(
+            union_codec(vec!["t.string".to_string(), "t.number".to_string()]),
+            "t.union([t.string, t.number])"
+        );
+    }
+}
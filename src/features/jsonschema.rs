@@ -9,49 +9,236 @@ pub fn should_generate_json_schema() -> bool {
 }
 
 /// Generates the JSON schema method implementation for structs
+///
+/// `catchall_schema` is an expression evaluating to the schema of extra keys (from a flattened
+/// map field); without it, additional properties are rejected. `dependent_required` holds
+/// `(trigger, dependent)` pairs emitted as the root `dependentRequired` keyword.
+///
+/// `flattened_schemas` evaluate to the schemas of flattened struct fields, composed with
+/// `allOf`. `additionalProperties` only sees the properties of its own subschema, so it would
+/// reject the keys of the others: the subschemas lose theirs, and the combined schema closes
+/// with `unevaluatedProperties` (draft 2019-09), which sees the keys evaluated by all of them.
 pub fn generate_struct_json_schema_method(
     json_schema_fields: &[proc_macro2::TokenStream],
+    flattened_schemas: &[proc_macro2::TokenStream],
+    catchall_schema: Option<&proc_macro2::TokenStream>,
+    dependent_required: &[(String, String)],
+    json_schema: &syn::Ident,
+    item_name: &str,
 ) -> proc_macro2::TokenStream {
-    quote::quote! {
-        pub fn json_schema() -> serde_json::Value {
-            let mut schema_obj = serde_json::Map::new();
-            schema_obj.insert("type".to_string(), serde_json::Value::String("object".to_string()));
-            schema_obj.insert("additionalProperties".to_string(), serde_json::Value::Bool(false));
-            let mut properties = serde_json::Map::new();
-            let mut required = Vec::new();
-
-            #(#json_schema_fields)*
+    let additional_properties = match catchall_schema {
+        Some(schema) => quote::quote! { #schema },
+        None => quote::quote! { serde_json::Value::Bool(false) },
+    };
 
+    // Group dependents by trigger, keeping declaration order
+    let mut grouped: Vec<(&str, Vec<&str>)> = Vec::new();
+    for (trigger, dependent) in dependent_required {
+        match grouped.iter_mut().find(|(t, _)| t == trigger) {
+            Some((_, dependents)) => dependents.push(dependent),
+            None => grouped.push((trigger, vec![dependent])),
+        }
+    }
+    let dependent_required_code = if grouped.is_empty() {
+        quote::quote! {}
+    } else {
+        let triggers = grouped.iter().map(|(t, _)| *t);
+        let dependents = grouped.iter().map(|(_, d)| quote::quote! { [#(#d),*] });
+        quote::quote! {
             schema_obj.insert(
-                "properties".to_string(),
-                serde_json::Value::Object(properties),
+                "dependentRequired".to_string(),
+                serde_json::json!({ #(#triggers: #dependents),* }),
             );
+        }
+    };
 
-            schema_obj.insert("required".to_string(), serde_json::Value::Array(required));
-
-            serde_json::Value::Object(schema_obj)
+    let closing_keyword = if flattened_schemas.is_empty() {
+        quote::quote! {
+            schema_obj.insert("additionalProperties".to_string(), #additional_properties);
         }
-    }
+    } else {
+        quote::quote! {
+            let all_of = [#(#flattened_schemas),*]
+                .into_iter()
+                .map(|mut subschema| {
+                    if let Some(subschema_obj) = subschema.as_object_mut() {
+                        subschema_obj.remove("additionalProperties");
+                        subschema_obj.remove("unevaluatedProperties");
+                    }
+                    subschema
+                })
+                .collect::<Vec<_>>();
+            schema_obj.insert("allOf".to_string(), serde_json::Value::Array(all_of));
+            schema_obj.insert("unevaluatedProperties".to_string(), #additional_properties);
+        }
+    };
+
+    let body = quote::quote! {
+        let mut schema_obj = serde_json::Map::new();
+        schema_obj.insert("type".to_string(), serde_json::Value::String("object".to_string()));
+        #closing_keyword
+        let mut properties = serde_json::Map::new();
+        let mut required = Vec::new();
+
+        #(#json_schema_fields)*
+
+        schema_obj.insert(
+            "properties".to_string(),
+            serde_json::Value::Object(properties),
+        );
+
+        schema_obj.insert("required".to_string(), serde_json::Value::Array(required));
+        #dependent_required_code
+
+        serde_json::Value::Object(schema_obj)
+    };
+
+    json_schema_methods(json_schema, item_name, body)
 }
 
 /// Generates the JSON schema method implementation for plain enums
-pub fn generate_plain_enum_json_schema_method() -> proc_macro2::TokenStream {
+///
+/// `enum_options` are the serialized (renamed) values, the same list the TypeScript union and
+/// the Zod enum are built from. With a `#[serde(other)]` catch-all variant any string is valid,
+/// so there's no closed `enum`.
+pub fn generate_plain_enum_json_schema_method(
+    json_schema: &syn::Ident,
+    item_name: &str,
+    enum_options: &[String],
+    has_catch_all: bool,
+) -> proc_macro2::TokenStream {
+    let enum_values = if has_catch_all {
+        quote::quote! {}
+    } else {
+        quote::quote! {
+            schema_obj.insert("enum".to_string(), serde_json::json!([#(#enum_options),*]));
+        }
+    };
+    let body = quote::quote! {
+        let mut schema_obj = serde_json::Map::new();
+        schema_obj.insert("type".to_string(), serde_json::Value::String("string".to_string()));
+        #enum_values
+
+        serde_json::Value::Object(schema_obj)
+    };
+
+    json_schema_methods(json_schema, item_name, body)
+}
+
+/// Generates the `json_schema()` method of a type from the `body` building its schema, with
+/// the hidden methods other types build it through when it's nested in theirs:
+///
+/// - `__tixschema_json_schema_in(stack, defs)` evaluates `body`. Nested types in it are built
+///   with `nested_json_schema`, which passes `stack` and `defs` on.
+/// - `__tixschema_json_schema_ref(stack, defs)` is the schema of the type where it's nested:
+///   its whole schema, or a `$ref` when it's a shared definition. `stack` holds the names of
+///   the types being expanded, so a type reached again inside itself is a `{ "$ref": "#" }`
+///   to the root, or a `{ "$ref": "#/$defs/{name}" }` with its schema moved to `defs`, instead
+///   of an endless expansion. `model_schema_components!` lists its types in `defs` up front,
+///   so references follow the declared field types rather than matching schemas by value.
+///
+/// `json_schema()` builds the schema from the root, and adds the `$defs` collected on the way.
+pub fn json_schema_methods(
+    json_schema: &syn::Ident,
+    item_name: &str,
+    body: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let root = sorted_json_schema_body(quote::quote! {
+        let mut defs = serde_json::Map::new();
+        let mut schema = Self::__tixschema_json_schema_in(&mut vec![#item_name], &mut defs);
+        if !defs.is_empty() {
+            if let Some(schema_obj) = schema.as_object_mut() {
+                schema_obj.insert("$defs".to_string(), serde_json::Value::Object(defs));
+            }
+        }
+        schema
+    });
+    let reference = format!("#/$defs/{item_name}");
+
     quote::quote! {
-        pub fn json_schema() -> serde_json::Value {
-            let mut schema_obj = serde_json::Map::new();
-            schema_obj.insert("type".to_string(), serde_json::Value::String("string".to_string()));
-            schema_obj.insert("enum".to_string(), serde_json::Value::Array(Self::enum_members().into_iter().map(|v| serde_json::Value::String(v)).collect()));
+        pub fn #json_schema() -> serde_json::Value {
+            #root
+        }
+
+        #[doc(hidden)]
+        pub fn __tixschema_name() -> &'static str {
+            #item_name
+        }
+
+        #[doc(hidden)]
+        #[allow(unused_variables, clippy::ptr_arg)]
+        pub fn __tixschema_json_schema_in(
+            stack: &mut Vec<&'static str>,
+            defs: &mut serde_json::Map<String, serde_json::Value>,
+        ) -> serde_json::Value {
+            #body
+        }
 
-            serde_json::Value::Object(schema_obj)
+        #[doc(hidden)]
+        pub fn __tixschema_json_schema_ref(
+            stack: &mut Vec<&'static str>,
+            defs: &mut serde_json::Map<String, serde_json::Value>,
+        ) -> serde_json::Value {
+            if defs.contains_key(#item_name) {
+                return serde_json::json!({ "$ref": #reference });
+            }
+            if stack.first() == Some(&#item_name) {
+                return serde_json::json!({ "$ref": "#" });
+            }
+            // Recursive: the definition is filled in once its outermost expansion is built
+            if stack.contains(&#item_name) {
+                defs.insert(#item_name.to_string(), serde_json::Value::Null);
+                return serde_json::json!({ "$ref": #reference });
+            }
+            stack.push(#item_name);
+            let schema = Self::__tixschema_json_schema_in(stack, defs);
+            stack.pop();
+            if let Some(def) = defs.get_mut(#item_name) {
+                *def = schema;
+                return serde_json::json!({ "$ref": #reference });
+            }
+            schema
         }
     }
 }
 
+/// Wraps the body of a `json_schema()` method so every object in the returned schema has its
+/// keys sorted.
+///
+/// serde_json's `Map` is a `BTreeMap` by default, but keeps insertion order once any crate in
+/// the dependency graph enables its `preserve_order` feature (bson does). Sorting makes the
+/// schema, and the pretty-printed copy embedded in the TypeScript docs, the same either way.
+pub fn sorted_json_schema_body(body: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    quote::quote! {
+        fn sort_keys(value: serde_json::Value) -> serde_json::Value {
+            match value {
+                serde_json::Value::Object(map) => {
+                    let mut entries = map.into_iter().collect::<Vec<_>>();
+                    entries.sort_by(|a, b| a.0.cmp(&b.0));
+                    serde_json::Value::Object(
+                        entries.into_iter().map(|(k, v)| (k, sort_keys(v))).collect(),
+                    )
+                }
+                serde_json::Value::Array(items) => {
+                    serde_json::Value::Array(items.into_iter().map(sort_keys).collect())
+                }
+                other => other,
+            }
+        }
+
+        sort_keys({ #body })
+    }
+}
+
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn json_schema_ident() -> syn::Ident {
+        syn::Ident::new("json_schema", proc_macro2::Span::call_site())
+    }
+
     #[test]
     fn test_should_generate_json_schema() {
         assert!(should_generate_json_schema());
@@ -60,12 +247,83 @@ mod tests {
     #[test]
     fn test_json_schema_method_generation() {
         let fields = vec![];
-        let method = generate_struct_json_schema_method(&fields);
+        let method = generate_struct_json_schema_method(&fields, &[], None, &[], &json_schema_ident(), "Test");
         let method_str = method.to_string();
         
         assert!(method_str.contains("json_schema"));
         assert!(method_str.contains("serde_json"));
         assert!(method_str.contains("properties"));
         assert!(method_str.contains("required"));
+        assert!(method_str.contains("Bool (false)"));
+    }
+
+    #[test]
+    fn test_json_schema_method_with_catchall() {
+        let catchall = quote::quote! { serde_json::json!({ "type": "string" }) };
+        let method = generate_struct_json_schema_method(&[], &[], Some(&catchall), &[], &json_schema_ident(), "Test");
+        let method_str = method.to_string();
+
+        assert!(method_str.contains("additionalProperties"));
+        assert!(!method_str.contains("Bool (false)"));
+    }
+
+    #[test]
+    fn test_json_schema_method_with_flattened_structs() {
+        let flattened = [quote::quote! { AuditFieldsJson::json_schema() }];
+        let method_str = generate_struct_json_schema_method(&[], &flattened, None, &[], &json_schema_ident(), "Test").to_string();
+
+        assert!(method_str.contains("\"allOf\""));
+        assert!(method_str.contains("\"unevaluatedProperties\" . to_string () , serde_json :: Value :: Bool (false)"));
+        assert!(!method_str.contains("insert (\"additionalProperties\""));
+    }
+
+    #[test]
+    fn test_json_schema_method_with_dependent_required() {
+        let pairs = vec![
+            ("country".to_string(), "region".to_string()),
+            ("country".to_string(), "postal_code".to_string()),
+        ];
+        let method = generate_struct_json_schema_method(&[], &[], None, &pairs, &json_schema_ident(), "Test");
+        let method_str = method.to_string();
+
+        assert!(method_str.contains("dependentRequired"));
+        assert!(method_str.contains("\"country\" : [\"region\" , \"postal_code\"]"));
+
+        let method = generate_struct_json_schema_method(&[], &[], None, &[], &json_schema_ident(), "Test");
+        assert!(!method.to_string().contains("dependentRequired"));
+    }
+
+    #[test]
+    fn test_sorted_json_schema_body() {
+        let body = sorted_json_schema_body(quote::quote! { serde_json::json!({}) }).to_string();
+        assert!(body.contains("fn sort_keys"));
+        assert!(body.contains("sort_keys ({ serde_json :: json ! ({ }) })"));
+    }
+
+    #[test]
+    fn test_json_schema_method_renamed() {
+        let name = syn::Ident::new("schema", proc_macro2::Span::call_site());
+        let method_str = generate_struct_json_schema_method(&[], &[], None, &[], &name, "Test").to_string();
+        assert!(method_str.contains("pub fn schema ()"));
+
+        let method_str = generate_plain_enum_json_schema_method(&name, "Test", &[], false).to_string();
+        assert!(method_str.contains("pub fn schema ()"));
+    }
+
+    #[test]
+    fn test_catch_all_plain_enum_has_no_closed_enum() {
+        let name = syn::Ident::new("json_schema", proc_macro2::Span::call_site());
+        let values = ["active".to_string()];
+        assert!(generate_plain_enum_json_schema_method(&name, "Test", &values, false).to_string().contains("\"enum\""));
+        assert!(!generate_plain_enum_json_schema_method(&name, "Test", &values, true).to_string().contains("\"enum\""));
     }
-} 
\ No newline at end of file
+
+    #[test]
+    fn test_plain_enum_json_schema_uses_given_values() {
+        let name = syn::Ident::new("json_schema", proc_macro2::Span::call_site());
+        let values = ["active".to_string(), "on_hold".to_string()];
+        let method_str = generate_plain_enum_json_schema_method(&name, "Test", &values, false).to_string();
+        assert!(method_str.contains("[\"active\" , \"on_hold\"]"));
+        assert!(!method_str.contains("enum_members"));
+    }
+}
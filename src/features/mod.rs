@@ -15,9 +15,39 @@ pub mod jsonschema;
 #[cfg(feature = "object_id")]
 pub mod object_id;
 
+#[cfg(feature = "bytes")]
+pub mod bytes;
+
+#[cfg(feature = "fixtures")]
+pub mod fixtures;
+
+#[cfg(feature = "io_ts")]
+pub mod io_ts;
+
+#[cfg(feature = "reflect")]
+pub mod reflect;
+
+#[cfg(feature = "csv")]
+pub mod csv;
+
 /// Module for parsing model_schema_prop attributes
 pub mod model_schema_prop;
 
+/// Module for parsing the arguments of model_schema itself
+pub mod model_schema_args;
+
+/// Module for the diagnostics of model_schema(strict)
+pub mod strict;
+
+/// Module for the diagnostics of model_schema(debug)
+pub mod debug;
+
+/// Module for the case conversions of rename_all
+pub mod casing;
+
+/// Module for scalar types serialized as formatted strings (UUIDs, IP addresses, timestamps)
+pub mod string_formats;
+
 /// Feature detection utilities
 #[cfg(test)]
 pub struct Features;
@@ -49,6 +79,61 @@ impl Features {
         cfg!(feature = "typescript")
     }
 
+    /// Check if bytes feature is enabled
+    pub const fn has_bytes() -> bool {
+        cfg!(feature = "bytes")
+    }
+
+    /// Check if union_helpers feature is enabled
+    pub const fn has_union_helpers() -> bool {
+        cfg!(feature = "union_helpers")
+    }
+
+    /// Check if float_precision_hints feature is enabled
+    pub const fn has_float_precision_hints() -> bool {
+        cfg!(feature = "float_precision_hints")
+    }
+
+    /// Check if dual_schemas feature is enabled
+    pub const fn has_dual_schemas() -> bool {
+        cfg!(feature = "dual_schemas")
+    }
+
+    /// Check if fixtures feature is enabled
+    pub const fn has_fixtures() -> bool {
+        cfg!(feature = "fixtures")
+    }
+
+    /// Check if enum_consts feature is enabled
+    pub const fn has_enum_consts() -> bool {
+        cfg!(feature = "enum_consts")
+    }
+
+    /// Check if io_ts feature is enabled
+    pub const fn has_io_ts() -> bool {
+        cfg!(feature = "io_ts")
+    }
+
+    /// Check if reflect feature is enabled
+    pub const fn has_reflect() -> bool {
+        cfg!(feature = "reflect")
+    }
+
+    /// Check if safe_ints feature is enabled
+    pub const fn has_safe_ints() -> bool {
+        cfg!(feature = "safe_ints")
+    }
+
+    /// Check if zod_describe feature is enabled
+    pub const fn has_zod_describe() -> bool {
+        cfg!(feature = "zod_describe")
+    }
+
+    /// Check if csv feature is enabled
+    pub const fn has_csv() -> bool {
+        cfg!(feature = "csv")
+    }
+
     /// Get a description of enabled features for debugging
     pub fn enabled_features() -> Vec<&'static str> {
         let mut features = Vec::new();
@@ -68,6 +153,39 @@ impl Features {
         if Self::has_typescript() {
             features.push("typescript");
         }
+        if Self::has_bytes() {
+            features.push("bytes");
+        }
+        if Self::has_union_helpers() {
+            features.push("union_helpers");
+        }
+        if Self::has_float_precision_hints() {
+            features.push("float_precision_hints");
+        }
+        if Self::has_dual_schemas() {
+            features.push("dual_schemas");
+        }
+        if Self::has_fixtures() {
+            features.push("fixtures");
+        }
+        if Self::has_enum_consts() {
+            features.push("enum_consts");
+        }
+        if Self::has_io_ts() {
+            features.push("io_ts");
+        }
+        if Self::has_reflect() {
+            features.push("reflect");
+        }
+        if Self::has_safe_ints() {
+            features.push("safe_ints");
+        }
+        if Self::has_zod_describe() {
+            features.push("zod_describe");
+        }
+        if Self::has_csv() {
+            features.push("csv");
+        }
         
         if features.is_empty() {
             features.push("minimal");
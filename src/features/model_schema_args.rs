@@ -0,0 +1,547 @@
+//! Model schema arguments module
+//!
+//! This module handles parsing of the arguments passed to `#[model_schema(...)]` itself,
+//! such as `rename = "..."`, `tag = "..."`, `untagged_fallback`, `camel_schema_keys`, `enum_as_object = "..."`,
+//! `rename_all = "..."`, `strict`, `debug`, `optionals_absent`, `optionals_nullable`,
+//! `optional_style = "..."`, `deserialize_shape`, `derive_default_instance`, `discriminator_description`, `extern_map(...)`, `default_export`,
+//! `zod_import = "..."` and the generated method names (`ts_definition = "..."`, `emit = "..."`, etc.).
+
+use std::collections::BTreeMap;
+
+use proc_macro2::Span;
+use syn::{Ident, LitStr, meta::ParseNestedMeta};
+
+/// Whether `import` can stand in for `z` in the generated schemas: a JavaScript identifier, or
+/// a dotted path of them for a Zod re-exported by another module (e.g. `lib.z`).
+fn is_valid_zod_import(import: &str) -> bool {
+    import.split('.').all(|segment| {
+        let mut chars = segment.chars();
+        chars.next().is_some_and(|c| c.is_alphabetic() || c == '_' || c == '$')
+            && chars.all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+    })
+}
+
+/// Arguments accepted by `#[model_schema(...)]`.
+#[derive(Clone, Debug, Default)]
+pub struct ModelSchemaArgs {
+    pub rename: Option<String>,   // e.g., "Customer" from rename = "Customer"
+    pub tag: Option<String>,      // e.g., "kind" from tag = "kind"
+    pub untagged_fallback: bool,  // from untagged_fallback
+    pub camel_schema_keys: bool,  // from camel_schema_keys
+    pub enum_as_object: Option<String>, // e.g., "kind" from enum_as_object = "kind"
+    pub rename_all: Option<String>,     // e.g., "camelCase", takes precedence over serde's
+    pub strict: bool,                   // from strict
+    pub debug: bool,                    // from debug
+    pub optionals_absent: bool,         // from optionals_absent
+    pub optionals_nullable: bool,       // from optionals_nullable
+    pub optional_style: Option<OptionalStyle>, // e.g., Absent from optional_style = "question_mark"
+    pub deserialize_shape: bool,        // from deserialize_shape
+    pub derive_default_instance: bool,  // from derive_default_instance
+    pub discriminator_description: bool, // from discriminator_description
+    pub extern_map: BTreeMap<String, ExternType>, // e.g., GeoJson from extern_map(GeoJson = "GeoJSON.Geometry")
+    #[cfg_attr(not(any(feature = "typescript", feature = "zod")), allow(dead_code))]
+    pub default_export: bool,           // from default_export
+    #[cfg_attr(not(feature = "zod"), allow(dead_code))]
+    pub zod_import: Option<String>,     // e.g., "zod" from zod_import = "zod"
+    pub ts_definition: Option<Ident>,   // e.g., `to_ts` from ts_definition = "to_ts"
+    pub zod_schema: Option<Ident>,      // e.g., `zod` from zod_schema = "zod"
+    pub json_schema: Option<Ident>,     // e.g., `schema` from json_schema = "schema"
+    pub emit: Option<Ident>,            // e.g., `emit_schema` from emit = "emit_schema"
+    pub spans: BTreeMap<String, Span>,  // where each argument was written, for errors about it
+}
+
+/// Identifiers of the generated methods, the defaults unless renamed through
+/// `#[model_schema(ts_definition = "...", zod_schema = "...", json_schema = "...", emit = "...")]`.
+///
+/// Generated code for other types never calls these names: it goes through the hidden
+/// `__tixschema_ts_definition`, `__tixschema_zod_schema` and `__tixschema_json_schema`
+/// forwarders, which keep their names whatever the methods are renamed to.
+#[derive(Clone, Debug)]
+pub struct MethodNames {
+    #[cfg_attr(not(feature = "typescript"), allow(dead_code))]
+    pub ts_definition: Ident,
+    #[cfg_attr(not(feature = "zod"), allow(dead_code))]
+    pub zod_schema: Ident,
+    #[cfg_attr(not(feature = "jsonschema"), allow(dead_code))]
+    pub json_schema: Ident,
+    #[cfg_attr(not(any(feature = "typescript", feature = "zod", feature = "jsonschema")), allow(dead_code))]
+    pub emit: Ident,
+}
+
+/// Options shaping the generated TypeScript and Zod code rather than the types it describes.
+#[cfg(any(feature = "typescript", feature = "zod"))]
+#[derive(Clone, Debug)]
+pub struct CodegenOptions {
+    /// Whether the output ends with a default export, from `default_export`
+    pub default_export: bool,
+    /// The identifier the Zod schema calls Zod through, `z` unless set with `zod_import`
+    #[cfg(feature = "zod")]
+    pub zod_import: String,
+}
+
+/// A type the macro can't introspect, mapped through `extern_map(...)` to a hand-maintained or
+/// third-party TypeScript type and, optionally, the Zod schema validating it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExternType {
+    pub typescript: String,
+    pub zod: Option<String>,
+}
+
+impl ExternType {
+    /// The Zod schema of the type, a `z.custom()` accepting any value unless one was given,
+    /// calling Zod through `zod`. A given schema is used as written.
+    #[cfg(feature = "zod")]
+    pub fn zod_schema(&self, zod: &str) -> String {
+        match &self.zod {
+            Some(schema) => schema.clone(),
+            #[cfg(feature = "typescript")]
+            None => format!("{zod}.custom<{}>()", self.typescript),
+            #[cfg(not(feature = "typescript"))]
+            None => format!("{zod}.custom()"),
+        }
+    }
+}
+
+/// How `Option<T>` fields are rendered, from `#[model_schema(optionals_absent)]`,
+/// `#[model_schema(optionals_nullable)]` or `#[model_schema(optional_style = "...")]`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OptionalStyle {
+    /// `key: T | undefined`, the default
+    Undefined,
+    /// `key?: T`, for `None` fields left out of the payload
+    Absent,
+    /// `key: T | null`, for `None` fields written as `null`
+    Nullable,
+}
+
+impl ModelSchemaArgs {
+    /// Returns true when an argument that only applies to enums was used.
+    pub fn has_enum_args(&self) -> bool {
+        self.tag.is_some()
+            || self.untagged_fallback
+            || self.enum_as_object.is_some()
+            || self.discriminator_description
+    }
+
+    /// Returns where the argument `name` was written, or the call site when it wasn't.
+    pub fn span(&self, name: &str) -> Span {
+        self.spans.get(name).copied().unwrap_or_else(Span::call_site)
+    }
+
+    /// Returns how optional fields are rendered.
+    pub fn optional_style(&self) -> OptionalStyle {
+        if let Some(style) = self.optional_style {
+            style
+        } else if self.optionals_absent {
+            OptionalStyle::Absent
+        } else if self.optionals_nullable {
+            OptionalStyle::Nullable
+        } else {
+            OptionalStyle::Undefined
+        }
+    }
+
+    /// Rejects an argument choosing how optional fields are rendered when an earlier one
+    /// already did, pointing at the second.
+    fn check_optional_style_unset(&self, meta: &ParseNestedMeta) -> syn::Result<()> {
+        if self.optionals_absent || self.optionals_nullable || self.optional_style.is_some() {
+            return Err(meta.error(
+                "only one of the model_schema arguments `optionals_absent`, `optionals_nullable` and `optional_style` can be used",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns the name of the exported TypeScript type and Zod schema: `rename`, or the Rust
+    /// name without its `Json` suffix.
+    pub fn item_name(&self, name: &Ident) -> String {
+        self.rename
+            .clone()
+            .unwrap_or_else(|| crate::utils::safe_type_name(&name.to_string()))
+    }
+
+    /// Returns the identifiers to use for the generated methods.
+    pub fn method_names(&self) -> MethodNames {
+        let name_or = |name: &Option<Ident>, default: &str| {
+            name.clone().unwrap_or_else(|| Ident::new(default, Span::call_site()))
+        };
+        MethodNames {
+            ts_definition: name_or(&self.ts_definition, "ts_definition"),
+            zod_schema: name_or(&self.zod_schema, "zod_schema"),
+            json_schema: name_or(&self.json_schema, "json_schema"),
+            emit: name_or(&self.emit, "emit"),
+        }
+    }
+
+    /// Returns the options of the generated TypeScript and Zod code.
+    #[cfg(any(feature = "typescript", feature = "zod"))]
+    pub fn codegen_options(&self) -> CodegenOptions {
+        CodegenOptions {
+            default_export: self.default_export,
+            #[cfg(feature = "zod")]
+            zod_import: self.zod_import.clone().unwrap_or_else(|| "z".to_string()),
+        }
+    }
+
+    /// Parses a single `#[model_schema(...)]` argument.
+    pub fn parse(&mut self, meta: ParseNestedMeta) -> syn::Result<()> {
+        if let Some(ident) = meta.path.get_ident() {
+            self.spans.insert(ident.to_string(), ident.span());
+        }
+
+        // Handle `rename = "Customer"`, which must be a valid TypeScript identifier
+        if meta.path.is_ident("rename") {
+            let lit: LitStr = meta.value()?.parse()?;
+            let ident: Ident = lit.parse().map_err(|_| syn::Error::new(lit.span(), "rename must be a valid type name"))?;
+            self.rename = Some(ident.to_string());
+            Ok(())
+        }
+        // Handle `tag = "value"`
+        else if meta.path.is_ident("tag") {
+            let lit: LitStr = meta.value()?.parse()?;
+            self.tag = Some(lit.value());
+            Ok(())
+        }
+        // Handle `untagged_fallback` flag
+        else if meta.path.is_ident("untagged_fallback") {
+            self.untagged_fallback = true;
+            Ok(())
+        }
+        // Handle `camel_schema_keys` flag
+        else if meta.path.is_ident("camel_schema_keys") {
+            self.camel_schema_keys = true;
+            Ok(())
+        }
+        // Handle `enum_as_object = "value"`
+        else if meta.path.is_ident("enum_as_object") {
+            let lit: LitStr = meta.value()?.parse()?;
+            self.enum_as_object = Some(lit.value());
+            Ok(())
+        }
+        // Handle `rename_all = "value"`, one of serde's rules
+        else if meta.path.is_ident("rename_all") {
+            let lit: LitStr = meta.value()?.parse()?;
+            if !crate::features::casing::RENAME_ALL_RULES.contains(&lit.value().as_str()) {
+                return Err(syn::Error::new(
+                    lit.span(),
+                    format!(
+                        "unsupported rename_all rule, expected one of \"{}\"",
+                        crate::features::casing::RENAME_ALL_RULES.join("\", \"")
+                    ),
+                ));
+            }
+            self.rename_all = Some(lit.value());
+            Ok(())
+        }
+        // Handle `strict` flag
+        else if meta.path.is_ident("strict") {
+            self.strict = true;
+            Ok(())
+        }
+        // Handle `debug` flag
+        else if meta.path.is_ident("debug") {
+            self.debug = true;
+            Ok(())
+        }
+        // Handle `optionals_absent` flag
+        else if meta.path.is_ident("optionals_absent") {
+            self.check_optional_style_unset(&meta)?;
+            self.optionals_absent = true;
+            Ok(())
+        }
+        // Handle `optionals_nullable` flag
+        else if meta.path.is_ident("optionals_nullable") {
+            self.check_optional_style_unset(&meta)?;
+            self.optionals_nullable = true;
+            Ok(())
+        }
+        // Handle `optional_style = "question_mark"`, the spelled-out form of the flags above
+        else if meta.path.is_ident("optional_style") {
+            self.check_optional_style_unset(&meta)?;
+            let lit: LitStr = meta.value()?.parse()?;
+            self.optional_style = Some(match lit.value().as_str() {
+                "undefined" => OptionalStyle::Undefined,
+                "question_mark" => OptionalStyle::Absent,
+                "null" => OptionalStyle::Nullable,
+                _ => {
+                    return Err(syn::Error::new(
+                        lit.span(),
+                        "unsupported optional_style, expected \"undefined\", \"question_mark\" or \"null\"",
+                    ));
+                }
+            });
+            Ok(())
+        }
+        // Handle `deserialize_shape` flag
+        else if meta.path.is_ident("deserialize_shape") {
+            self.deserialize_shape = true;
+            Ok(())
+        }
+        // Handle `derive_default_instance` flag
+        else if meta.path.is_ident("derive_default_instance") {
+            self.derive_default_instance = true;
+            Ok(())
+        }
+        // Handle `discriminator_description` flag
+        else if meta.path.is_ident("discriminator_description") {
+            self.discriminator_description = true;
+            Ok(())
+        }
+        // Handle `default_export` flag
+        else if meta.path.is_ident("default_export") {
+            self.default_export = true;
+            Ok(())
+        }
+        // Handle `zod_import = "zod"`, the identifier the generated schemas call instead of `z`
+        else if meta.path.is_ident("zod_import") {
+            let lit: LitStr = meta.value()?.parse()?;
+            if !is_valid_zod_import(&lit.value()) {
+                return Err(syn::Error::new(lit.span(), "zod_import must be a JavaScript identifier, e.g. \"zod\""));
+            }
+            self.zod_import = Some(lit.value());
+            Ok(())
+        }
+        // Handle `extern_map(Type = "TsType", Other(ts = "TsType", zod = "Schema"))`
+        else if meta.path.is_ident("extern_map") {
+            meta.parse_nested_meta(|entry| {
+                let name = entry.path.require_ident()?.to_string();
+                let extern_type = if entry.input.peek(syn::Token![=]) {
+                    let lit: LitStr = entry.value()?.parse()?;
+                    ExternType { typescript: lit.value(), zod: None }
+                } else {
+                    let (mut typescript, mut zod) = (None, None);
+                    entry.parse_nested_meta(|option| {
+                        let lit: LitStr = option.value()?.parse()?;
+                        if option.path.is_ident("ts") {
+                            typescript = Some(lit.value());
+                        } else if option.path.is_ident("zod") {
+                            zod = Some(lit.value());
+                        } else {
+                            return Err(option.error("expected `ts` or `zod`"));
+                        }
+                        Ok(())
+                    })?;
+                    let typescript = typescript.ok_or_else(|| entry.error("missing `ts = \"...\"`"))?;
+                    ExternType { typescript, zod }
+                };
+                self.extern_map.insert(name, extern_type);
+                Ok(())
+            })
+        }
+        // Handle generated method renames, e.g. `ts_definition = "to_ts"`
+        else if meta.path.is_ident("ts_definition") {
+            let lit: LitStr = meta.value()?.parse()?;
+            self.ts_definition = Some(lit.parse()?);
+            Ok(())
+        } else if meta.path.is_ident("zod_schema") {
+            let lit: LitStr = meta.value()?.parse()?;
+            self.zod_schema = Some(lit.parse()?);
+            Ok(())
+        } else if meta.path.is_ident("json_schema") {
+            let lit: LitStr = meta.value()?.parse()?;
+            self.json_schema = Some(lit.parse()?);
+            Ok(())
+        } else if meta.path.is_ident("emit") {
+            let lit: LitStr = meta.value()?.parse()?;
+            self.emit = Some(lit.parse()?);
+            Ok(())
+        } else {
+            Err(meta.error(
+                "unsupported model_schema argument, expected `rename`, `tag`, `untagged_fallback`, `camel_schema_keys`, `enum_as_object`, `rename_all`, `strict`, `debug`, `optionals_absent`, `optionals_nullable`, `optional_style`, `deserialize_shape`, `derive_default_instance`, `discriminator_description`, `extern_map`, `default_export`, `zod_import`, `ts_definition`, `zod_schema`, `json_schema` or `emit`",
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse::Parser;
+
+    fn parse_args(tokens: proc_macro2::TokenStream) -> syn::Result<ModelSchemaArgs> {
+        let mut args = ModelSchemaArgs::default();
+        syn::meta::parser(|meta| args.parse(meta)).parse2(tokens)?;
+        Ok(args)
+    }
+
+    #[test]
+    fn test_parse_empty_args() {
+        let args = parse_args(quote::quote!()).unwrap();
+        assert!(!args.has_enum_args());
+        assert!(!args.camel_schema_keys);
+        assert!(args.rename_all.is_none());
+    }
+
+    #[test]
+    fn test_parse_tag_and_untagged_fallback() {
+        let args = parse_args(quote::quote!(tag = "kind", untagged_fallback)).unwrap();
+        assert_eq!(args.tag.as_deref(), Some("kind"));
+        assert!(args.untagged_fallback);
+        assert!(args.has_enum_args());
+    }
+
+    #[test]
+    fn test_parse_rename() {
+        let name: Ident = syn::parse_quote!(CustomerRecordJson);
+        let args = parse_args(quote::quote!(rename = "Customer")).unwrap();
+        assert_eq!(args.item_name(&name), "Customer");
+        assert_eq!(parse_args(quote::quote!()).unwrap().item_name(&name), "CustomerRecord");
+        assert!(parse_args(quote::quote!(rename = "Customer Record")).is_err());
+    }
+
+    #[test]
+    fn test_parse_default_export() {
+        let args = parse_args(quote::quote!(default_export)).unwrap();
+        assert!(args.default_export);
+        #[cfg(any(feature = "typescript", feature = "zod"))]
+        {
+            assert!(args.codegen_options().default_export);
+            assert!(!parse_args(quote::quote!()).unwrap().codegen_options().default_export);
+        }
+    }
+
+    #[test]
+    fn test_parse_discriminator_description() {
+        let args = parse_args(quote::quote!(discriminator_description)).unwrap();
+        assert!(args.discriminator_description);
+        assert!(args.has_enum_args());
+    }
+
+    #[test]
+    fn test_parse_camel_schema_keys() {
+        let args = parse_args(quote::quote!(camel_schema_keys)).unwrap();
+        assert!(args.camel_schema_keys);
+        assert!(!args.has_enum_args());
+    }
+
+    #[test]
+    fn test_parse_enum_as_object() {
+        let args = parse_args(quote::quote!(enum_as_object = "kind")).unwrap();
+        assert_eq!(args.enum_as_object.as_deref(), Some("kind"));
+        assert!(args.has_enum_args());
+        assert!(args.tag.is_none());
+    }
+
+    #[test]
+    fn test_parse_rename_all() {
+        let args = parse_args(quote::quote!(rename_all = "camelCase")).unwrap();
+        assert_eq!(args.rename_all.as_deref(), Some("camelCase"));
+        assert!(!args.has_enum_args());
+        assert!(parse_args(quote::quote!(rename_all = "SCREAMING-KEBAB-CASE")).is_ok());
+        assert!(parse_args(quote::quote!(rename_all = "camelcase")).is_err());
+    }
+
+    #[test]
+    fn test_parse_strict() {
+        let args = parse_args(quote::quote!(strict, rename_all = "camelCase")).unwrap();
+        assert!(args.strict);
+        assert!(!args.has_enum_args());
+        assert!(!parse_args(quote::quote!()).unwrap().strict);
+    }
+
+    #[test]
+    fn test_parse_debug() {
+        let args = parse_args(quote::quote!(debug, tag = "kind")).unwrap();
+        assert!(args.debug);
+        assert_eq!(args.tag.as_deref(), Some("kind"));
+        assert!(!parse_args(quote::quote!()).unwrap().debug);
+    }
+
+    #[test]
+    fn test_parse_optionals_absent() {
+        let args = parse_args(quote::quote!(optionals_absent)).unwrap();
+        assert!(args.optionals_absent);
+        assert!(!args.has_enum_args());
+        assert!(!parse_args(quote::quote!()).unwrap().optionals_absent);
+    }
+
+    #[test]
+    fn test_parse_extern_map() {
+        let args = parse_args(quote::quote!(
+            extern_map(GeoJson = "GeoJSON.Geometry", Money(ts = "Dinero", zod = "Dinero$Schema"))
+        ))
+        .unwrap();
+        assert_eq!(
+            args.extern_map["GeoJson"],
+            ExternType { typescript: "GeoJSON.Geometry".to_string(), zod: None }
+        );
+        assert_eq!(
+            args.extern_map["Money"],
+            ExternType { typescript: "Dinero".to_string(), zod: Some("Dinero$Schema".to_string()) }
+        );
+
+        assert!(parse_args(quote::quote!(extern_map(Money(zod = "Dinero$Schema")))).is_err());
+    }
+
+    #[test]
+    fn test_optional_style() {
+        assert_eq!(parse_args(quote::quote!()).unwrap().optional_style(), OptionalStyle::Undefined);
+        assert_eq!(parse_args(quote::quote!(optionals_absent)).unwrap().optional_style(), OptionalStyle::Absent);
+        assert_eq!(parse_args(quote::quote!(optionals_nullable)).unwrap().optional_style(), OptionalStyle::Nullable);
+        assert_eq!(
+            parse_args(quote::quote!(optional_style = "question_mark")).unwrap().optional_style(),
+            OptionalStyle::Absent
+        );
+        assert_eq!(parse_args(quote::quote!(optional_style = "undefined")).unwrap().optional_style(), OptionalStyle::Undefined);
+        assert_eq!(parse_args(quote::quote!(optional_style = "null")).unwrap().optional_style(), OptionalStyle::Nullable);
+        assert!(parse_args(quote::quote!(optional_style = "optional")).is_err());
+    }
+
+    #[test]
+    fn test_conflicting_optional_styles() {
+        assert!(parse_args(quote::quote!(optionals_absent, optionals_nullable)).is_err());
+        assert!(parse_args(quote::quote!(optionals_nullable, optional_style = "null")).is_err());
+        assert!(parse_args(quote::quote!(optional_style = "question_mark", optionals_absent)).is_err());
+    }
+
+    #[test]
+    fn test_parse_derive_default_instance() {
+        let args = parse_args(quote::quote!(derive_default_instance)).unwrap();
+        assert!(args.derive_default_instance);
+        assert!(!parse_args(quote::quote!()).unwrap().derive_default_instance);
+    }
+
+    #[test]
+    fn test_parse_method_names() {
+        let names = parse_args(quote::quote!()).unwrap().method_names();
+        assert_eq!(names.ts_definition, "ts_definition");
+        assert_eq!(names.zod_schema, "zod_schema");
+        assert_eq!(names.json_schema, "json_schema");
+        assert_eq!(names.emit, "emit");
+
+        let args = parse_args(quote::quote!(ts_definition = "to_ts", zod_schema = "zod", emit = "emit_schema")).unwrap();
+        let names = args.method_names();
+        assert_eq!(names.ts_definition, "to_ts");
+        assert_eq!(names.zod_schema, "zod");
+        assert_eq!(names.json_schema, "json_schema");
+        assert_eq!(names.emit, "emit_schema");
+        assert!(!args.has_enum_args());
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_parse_zod_import() {
+        assert_eq!(parse_args(quote::quote!()).unwrap().codegen_options().zod_import, "z");
+        let args = parse_args(quote::quote!(zod_import = "zod")).unwrap();
+        assert_eq!(args.codegen_options().zod_import, "zod");
+    }
+
+    #[test]
+    fn test_parse_invalid_zod_import_fails() {
+        assert!(parse_args(quote::quote!(zod_import = "my-z")).is_err());
+        assert!(parse_args(quote::quote!(zod_import = "1z")).is_err());
+        assert!(parse_args(quote::quote!(zod_import = "lib.")).is_err());
+        assert!(parse_args(quote::quote!(zod_import = "")).is_err());
+        assert!(parse_args(quote::quote!(zod_import = "$z")).is_ok());
+    }
+
+    #[test]
+    fn test_parse_invalid_method_name_fails() {
+        assert!(parse_args(quote::quote!(ts_definition = "to ts")).is_err());
+    }
+
+    #[test]
+    fn test_parse_unknown_arg_fails() {
+        assert!(parse_args(quote::quote!(exhaustive)).is_err());
+    }
+}
@@ -8,24 +8,150 @@ use syn::{Attribute, LitStr, Type};
 /// Metadata for model_schema_prop attributes applied to a field.
 #[derive(Clone, Debug, Default)]
 pub struct ModelSchemaPropMeta {
-    pub as_type: Option<String>,    // e.g., "String" from as = String
+    pub as_type: Option<Type>,      // e.g., `String` from as = String, `serde_json::Value` from as = "any"
     pub literal: Option<String>,    // e.g., "Tixena" from literal = "Tixena"
     pub min_length: Option<usize>,  // e.g., 1 from minLength = 1
+    pub max_length: Option<usize>,  // e.g., 64 from maxLength = 64
+    pub min: Option<String>,        // e.g., "0" from min = 0, kept as written for the schemas
+    pub max: Option<String>,        // e.g., "99.5" from max = 99.5
+    pub deprecated: Option<String>, // e.g., "use newField" from deprecated = "use newField"
+    pub read_only: bool,            // from read_only
+    pub write_only: bool,           // from write_only
+    pub trim: bool,                 // from trim, Zod `.trim()`
+    pub lowercase: bool,            // from lowercase, Zod `.toLowerCase()`
+    pub uppercase: bool,            // from uppercase, Zod `.toUpperCase()`
+    pub required_with: Option<LitStr>, // e.g., "country" from required_with = "country", kept for its span
+    pub enum_values: Option<Vec<String>>, // e.g., ["red", "green"] from enum_values = ["red", "green"]
+    pub coerce: bool,               // from coerce, Zod `z.coerce.*`
+    pub raw_json: bool,             // from raw_json or raw_json(parse)
+    pub raw_json_parse: bool,       // from raw_json(parse), Zod `.transform()` through JSON.parse
+    pub ts_template: Option<String>, // e.g., "user_${string}" from ts_template = "user_${string}"
+    pub pattern: Option<String>,    // e.g., "^[a-z]+$" from pattern = "^[a-z]+$"
+    pub format: Option<String>,     // e.g., "email" from format = "email"
+    pub key_pattern: Option<String>, // e.g., "^[a-z-]+$" from keyPattern = "^[a-z-]+$"
+    pub as_ts: Option<String>,      // e.g., "Date" from as_ts = "Date", the raw TypeScript type
+    pub as_zod: Option<String>,     // e.g., "z.coerce.date()" from as_zod = "z.coerce.date()"
 }
 
-/// Parses model_schema_prop attributes from a field.
-pub fn parse_model_schema_prop_attributes(attrs: &[Attribute]) -> ModelSchemaPropMeta {
+impl ModelSchemaPropMeta {
+    /// Returns true when no model_schema_prop option was set on the field.
+    pub fn is_empty(&self) -> bool {
+        self.as_type.is_none()
+            && self.literal.is_none()
+            && self.min_length.is_none()
+            && self.max_length.is_none()
+            && self.min.is_none()
+            && self.max.is_none()
+            && self.deprecated.is_none()
+            && !self.read_only
+            && !self.write_only
+            && self.string_transforms().is_empty()
+            && self.required_with.is_none()
+            && self.enum_values.is_none()
+            && !self.coerce
+            && !self.raw_json
+            && self.ts_template.is_none()
+            && self.pattern.is_none()
+            && self.format.is_none()
+            && self.key_pattern.is_none()
+            && self.as_ts.is_none()
+            && self.as_zod.is_none()
+    }
+
+    /// Returns the Zod string transforms to apply, in the order they run.
+    pub fn string_transforms(&self) -> Vec<&'static str> {
+        [
+            (self.trim, "trim"),
+            (self.lowercase, "toLowerCase"),
+            (self.uppercase, "toUpperCase"),
+        ]
+        .into_iter()
+        .filter_map(|(enabled, name)| enabled.then_some(name))
+        .collect()
+    }
+}
+
+/// Converts a TypeScript template literal type (without its backticks) to the anchored regex
+/// the Zod and JSON schemas validate with. Only the `${string}` and `${number}` placeholders
+/// are supported; the text around them is matched as is.
+pub fn ts_template_pattern(template: &str) -> Result<String, String> {
+    let mut pattern = String::from("^");
+    let mut rest = template;
+    while let Some(start) = rest.find("${") {
+        pattern.push_str(&regex_escape(&rest[..start]));
+        let end = rest[start..]
+            .find('}')
+            .ok_or_else(|| format!("unclosed placeholder in `{template}`"))?;
+        match &rest[start + 2..start + end] {
+            "string" => pattern.push_str(".*"),
+            "number" => pattern.push_str(r"-?\d+(\.\d+)?"),
+            other => return Err(format!("unsupported placeholder `${{{other}}}`, use `${{string}}` or `${{number}}`")),
+        }
+        rest = &rest[start + end + 1..];
+    }
+    pattern.push_str(&regex_escape(rest));
+    pattern.push('$');
+    Ok(pattern)
+}
+
+/// Escapes the unescaped slashes of a regex, so it can be written as a JavaScript
+/// `/.../` literal.
+#[cfg(feature = "zod")]
+pub fn js_regex_source(pattern: &str) -> String {
+    let mut source = String::with_capacity(pattern.len());
+    let mut escaped = false;
+    for c in pattern.chars() {
+        if c == '/' && !escaped {
+            source.push('\\');
+        }
+        escaped = c == '\\' && !escaped;
+        source.push(c);
+    }
+    source
+}
+
+/// Escapes the characters with a meaning in (JavaScript) regular expressions.
+fn regex_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if "\\^$.*+?()[]{}|/".contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Parses a numeric bound such as `0`, `-10` or `99.5`, keeping its digits (without a type
+/// suffix) to write into the Zod and JSON schemas.
+fn parse_numeric_bound(input: syn::parse::ParseStream) -> syn::Result<String> {
+    let negative = input.parse::<Option<syn::Token![-]>>()?.is_some();
+    let digits = match input.parse::<syn::Lit>()? {
+        syn::Lit::Int(lit) => lit.base10_digits().to_string(),
+        syn::Lit::Float(lit) => lit.base10_digits().to_string(),
+        lit => return Err(syn::Error::new(lit.span(), "expected a number")),
+    };
+    Ok(if negative { format!("-{digits}") } else { digits })
+}
+
+/// Parses model_schema_prop attributes from a field, failing on a malformed option.
+pub fn parse_model_schema_prop_attributes(attrs: &[Attribute]) -> syn::Result<ModelSchemaPropMeta> {
     let mut meta = ModelSchemaPropMeta::default();
 
     for attr in attrs {
         if attr.path().is_ident("model_schema_prop") {
             attr.parse_nested_meta(|nested| {
-                // Handle `as = Type`
+                // Handle `as = Type`, or `as = "any"` for arbitrary JSON like `serde_json::Value`
                 if nested.path.is_ident("as") {
                     let value = nested.value()?;
-                    if let Ok(ty) = value.parse::<Type>() {
-                        // Convert the type to a string representation
-                        meta.as_type = Some(quote::quote!(#ty).to_string());
+                    if value.peek(LitStr) {
+                        let lit: LitStr = value.parse()?;
+                        if lit.value() != "any" {
+                            return Err(syn::Error::new_spanned(lit, "`as` only accepts a type or \"any\""));
+                        }
+                        meta.as_type = Some(syn::parse_quote!(serde_json::Value));
+                    } else {
+                        meta.as_type = Some(value.parse()?);
                     }
                 }
                 // Handle `literal = "value"`
@@ -42,17 +168,135 @@ pub fn parse_model_schema_prop_attributes(attrs: &[Attribute]) -> ModelSchemaPro
                         meta.min_length = Some(min_len);
                     }
                 }
-                Ok(())
-            })
-            .unwrap_or_else(|e| {
-                if std::env::var("RUST_LOG") == Ok(String::from("trace")) {
-                    println!("Failed to parse model_schema_prop attribute: {e}");
+                // Handle `maxLength = N`
+                else if nested.path.is_ident("maxLength") {
+                    let value = nested.value()?;
+                    let lit = value.parse::<syn::LitInt>()?;
+                    if let Ok(max_len) = lit.base10_parse::<usize>() {
+                        meta.max_length = Some(max_len);
+                    }
+                }
+                // Handle `min = N` / `max = N`
+                else if nested.path.is_ident("min") {
+                    meta.min = Some(parse_numeric_bound(nested.value()?)?);
+                } else if nested.path.is_ident("max") {
+                    meta.max = Some(parse_numeric_bound(nested.value()?)?);
+                }
+                // Handle `deprecated = "reason"`
+                else if nested.path.is_ident("deprecated") {
+                    let value = nested.value()?;
+                    let lit: LitStr = value.parse()?;
+                    meta.deprecated = Some(lit.value());
+                }
+                // Handle `read_only` / `write_only` flags, which contradict each other
+                else if nested.path.is_ident("read_only") {
+                    if meta.write_only {
+                        return Err(nested.error("`read_only` can't be combined with `write_only`"));
+                    }
+                    meta.read_only = true;
+                }
+                else if nested.path.is_ident("write_only") {
+                    if meta.read_only {
+                        return Err(nested.error("`write_only` can't be combined with `read_only`"));
+                    }
+                    meta.write_only = true;
+                }
+                // Handle `trim` / `lowercase` / `uppercase` string transforms
+                else if nested.path.is_ident("trim") {
+                    meta.trim = true;
+                }
+                else if nested.path.is_ident("lowercase") {
+                    meta.lowercase = true;
+                }
+                else if nested.path.is_ident("uppercase") {
+                    meta.uppercase = true;
+                }
+                // Handle `required_with = "other_field"`
+                else if nested.path.is_ident("required_with") {
+                    let value = nested.value()?;
+                    let lit: LitStr = value.parse()?;
+                    meta.required_with = Some(lit);
+                }
+                // Handle `enum_values = ["a", "b"]`
+                else if nested.path.is_ident("enum_values") {
+                    let value = nested.value()?;
+                    let array: syn::ExprArray = value.parse()?;
+                    let values = array
+                        .elems
+                        .iter()
+                        .map(|elem| match elem {
+                            syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(lit), .. }) => Ok(lit.value()),
+                            _ => Err(syn::Error::new_spanned(elem, "enum_values only accepts string literals")),
+                        })
+                        .collect::<syn::Result<Vec<_>>>()?;
+                    meta.enum_values = Some(values);
+                }
+                // Handle `coerce` flag
+                else if nested.path.is_ident("coerce") {
+                    meta.coerce = true;
+                }
+                // Handle `raw_json` / `raw_json(parse)` flags
+                else if nested.path.is_ident("raw_json") {
+                    meta.raw_json = true;
+                    if nested.input.peek(syn::token::Paren) {
+                        let mut parse = false;
+                        nested.parse_nested_meta(|option| {
+                            if option.path.is_ident("parse") {
+                                parse = true;
+                                Ok(())
+                            } else {
+                                Err(option.error("expected `parse`"))
+                            }
+                        })?;
+                        meta.raw_json_parse = parse;
+                    }
+                }
+                // Handle `pattern = "^[a-z]+$"`
+                else if nested.path.is_ident("pattern") {
+                    let value = nested.value()?;
+                    let lit: LitStr = value.parse()?;
+                    meta.pattern = Some(lit.value());
                 }
-            });
+                // Handle `keyPattern = "^[a-z-]+$"`
+                else if nested.path.is_ident("keyPattern") {
+                    let value = nested.value()?;
+                    let lit: LitStr = value.parse()?;
+                    meta.key_pattern = Some(lit.value());
+                }
+                // Handle `format = "email"`
+                else if nested.path.is_ident("format") {
+                    let value = nested.value()?;
+                    let lit: LitStr = value.parse()?;
+                    meta.format = Some(lit.value());
+                }
+                // Handle `ts_template = "user_${string}"`
+                else if nested.path.is_ident("ts_template") {
+                    let value = nested.value()?;
+                    let lit: LitStr = value.parse()?;
+                    meta.ts_template = Some(lit.value());
+                }
+                // Handle `as_ts = "Date"`
+                else if nested.path.is_ident("as_ts") {
+                    let value = nested.value()?;
+                    let lit: LitStr = value.parse()?;
+                    meta.as_ts = Some(lit.value());
+                }
+                // Handle `as_zod = "z.coerce.date()"`
+                else if nested.path.is_ident("as_zod") {
+                    let value = nested.value()?;
+                    let lit: LitStr = value.parse()?;
+                    meta.as_zod = Some(lit.value());
+                } else {
+                    return Err(nested.error(
+                        "unsupported model_schema_prop option, expected `as`, `literal`, `minLength`, `maxLength`, `min`, `max`, `deprecated`, `read_only`, `write_only`, `trim`, `lowercase`, `uppercase`, `required_with`, `enum_values`, `coerce`, `raw_json`, `pattern`, `keyPattern`, `format`, `ts_template`, `as_ts` or `as_zod`",
+                    ));
+                }
+                Ok(())
+            })?;
         }
     }
 
-    meta
+    Ok(meta)
 }
 
 #[cfg(test)]
@@ -60,10 +304,14 @@ mod tests {
     use super::*;
     use syn::parse_quote;
 
+    fn as_type(meta: &ModelSchemaPropMeta) -> Option<String> {
+        meta.as_type.as_ref().map(|ty| quote::quote!(#ty).to_string())
+    }
+
     #[test]
     fn test_parse_empty_attributes() {
         let attrs: Vec<Attribute> = vec![];
-        let meta = parse_model_schema_prop_attributes(&attrs);
+        let meta = parse_model_schema_prop_attributes(&attrs).unwrap();
         assert!(meta.as_type.is_none());
         assert!(meta.literal.is_none());
         assert!(meta.min_length.is_none());
@@ -72,17 +320,27 @@ mod tests {
     #[test]
     fn test_parse_as_type() {
         let attr: Attribute = parse_quote! { #[model_schema_prop(as = String)] };
-        let meta = parse_model_schema_prop_attributes(&[attr]);
+        let meta = parse_model_schema_prop_attributes(&[attr]).unwrap();
         assert!(meta.as_type.is_some());
-        assert_eq!(meta.as_type.unwrap(), "String");
+        assert_eq!(as_type(&meta).as_deref(), Some("String"));
         assert!(meta.literal.is_none());
         assert!(meta.min_length.is_none());
     }
 
+    #[test]
+    fn test_parse_as_any() {
+        let attr: Attribute = parse_quote! { #[model_schema_prop(as = "any")] };
+        let meta = parse_model_schema_prop_attributes(&[attr]).unwrap();
+        assert_eq!(as_type(&meta).as_deref(), Some("serde_json :: Value"));
+
+        let attr: Attribute = parse_quote! { #[model_schema_prop(as = "whatever")] };
+        assert!(parse_model_schema_prop_attributes(&[attr]).is_err());
+    }
+
     #[test]
     fn test_parse_literal() {
         let attr: Attribute = parse_quote! { #[model_schema_prop(literal = "Tixena")] };
-        let meta = parse_model_schema_prop_attributes(&[attr]);
+        let meta = parse_model_schema_prop_attributes(&[attr]).unwrap();
         assert!(meta.as_type.is_none());
         assert!(meta.literal.is_some());
         assert_eq!(meta.literal.unwrap(), "Tixena");
@@ -92,9 +350,9 @@ mod tests {
     #[test]
     fn test_parse_both_as_and_literal() {
         let attr: Attribute = parse_quote! { #[model_schema_prop(as = String, literal = "Tixena")] };
-        let meta = parse_model_schema_prop_attributes(&[attr]);
+        let meta = parse_model_schema_prop_attributes(&[attr]).unwrap();
         assert!(meta.as_type.is_some());
-        assert_eq!(meta.as_type.unwrap(), "String");
+        assert_eq!(as_type(&meta).as_deref(), Some("String"));
         assert!(meta.literal.is_some());
         assert_eq!(meta.literal.unwrap(), "Tixena");
         assert!(meta.min_length.is_none());
@@ -103,19 +361,41 @@ mod tests {
     #[test]
     fn test_parse_min_length() {
         let attr: Attribute = parse_quote! { #[model_schema_prop(minLength = 1)] };
-        let meta = parse_model_schema_prop_attributes(&[attr]);
+        let meta = parse_model_schema_prop_attributes(&[attr]).unwrap();
         assert!(meta.as_type.is_none());
         assert!(meta.literal.is_none());
         assert!(meta.min_length.is_some());
         assert_eq!(meta.min_length.unwrap(), 1);
     }
 
+    #[test]
+    fn test_parse_min_and_max_length() {
+        let attr: Attribute = parse_quote! { #[model_schema_prop(minLength = 2, maxLength = 64)] };
+        let meta = parse_model_schema_prop_attributes(&[attr]).unwrap();
+
+        assert_eq!(meta.min_length, Some(2));
+        assert_eq!(meta.max_length, Some(64));
+    }
+
+    #[test]
+    fn test_parse_numeric_bounds() {
+        let attr: Attribute = parse_quote! { #[model_schema_prop(min = -10, max = 99.5)] };
+        let meta = parse_model_schema_prop_attributes(&[attr]).unwrap();
+
+        assert_eq!(meta.min.as_deref(), Some("-10"));
+        assert_eq!(meta.max.as_deref(), Some("99.5"));
+        assert!(!meta.is_empty());
+
+        let attr: Attribute = parse_quote! { #[model_schema_prop(min = 0u32)] };
+        assert_eq!(parse_model_schema_prop_attributes(&[attr]).unwrap().min.as_deref(), Some("0"));
+    }
+
     #[test]
     fn test_parse_as_and_min_length() {
         let attr: Attribute = parse_quote! { #[model_schema_prop(as = String, minLength = 5)] };
-        let meta = parse_model_schema_prop_attributes(&[attr]);
+        let meta = parse_model_schema_prop_attributes(&[attr]).unwrap();
         assert!(meta.as_type.is_some());
-        assert_eq!(meta.as_type.unwrap(), "String");
+        assert_eq!(as_type(&meta).as_deref(), Some("String"));
         assert!(meta.literal.is_none());
         assert!(meta.min_length.is_some());
         assert_eq!(meta.min_length.unwrap(), 5);
@@ -124,12 +404,185 @@ mod tests {
     #[test]
     fn test_parse_all_attributes() {
         let attr: Attribute = parse_quote! { #[model_schema_prop(as = String, literal = "test", minLength = 3)] };
-        let meta = parse_model_schema_prop_attributes(&[attr]);
+        let meta = parse_model_schema_prop_attributes(&[attr]).unwrap();
         assert!(meta.as_type.is_some());
-        assert_eq!(meta.as_type.unwrap(), "String");
+        assert_eq!(as_type(&meta).as_deref(), Some("String"));
         assert!(meta.literal.is_some());
         assert_eq!(meta.literal.unwrap(), "test");
         assert!(meta.min_length.is_some());
         assert_eq!(meta.min_length.unwrap(), 3);
     }
-} 
\ No newline at end of file
+
+    #[test]
+    fn test_parse_deprecated() {
+        let attr: Attribute = parse_quote! { #[model_schema_prop(deprecated = "use newField")] };
+        let meta = parse_model_schema_prop_attributes(&[attr]).unwrap();
+        assert_eq!(meta.deprecated.as_deref(), Some("use newField"));
+        assert!(meta.as_type.is_none());
+        assert!(!meta.is_empty());
+    }
+
+    #[test]
+    fn test_empty_meta_is_empty() {
+        let meta = parse_model_schema_prop_attributes(&[]).unwrap();
+        assert!(meta.is_empty());
+    }
+
+    #[test]
+    fn test_parse_read_and_write_only() {
+        let attr: Attribute = parse_quote! { #[model_schema_prop(read_only)] };
+        let meta = parse_model_schema_prop_attributes(&[attr]).unwrap();
+        assert!(meta.read_only);
+        assert!(!meta.write_only);
+        assert!(!meta.is_empty());
+
+        let attr: Attribute = parse_quote! { #[model_schema_prop(write_only, minLength = 8)] };
+        let meta = parse_model_schema_prop_attributes(&[attr]).unwrap();
+        assert!(!meta.read_only);
+        assert!(meta.write_only);
+        assert_eq!(meta.min_length, Some(8));
+    }
+
+    #[test]
+    fn test_parse_read_and_write_only_together_fails() {
+        let attr: Attribute = parse_quote! { #[model_schema_prop(read_only, write_only)] };
+        assert!(parse_model_schema_prop_attributes(&[attr]).is_err());
+
+        let attrs: Vec<Attribute> = vec![
+            parse_quote! { #[model_schema_prop(write_only)] },
+            parse_quote! { #[model_schema_prop(read_only)] },
+        ];
+        assert!(parse_model_schema_prop_attributes(&attrs).is_err());
+    }
+
+    #[test]
+    fn test_parse_string_transforms() {
+        let attr: Attribute = parse_quote! { #[model_schema_prop(uppercase, trim)] };
+        let meta = parse_model_schema_prop_attributes(&[attr]).unwrap();
+        assert!(meta.trim);
+        assert!(!meta.lowercase);
+        assert!(meta.uppercase);
+        assert!(!meta.is_empty());
+        assert_eq!(meta.string_transforms(), vec!["trim", "toUpperCase"]);
+    }
+
+    #[test]
+    fn test_parse_required_with() {
+        let attr: Attribute = parse_quote! { #[model_schema_prop(required_with = "country")] };
+        let meta = parse_model_schema_prop_attributes(&[attr]).unwrap();
+        assert_eq!(meta.required_with.as_ref().map(LitStr::value).as_deref(), Some("country"));
+        assert!(!meta.is_empty());
+    }
+
+    #[test]
+    fn test_parse_enum_values() {
+        let attr: Attribute = parse_quote! { #[model_schema_prop(enum_values = ["red", "green", "blue"])] };
+        let meta = parse_model_schema_prop_attributes(&[attr]).unwrap();
+        assert_eq!(
+            meta.enum_values,
+            Some(vec!["red".to_string(), "green".to_string(), "blue".to_string()])
+        );
+        assert!(!meta.is_empty());
+    }
+
+    #[test]
+    fn test_parse_raw_json() {
+        let attr: Attribute = parse_quote! { #[model_schema_prop(raw_json)] };
+        let meta = parse_model_schema_prop_attributes(&[attr]).unwrap();
+        assert!(meta.raw_json);
+        assert!(!meta.raw_json_parse);
+
+        let attr: Attribute = parse_quote! { #[model_schema_prop(raw_json(parse))] };
+        let meta = parse_model_schema_prop_attributes(&[attr]).unwrap();
+        assert!(meta.raw_json);
+        assert!(meta.raw_json_parse);
+    }
+
+    #[test]
+    fn test_parse_key_pattern() {
+        let attr: Attribute = parse_quote! { #[model_schema_prop(keyPattern = "^[a-z-]+$")] };
+        let meta = parse_model_schema_prop_attributes(&[attr]).unwrap();
+
+        assert_eq!(meta.key_pattern.as_deref(), Some("^[a-z-]+$"));
+        assert!(meta.pattern.is_none());
+        assert!(!meta.is_empty());
+    }
+
+    #[test]
+    fn test_parse_format() {
+        let attr: Attribute = parse_quote! { #[model_schema_prop(format = "email", maxLength = 254)] };
+        let meta = parse_model_schema_prop_attributes(&[attr]).unwrap();
+
+        assert_eq!(meta.format.as_deref(), Some("email"));
+        assert_eq!(meta.max_length, Some(254));
+    }
+
+    #[test]
+    fn test_parse_raw_overrides() {
+        let attr: Attribute = parse_quote! { #[model_schema_prop(as_ts = "Date", as_zod = "z.coerce.date()")] };
+        let meta = parse_model_schema_prop_attributes(&[attr]).unwrap();
+
+        assert_eq!(meta.as_ts.as_deref(), Some("Date"));
+        assert_eq!(meta.as_zod.as_deref(), Some("z.coerce.date()"));
+        assert!(!meta.is_empty());
+    }
+
+    #[test]
+    fn test_parse_coerce() {
+        let attr: Attribute = parse_quote! { #[model_schema_prop(coerce)] };
+        let meta = parse_model_schema_prop_attributes(&[attr]).unwrap();
+        assert!(meta.coerce);
+        assert!(!meta.is_empty());
+        assert!(!parse_model_schema_prop_attributes(&[]).unwrap().coerce);
+    }
+
+    #[test]
+    fn test_parse_ts_template() {
+        let attr: Attribute = parse_quote! { #[model_schema_prop(ts_template = "user_${string}")] };
+        let meta = parse_model_schema_prop_attributes(&[attr]).unwrap();
+        assert_eq!(meta.ts_template.as_deref(), Some("user_${string}"));
+        assert!(!meta.is_empty());
+    }
+
+    #[test]
+    fn test_parse_pattern() {
+        let attr: Attribute = parse_quote! { #[model_schema_prop(pattern = "^[a-z]+$")] };
+        let meta = parse_model_schema_prop_attributes(&[attr]).unwrap();
+        assert_eq!(meta.pattern.as_deref(), Some("^[a-z]+$"));
+        assert!(!meta.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_js_regex_source() {
+        assert_eq!(js_regex_source("^a/b$"), r"^a\/b$");
+        assert_eq!(js_regex_source(r"^a\/b$"), r"^a\/b$");
+        assert_eq!(js_regex_source(r"^a\\/b$"), r"^a\\\/b$");
+    }
+
+    #[test]
+    fn test_ts_template_pattern() {
+        assert_eq!(ts_template_pattern("user_${string}").unwrap(), "^user_.*$");
+        assert_eq!(ts_template_pattern("v${number}.x").unwrap(), r"^v-?\d+(\.\d+)?\.x$");
+        assert_eq!(ts_template_pattern("a/b").unwrap(), r"^a\/b$");
+        assert!(ts_template_pattern("id_${bigint}").unwrap_err().contains("`${bigint}`"));
+        assert!(ts_template_pattern("id_${string").is_err());
+    }
+
+    #[test]
+    fn test_parse_unknown_option_fails() {
+        let attr: Attribute = parse_quote! { #[model_schema_prop(readonly)] };
+        assert!(parse_model_schema_prop_attributes(&[attr]).is_err());
+
+        let attr: Attribute = parse_quote! { #[model_schema_prop(min_length = 3)] };
+        let err = parse_model_schema_prop_attributes(&[attr]).unwrap_err();
+        assert!(err.to_string().starts_with("unsupported model_schema_prop option"));
+    }
+
+    #[test]
+    fn test_parse_enum_values_rejects_non_strings() {
+        let attr: Attribute = parse_quote! { #[model_schema_prop(enum_values = ["red", 2])] };
+        let err = parse_model_schema_prop_attributes(&[attr]).unwrap_err();
+        assert_eq!(err.to_string(), "enum_values only accepts string literals");
+    }
+}
@@ -11,12 +11,49 @@ pub fn is_object_id_type(type_name: &str) -> bool {
 
 /// Generates TypeScript type name for ObjectId
 pub fn get_object_id_typescript_type() -> String {
-    "ObjectId".to_string()
+    if cfg!(feature = "object_id_flexible") {
+        "ObjectId | string".to_string()
+    } else {
+        "ObjectId".to_string()
+    }
+}
+
+/// Zod schema for ObjectId: the extended JSON `{ $oid }` form, or with `object_id_flexible` a
+/// union also accepting the plain hex string
+#[cfg(all(feature = "object_id", any(test, feature = "zod")))]
+pub fn get_object_id_zod_schema(zod: &str) -> String {
+    let extended = format!("{zod}.object({{ $oid: {} }})", get_object_id_key_zod_schema(zod));
+    if cfg!(feature = "object_id_flexible") {
+        format!("{zod}.union([{extended}, {}])", get_object_id_key_zod_schema(zod))
+    } else {
+        extended
+    }
+}
+
+#[cfg(all(feature = "object_id", feature = "io_ts"))]
+pub fn get_object_id_io_ts_codec() -> String {
+    if cfg!(feature = "object_id_flexible") {
+        "t.union([t.type({ $oid: t.string }), t.string])".to_string()
+    } else {
+        "t.type({ $oid: t.string })".to_string()
+    }
+}
+
+/// TypeScript key type for `HashMap<ObjectId, T>`: JSON object keys are always strings, and
+/// ObjectId keys serialize as their hex form
+pub fn get_object_id_key_typescript_type() -> String {
+    "string".to_string()
 }
 
 #[cfg(all(feature = "object_id", any(test, feature = "zod")))]
-pub fn get_object_id_zod_schema() -> String {
-    "z.object({ $oid: z.string().regex(/^[a-f\\d]{24}$/i, { message: \"Invalid ObjectId\" }) })".to_string()
+pub fn get_object_id_key_zod_schema(zod: &str) -> String {
+    format!("{zod}.string().regex(/^[a-f\\d]{{24}}$/i, {{ message: \"Invalid ObjectId\" }})")
+}
+
+/// JSON schema `pattern` for ObjectId map keys (JSON schema regexes have no `i` flag)
+#[cfg(feature = "object_id")]
+pub fn get_object_id_key_pattern() -> &'static str {
+    "^[a-fA-F0-9]{24}$"
 }
 
 /// Check if we should handle this type as ObjectId
@@ -36,14 +73,38 @@ mod tests {
     }
 
     #[test]
+    #[cfg(not(feature = "object_id_flexible"))]
     fn test_object_id_typescript_type() {
         assert_eq!(get_object_id_typescript_type(), "ObjectId");
     }
 
+    #[test]
+    #[cfg(feature = "object_id_flexible")]
+    fn test_object_id_flexible_schemas() {
+        assert_eq!(get_object_id_typescript_type(), "ObjectId | string");
+        assert_eq!(
+            get_object_id_zod_schema("z"),
+            format!(
+                "z.union([z.object({{ $oid: {key} }}), {key}])",
+                key = get_object_id_key_zod_schema("z")
+            )
+        );
+    }
+
+    #[cfg(feature = "object_id")]
+    #[test]
+    fn test_object_id_key_schemas() {
+        assert_eq!(get_object_id_key_typescript_type(), "string");
+        assert!(get_object_id_key_zod_schema("z").starts_with("z.string().regex("));
+        assert!(get_object_id_key_zod_schema("zod").starts_with("zod.string().regex("));
+        assert!(!get_object_id_key_zod_schema("z").contains("$oid"));
+        assert_eq!(get_object_id_key_pattern(), "^[a-fA-F0-9]{24}$");
+    }
+
     #[cfg(feature = "object_id")]
     #[test]
     fn test_object_id_zod_schema() {
-        let schema = get_object_id_zod_schema();
+        let schema = get_object_id_zod_schema("z");
         assert!(schema.contains("$oid"));
         assert!(schema.contains("regex"));
         assert!(schema.contains("24"));
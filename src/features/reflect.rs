@@ -0,0 +1,24 @@
+//! Reflection feature module
+//!
+//! This module generates the `ts_fields()` method when the "reflect" feature is enabled: the
+//! serialized name and TypeScript type of each struct field, for consumers assembling their own
+//! output (GraphQL inputs, form schemas, ...) instead of using `ts_definition()`.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::field_type::FieldDef;
+
+/// Generates `ts_fields()` for a struct, listing its fields in declaration order.
+pub fn generate_ts_fields_method(fields: &[FieldDef]) -> TokenStream {
+    let pairs = fields.iter().map(|fld| {
+        let name = &fld.name;
+        let ts_type = fld.typescript_typename();
+        quote! { (#name.to_string(), #ts_type.to_string()) }
+    });
+    quote! {
+        pub fn ts_fields() -> Vec<(String, String)> {
+            vec![#(#pairs),*]
+        }
+    }
+}
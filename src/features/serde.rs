@@ -3,20 +3,39 @@
 //! This module handles serde attribute parsing and field name transformation
 //! when the "serde" feature is enabled.
 
-use syn::{Attribute, LitStr};
+use syn::{Attribute, LitStr, meta::ParseNestedMeta};
 
 /// Metadata for serde attributes applied to a struct or enum.
 #[derive(Clone, Debug, Default)]
 pub struct SerdeTypeMeta {
     pub tag: Option<String>,        // e.g., "behaviorType"
     pub rename_all: Option<String>, // e.g., "camelCase"
+    pub rename_all_fields: Option<String>, // e.g., "camelCase", applied to struct variant fields
+    pub untagged: bool,             // Whether the enum's variants are written without a tag
+    pub transparent: bool,          // Whether the struct is written as its only field
 }
 
 /// Metadata for serde attributes applied to a field.
 #[derive(Clone, Debug, Default)]
 pub struct SerdeFieldMeta {
     pub rename: Option<String>, // e.g., "new_name"
-    pub skip: bool,             // Whether to skip the field
+    pub rename_all: Option<String>, // e.g., "camelCase", only meaningful on enum variants
+    pub skip_serializing: bool, // Whether the field is never written, from skip or skip_serializing
+    pub skip_deserializing: bool, // Whether the field is never read, from skip or skip_deserializing
+    pub flatten: bool,          // Whether the field is flattened into its parent
+    pub default: bool,          // Whether a missing field is filled in on deserialization
+    pub other: bool,            // Whether the unit variant is the catch-all for unknown values
+}
+
+/// Consumes the value of a serde key the macro doesn't use, e.g. `alias = "..."` or
+/// `bound(serialize = "...")`, so the keys after it are still read.
+fn skip_value(nested: &ParseNestedMeta) -> syn::Result<()> {
+    if nested.input.peek(syn::Token![=]) {
+        let _: syn::Expr = nested.value()?.parse()?;
+    } else if nested.input.peek(syn::token::Paren) {
+        let _: proc_macro2::Group = nested.input.parse()?;
+    }
+    Ok(())
 }
 
 /// Parses serde attributes from a struct or enum.
@@ -38,13 +57,27 @@ pub fn parse_serde_type_attributes(attrs: &[Attribute]) -> SerdeTypeMeta {
                     let lit: LitStr = value.parse()?;
                     meta.rename_all = Some(lit.value());
                 }
+                // Handle `rename_all_fields = "value"`
+                else if nested.path.is_ident("rename_all_fields") {
+                    let value = nested.value()?;
+                    let lit: LitStr = value.parse()?;
+                    meta.rename_all_fields = Some(lit.value());
+                }
+                // Handle `untagged`
+                else if nested.path.is_ident("untagged") {
+                    meta.untagged = true;
+                }
+                // Handle `transparent`
+                else if nested.path.is_ident("transparent") {
+                    meta.transparent = true;
+                }
+                else {
+                    skip_value(&nested)?;
+                }
                 Ok(())
             })
-            .unwrap_or_else(|e| {
-                if std::env::var("RUST_LOG") == Ok(String::from("trace")) {
-                    println!("Failed to parse serde type attribute: {e}");
-                }
-            });
+            // A malformed serde attribute is reported by serde's own derive
+            .ok();
         }
     }
 
@@ -64,42 +97,57 @@ pub fn parse_serde_field_attributes(attrs: &[Attribute]) -> SerdeFieldMeta {
                     let lit: LitStr = value.parse()?;
                     meta.rename = Some(lit.value());
                 }
-                // Handle `skip` or `skip_serializing_if`
-                else if nested.path.is_ident("skip")
-                    || nested.path.is_ident("skip_serializing")
-                    || nested.path.is_ident("skip_deserializing")
-                    || nested.path.is_ident("skip_serializing_if")
-                {
-                    meta.skip = true;
+                // Handle `rename_all = "value"` on an enum variant
+                else if nested.path.is_ident("rename_all") {
+                    let value = nested.value()?;
+                    let lit: LitStr = value.parse()?;
+                    meta.rename_all = Some(lit.value());
+                }
+                // Handle `skip`, `skip_serializing` and `skip_deserializing`. A
+                // `skip_serializing_if` field is only sometimes left out, so it keeps its place
+                // in the schemas.
+                else if nested.path.is_ident("skip") {
+                    meta.skip_serializing = true;
+                    meta.skip_deserializing = true;
+                }
+                else if nested.path.is_ident("skip_serializing") {
+                    meta.skip_serializing = true;
+                }
+                else if nested.path.is_ident("skip_deserializing") {
+                    meta.skip_deserializing = true;
+                }
+                else if nested.path.is_ident("skip_serializing_if") {
+                    // The value is a path to the predicate, consumed so parsing can go on
+                    let _: LitStr = nested.value()?.parse()?;
+                }
+                // Handle `flatten`
+                else if nested.path.is_ident("flatten") {
+                    meta.flatten = true;
+                }
+                // Handle `default` or `default = "path"`
+                else if nested.path.is_ident("default") {
+                    if nested.input.peek(syn::Token![=]) {
+                        let _: LitStr = nested.value()?.parse()?;
+                    }
+                    meta.default = true;
+                }
+                // Handle `other` on an enum variant
+                else if nested.path.is_ident("other") {
+                    meta.other = true;
+                }
+                else {
+                    skip_value(&nested)?;
                 }
                 Ok(())
             })
-            .unwrap_or_else(|e| {
-                if std::env::var("RUST_LOG") == Ok(String::from("trace")) {
-                    println!("Failed to parse serde field attribute: {e}");
-                }
-            });
+            // A malformed serde attribute is reported by serde's own derive
+            .ok();
         }
     }
 
     meta
 }
 
-/// Applies serde rename_all transformation to a field name
-#[cfg(test)]
-pub fn apply_rename_all(field_name: &str, rename_all: &Option<String>) -> String {
-    match rename_all.as_deref() {
-        Some("camelCase") => to_camel_case(field_name),
-        Some("PascalCase") => to_pascal_case(field_name),
-        Some("snake_case") => field_name.to_string(), // Already snake_case
-        Some("SCREAMING_SNAKE_CASE") => field_name.to_uppercase(),
-        Some("kebab-case") => to_kebab_case(field_name),
-        Some("lowercase") => field_name.to_lowercase(),
-        Some("UPPERCASE") => field_name.to_uppercase(),
-        _ => field_name.to_string(),
-    }
-}
-
 /// Get the final field name after applying serde transformations
 #[cfg(test)]
 pub fn get_final_field_name(
@@ -113,78 +161,32 @@ pub fn get_final_field_name(
     }
 
     // Otherwise apply rename_all transformation
-    apply_rename_all(&original_name, &type_meta.rename_all)
-}
-
-/// Convert snake_case to camelCase
-#[cfg(test)]
-fn to_camel_case(s: &str) -> String {
-    let mut result = String::new();
-    let mut capitalize_next = false;
-
-    for (i, c) in s.chars().enumerate() {
-        if c == '_' {
-            capitalize_next = true;
-        } else if capitalize_next && i > 0 {
-            result.push(c.to_ascii_uppercase());
-            capitalize_next = false;
-        } else {
-            result.push(c);
-            capitalize_next = false;
-        }
-    }
-
-    result
-}
-
-/// Convert snake_case to PascalCase
-#[cfg(test)]
-fn to_pascal_case(s: &str) -> String {
-    let camel = to_camel_case(s);
-    if let Some(first_char) = camel.chars().next() {
-        format!("{}{}", first_char.to_ascii_uppercase(), &camel[1..])
-    } else {
-        camel
-    }
-}
-
-/// Convert snake_case to kebab-case
-#[cfg(test)]
-fn to_kebab_case(s: &str) -> String {
-    s.replace('_', "-")
+    crate::features::casing::apply_rename_all(&original_name, &type_meta.rename_all)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_rename_all_transformations() {
-        // Test camelCase
-        assert_eq!(apply_rename_all("user_name", &Some("camelCase".to_string())), "userName");
-        assert_eq!(apply_rename_all("first_name", &Some("camelCase".to_string())), "firstName");
-        
-        // Test PascalCase
-        assert_eq!(apply_rename_all("user_name", &Some("PascalCase".to_string())), "UserName");
-        
-        // Test kebab-case
-        assert_eq!(apply_rename_all("user_name", &Some("kebab-case".to_string())), "user-name");
-        
-        // Test no transformation
-        assert_eq!(apply_rename_all("user_name", &None), "user_name");
-    }
-
     #[test]
     fn test_final_field_name() {
         let type_meta = SerdeTypeMeta {
             tag: None,
             rename_all: Some("camelCase".to_string()),
+            rename_all_fields: None,
+            untagged: false,
+            transparent: false,
         };
 
         // Test field with explicit rename
         let field_meta_with_rename = SerdeFieldMeta {
             rename: Some("customName".to_string()),
-            skip: false,
+            rename_all: None,
+            skip_serializing: false,
+            skip_deserializing: false,
+            flatten: false,
+            default: false,
+            other: false,
         };
         assert_eq!(
             get_final_field_name("field_name".to_string(), &field_meta_with_rename, &type_meta),
@@ -194,11 +196,121 @@ mod tests {
         // Test field with rename_all
         let field_meta_no_rename = SerdeFieldMeta {
             rename: None,
-            skip: false,
+            rename_all: None,
+            skip_serializing: false,
+            skip_deserializing: false,
+            flatten: false,
+            default: false,
+            other: false,
         };
         assert_eq!(
             get_final_field_name("field_name".to_string(), &field_meta_no_rename, &type_meta),
             "fieldName"
         );
     }
-} 
\ No newline at end of file
+
+    #[test]
+    fn test_parse_enum_renames() {
+        use syn::parse_quote;
+
+        let attr: Attribute = parse_quote! {
+            #[serde(tag = "kind", rename_all = "camelCase", rename_all_fields = "snake_case")]
+        };
+        let meta = parse_serde_type_attributes(&[attr]);
+        assert_eq!(meta.tag.as_deref(), Some("kind"));
+        assert_eq!(meta.rename_all.as_deref(), Some("camelCase"));
+        assert_eq!(meta.rename_all_fields.as_deref(), Some("snake_case"));
+        assert!(!meta.untagged);
+
+        let attr: Attribute = parse_quote! { #[serde(rename_all = "camelCase")] };
+        let variant_meta = parse_serde_field_attributes(&[attr]);
+        assert_eq!(variant_meta.rename_all.as_deref(), Some("camelCase"));
+        assert!(variant_meta.rename.is_none());
+    }
+
+    #[test]
+    fn test_parse_flatten() {
+        use syn::parse_quote;
+
+        let attr: Attribute = parse_quote! { #[serde(flatten)] };
+        let meta = parse_serde_field_attributes(&[attr]);
+        assert!(meta.flatten);
+        assert!(!meta.skip_serializing && !meta.skip_deserializing);
+    }
+
+    #[test]
+    fn test_parse_default() {
+        use syn::parse_quote;
+
+        let attr: Attribute = parse_quote! { #[serde(default)] };
+        assert!(parse_serde_field_attributes(&[attr]).default);
+
+        let attr: Attribute = parse_quote! { #[serde(default = "default_limit", rename = "max")] };
+        let meta = parse_serde_field_attributes(&[attr]);
+        assert!(meta.default);
+        assert_eq!(meta.rename.as_deref(), Some("max"));
+
+        let attr: Attribute = parse_quote! { #[serde(rename = "max")] };
+        assert!(!parse_serde_field_attributes(&[attr]).default);
+    }
+
+    #[test]
+    fn test_parse_after_unused_keys() {
+        use syn::parse_quote;
+
+        let attr: Attribute = parse_quote! { #[serde(alias = "limit", default)] };
+        assert!(parse_serde_field_attributes(&[attr]).default);
+
+        let attr: Attribute = parse_quote! { #[serde(with = "audit_format", flatten)] };
+        assert!(parse_serde_field_attributes(&[attr]).flatten);
+
+        let attr: Attribute = parse_quote! { #[serde(deserialize_with = "parse_note", skip_serializing, rename = "note")] };
+        let meta = parse_serde_field_attributes(&[attr]);
+        assert!(meta.skip_serializing);
+        assert_eq!(meta.rename.as_deref(), Some("note"));
+
+        let attr: Attribute = parse_quote! { #[serde(bound(serialize = "T: Serialize"), rename_all = "camelCase")] };
+        assert_eq!(parse_serde_type_attributes(&[attr]).rename_all.as_deref(), Some("camelCase"));
+    }
+
+    #[test]
+    fn test_parse_skip() {
+        use syn::parse_quote;
+
+        let attr: Attribute = parse_quote! { #[serde(skip)] };
+        let meta = parse_serde_field_attributes(&[attr]);
+        assert!(meta.skip_serializing && meta.skip_deserializing);
+
+        let attr: Attribute = parse_quote! { #[serde(skip_serializing)] };
+        let meta = parse_serde_field_attributes(&[attr]);
+        assert!(meta.skip_serializing && !meta.skip_deserializing);
+
+        let attr: Attribute = parse_quote! { #[serde(skip_deserializing)] };
+        let meta = parse_serde_field_attributes(&[attr]);
+        assert!(!meta.skip_serializing && meta.skip_deserializing);
+
+        let attr: Attribute = parse_quote! { #[serde(skip_serializing_if = "Option::is_none", rename = "note")] };
+        let meta = parse_serde_field_attributes(&[attr]);
+        assert!(!meta.skip_serializing && !meta.skip_deserializing);
+        assert_eq!(meta.rename.as_deref(), Some("note"));
+    }
+
+    #[test]
+    fn test_parse_untagged() {
+        use syn::parse_quote;
+
+        let attr: Attribute = parse_quote! { #[serde(untagged, rename_all = "camelCase")] };
+        let meta = parse_serde_type_attributes(&[attr]);
+        assert!(meta.untagged);
+        assert_eq!(meta.rename_all.as_deref(), Some("camelCase"));
+    }
+
+    #[test]
+    fn test_parse_transparent() {
+        use syn::parse_quote;
+
+        let attr: Attribute = parse_quote! { #[serde(transparent)] };
+        assert!(parse_serde_type_attributes(&[attr]).transparent);
+        assert!(!parse_serde_type_attributes(&[]).transparent);
+    }
+}
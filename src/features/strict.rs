@@ -0,0 +1,132 @@
+//! Strict mode diagnostics module
+//!
+//! This module builds the warnings emitted by `#[model_schema(strict)]`, which the macro
+//! reports as deprecation warnings on the annotated type. A container's `rename_all` doesn't
+//! propagate to the types its fields reference, and the macro only sees the type it is applied
+//! to, so it can't check that a nested type uses the same casing. Strict mode points out every
+//! such reference so it can be checked by hand.
+
+use std::collections::BTreeSet;
+
+use crate::field_type::FieldDef;
+
+/// Wrapper and collection types that can show up as sibling types but have no fields to rename
+const CONTAINER_TYPES: &[&str] = &["Vec", "HashSet", "HashMap", "BTreeMap", "Option", "Box"];
+
+/// Returns a warning for each sibling type referenced by `fields` of a container whose
+/// fields are renamed with `rename_all`. Nothing is reported when fields aren't renamed.
+pub fn nested_casing_warnings(
+    container: &str,
+    rename_all: &Option<String>,
+    fields: &[FieldDef],
+) -> Vec<String> {
+    let Some(rename_all) = rename_all else {
+        return Vec::new();
+    };
+
+    let mut warnings = Vec::new();
+    for fld in fields {
+        let mut deps = BTreeSet::new();
+        fld.collect_dependencies(&mut deps);
+        for dep in deps.iter().filter(|dep| !CONTAINER_TYPES.contains(&dep.as_str())) {
+            warnings.push(format!(
+                "`{container}` renames its fields to {rename_all}, but field `{}` references `{dep}`, whose casing tixschema can't check: rename_all doesn't apply to nested types, make sure `{dep}` declares its own",
+                fld.name
+            ));
+        }
+    }
+    warnings
+}
+
+/// Returns a warning for each flattened struct of a container whose fields are renamed with
+/// `rename_all`. The flattened struct's keys are merged into the container's as that struct
+/// serializes them, so its own `rename_all` decides their casing.
+pub fn flattened_casing_warnings(
+    container: &str,
+    rename_all: &Option<String>,
+    flattened: &[FieldDef],
+) -> Vec<String> {
+    let Some(rename_all) = rename_all else {
+        return Vec::new();
+    };
+
+    let mut deps = BTreeSet::new();
+    flattened.iter().for_each(|fld| fld.collect_dependencies(&mut deps));
+    deps.iter()
+        .map(|dep| format!(
+            "`{container}` renames its fields to {rename_all}, but flattens `{dep}`, whose casing tixschema can't check: rename_all doesn't apply to flattened types, make sure `{dep}` declares the same one or its keys will be cased differently"
+        ))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field_type::FieldDefType;
+
+    fn field(name: &str, field_type: FieldDefType) -> FieldDef {
+        FieldDef {
+            is_optional: false,
+            name: name.to_string(),
+            docs: String::new(),
+            field_type,
+            is_array: false,
+            array_num: None,
+            model_schema_prop_meta: None,
+            is_non_zero: false,
+            is_set: false,
+            description: None,
+        }
+    }
+
+    #[test]
+    fn test_warns_for_map_value_siblings() {
+        let fields = vec![
+            field("id", FieldDefType::String),
+            field(
+                "data",
+                FieldDefType::Map(
+                    Box::new(field("", FieldDefType::String)),
+                    Box::new(field("", FieldDefType::SiblingType("Item".to_string(), vec![], None))),
+                ),
+            ),
+        ];
+
+        let warnings = nested_casing_warnings("InventoryJson", &Some("camelCase".to_string()), &fields);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].starts_with("`InventoryJson` renames its fields to camelCase"));
+        assert!(warnings[0].contains("field `data` references `Item`"));
+    }
+
+    #[test]
+    fn test_no_warnings_without_rename_all() {
+        let fields = vec![field("item", FieldDefType::SiblingType("Item".to_string(), vec![], None))];
+        assert!(nested_casing_warnings("InventoryJson", &None, &fields).is_empty());
+    }
+
+    #[test]
+    fn test_warns_for_flattened_types() {
+        let flattened = vec![field("audit", FieldDefType::SiblingType("AuditStamp".to_string(), vec![], None))];
+
+        let warnings = flattened_casing_warnings("OrderJson", &Some("camelCase".to_string()), &flattened);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("but flattens `AuditStamp`"));
+        assert!(flattened_casing_warnings("OrderJson", &None, &flattened).is_empty());
+    }
+
+    #[test]
+    fn test_skips_container_types() {
+        let fields = vec![field(
+            "rows",
+            FieldDefType::SiblingType(
+                "Vec".to_string(),
+                vec![field("", FieldDefType::SiblingType("Row".to_string(), vec![], None))],
+                None,
+            ),
+        )];
+
+        let warnings = nested_casing_warnings("TableJson", &Some("lowercase".to_string()), &fields);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("references `Row`"));
+    }
+}
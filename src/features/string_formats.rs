@@ -0,0 +1,125 @@
+//! String formats module
+//!
+//! This module maps scalar types that serde serializes as strings with a well-known format
+//! (`std::net::IpAddr` / `Ipv4Addr` / `Ipv6Addr`, `uuid::Uuid` with the `uuid` feature, and
+//! `chrono::DateTime<Tz>`, `NaiveDate` and `NaiveDateTime` with the `chrono` feature) to their
+//! Zod validators and JSON schema `format`s, as well as the formats `String` fields can be given
+//! with `model_schema_prop(format = "...")`.
+
+/// A string with a known format.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StringFormat {
+    Uuid,
+    Ipv4,
+    Ipv6,
+    Ip, // either an IPv4 or an IPv6 address
+    DateTime,
+    Date,          // chrono's NaiveDate, `2024-05-31`
+    #[cfg_attr(not(feature = "chrono"), allow(dead_code))]
+    LocalDateTime, // chrono's NaiveDateTime, `2024-05-31T12:00:00` without an offset
+    Email,         // only from model_schema_prop(format = "email")
+    Uri,           // only from model_schema_prop(format = "uri")
+}
+
+impl StringFormat {
+    /// The format named by a JSON schema `format` value, for the ones Zod has a validator for.
+    pub fn from_json_schema_format(format: &str) -> Option<StringFormat> {
+        match format {
+            "uuid" => Some(StringFormat::Uuid),
+            "ipv4" => Some(StringFormat::Ipv4),
+            "ipv6" => Some(StringFormat::Ipv6),
+            "date-time" => Some(StringFormat::DateTime),
+            "date" => Some(StringFormat::Date),
+            "email" => Some(StringFormat::Email),
+            "uri" => Some(StringFormat::Uri),
+            _ => None,
+        }
+    }
+
+    /// Returns the Zod v4 schema validating the format, calling Zod through `zod`.
+    #[cfg(any(test, feature = "zod"))]
+    pub fn zod_schema(&self, zod: &str) -> String {
+        match self {
+            StringFormat::Uuid => format!("{zod}.uuid()"),
+            StringFormat::Ipv4 => format!("{zod}.ipv4()"),
+            StringFormat::Ipv6 => format!("{zod}.ipv6()"),
+            StringFormat::Ip => format!("{zod}.union([{zod}.ipv4(), {zod}.ipv6()])"),
+            StringFormat::DateTime => format!("{zod}.iso.datetime({{ offset: true }})"),
+            StringFormat::Date => format!("{zod}.iso.date()"),
+            StringFormat::LocalDateTime => format!("{zod}.iso.datetime({{ local: true }})"),
+            StringFormat::Email => format!("{zod}.email()"),
+            StringFormat::Uri => format!("{zod}.url()"),
+        }
+    }
+
+    /// Returns the JSON schema `format` values the string may have, any of which is valid.
+    /// Empty when no standard format fits, see `json_schema_pattern`.
+    pub fn json_schema_formats(&self) -> &'static [&'static str] {
+        match self {
+            StringFormat::Uuid => &["uuid"],
+            StringFormat::Ipv4 => &["ipv4"],
+            StringFormat::Ipv6 => &["ipv6"],
+            StringFormat::Ip => &["ipv4", "ipv6"],
+            StringFormat::DateTime => &["date-time"],
+            StringFormat::Date => &["date"],
+            // `date-time` requires an offset
+            StringFormat::LocalDateTime => &[],
+            StringFormat::Email => &["email"],
+            StringFormat::Uri => &["uri"],
+        }
+    }
+
+    /// Returns the JSON schema `pattern` of formats without a standard `format`.
+    pub fn json_schema_pattern(&self) -> Option<&'static str> {
+        match self {
+            StringFormat::LocalDateTime => Some(r"^\d{4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}(\.\d+)?$"),
+            _ => None,
+        }
+    }
+
+    /// Returns a valid placeholder value, for `default_json()`.
+    #[cfg(any(test, feature = "fixtures"))]
+    pub fn placeholder(&self) -> &'static str {
+        match self {
+            StringFormat::Uuid => "00000000-0000-0000-0000-000000000000",
+            StringFormat::Ipv4 | StringFormat::Ip => "0.0.0.0",
+            StringFormat::Ipv6 => "::",
+            StringFormat::DateTime => "1970-01-01T00:00:00Z",
+            StringFormat::Date => "1970-01-01",
+            StringFormat::LocalDateTime => "1970-01-01T00:00:00",
+            StringFormat::Email => "user@example.com",
+            StringFormat::Uri => "https://example.com",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_schemas() {
+        assert_eq!(StringFormat::Uuid.zod_schema("z"), "z.uuid()");
+        assert_eq!(StringFormat::Ip.zod_schema("zod"), "zod.union([zod.ipv4(), zod.ipv6()])");
+        assert_eq!(StringFormat::Uuid.json_schema_formats(), &["uuid"]);
+        assert_eq!(StringFormat::Ip.json_schema_formats(), &["ipv4", "ipv6"]);
+        assert_eq!(StringFormat::DateTime.placeholder(), "1970-01-01T00:00:00Z");
+        assert_eq!(StringFormat::Date.json_schema_formats(), &["date"]);
+        assert!(StringFormat::LocalDateTime.json_schema_formats().is_empty());
+        assert!(StringFormat::LocalDateTime.json_schema_pattern().is_some());
+        assert!(StringFormat::DateTime.json_schema_pattern().is_none());
+    }
+
+    #[test]
+    fn test_from_json_schema_format() {
+        assert_eq!(StringFormat::from_json_schema_format("email"), Some(StringFormat::Email));
+        assert_eq!(StringFormat::from_json_schema_format("date-time"), Some(StringFormat::DateTime));
+        assert_eq!(StringFormat::from_json_schema_format("uri"), Some(StringFormat::Uri));
+        assert_eq!(StringFormat::from_json_schema_format("hostname"), None);
+        // Every format found by name maps back to it
+        for name in ["uuid", "ipv4", "ipv6", "date-time", "date", "email", "uri"] {
+            let format = StringFormat::from_json_schema_format(name).unwrap();
+            assert_eq!(format.json_schema_formats(), &[name]);
+        }
+    }
+}
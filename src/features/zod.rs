@@ -43,6 +43,16 @@ pub fn wrap_with_optional_if_needed(schema: &str, is_optional: bool) -> String {
     }
 }
 
+/// Appends `.describe("...")` with a field's doc comment to its Zod schema, for tooling such as
+/// `zod-to-json-schema` that reads descriptions from the schema.
+#[cfg(feature = "zod_describe")]
+pub fn with_description(schema: String, description: Option<&str>) -> String {
+    match description {
+        Some(description) => format!("{schema}.describe({})", crate::utils::js_string_literal(description)),
+        None => schema,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -65,4 +75,14 @@ mod tests {
         assert_eq!(wrap_with_optional_if_needed("z.string()", false), "z.string()");
         assert_eq!(wrap_with_optional_if_needed("z.string()", true), "z.string().or(z.undefined())");
     }
-} 
\ No newline at end of file
+
+    #[test]
+    #[cfg(feature = "zod_describe")]
+    fn test_with_description() {
+        assert_eq!(with_description("z.string()".to_string(), None), "z.string()");
+        assert_eq!(
+            with_description("z.string()".to_string(), Some("Seat \"row\"\nC:\\venue")),
+            "z.string().describe(\"Seat \\\"row\\\"\\nC:\\\\venue\")"
+        );
+    }
+}
@@ -5,16 +5,24 @@ use syn::{
 #[cfg(feature = "serde")]
 use syn::Attribute;
 
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::features::model_schema_args::ExternType;
+use crate::features::string_formats::StringFormat;
 use crate::safe_type_name;
 
 #[derive(Clone, Debug)]
 pub(crate) enum FieldDefType {
     Unknown,
-    SiblingType(String, Vec<FieldDef>),
+    // The type's name without the `Json` suffix, its generic arguments, and the module it was
+    // referenced through, e.g. `super::venue` for `super::venue::VenueJson`
+    SiblingType(String, Vec<FieldDef>, Option<syn::Path>),
     Map(Box<FieldDef>, Box<FieldDef>),
     Tuple(Vec<FieldDef>),
+    Nested(Box<FieldDef>), // The elements of a sequence that are sequences or optional themselves, e.g. Vec<Vec<u32>> or Vec<Option<u32>>
     Boolean,
     String,
+    Char, // A string of exactly one character
     StringLiteral(String),  // For string literal types like "Tixena"
     U8,
     U16,
@@ -28,6 +36,9 @@ pub(crate) enum FieldDefType {
     Isize,
     F32,
     F64,
+    Number, // serde_json::Number, either an integer or a float
+    FormattedString(StringFormat), // e.g. Uuid, IpAddr, DateTime<Utc>
+    External(ExternType), // mapped with model_schema(extern_map(...))
 
     #[cfg(feature = "object_id")]
     ObjectId,
@@ -41,19 +52,256 @@ pub(crate) struct FieldDef {
     pub field_type: FieldDefType,
     pub is_array: bool,
     pub array_num: Option<u16>,
+    pub is_non_zero: bool,  // For std::num::NonZero* integers
+    pub is_set: bool,       // For HashSet/BTreeSet/IndexSet, whose items are unique
+    /// The field's doc comment, for the Zod `.describe()` of `zod_describe`
+    #[cfg_attr(not(feature = "zod_describe"), allow(dead_code))]
+    pub description: Option<String>,
     pub model_schema_prop_meta: Option<crate::features::model_schema_prop::ModelSchemaPropMeta>,
 }
 
+/// The JSON value serde writes a variant of an untagged enum as.
+#[cfg(feature = "serde")]
+#[derive(Clone, Debug)]
+pub(crate) enum VariantShape {
+    /// A unit variant, written as `null`
+    Unit,
+    /// A newtype variant, written as the value it wraps
+    Newtype(Box<FieldDef>),
+    /// A struct variant, written as an object of its fields
+    Struct(Vec<FieldDef>),
+}
+
 // Re-export serde types conditionally based on feature
 #[cfg(feature = "serde")]
 pub(crate) use crate::features::serde::{SerdeTypeMeta, SerdeFieldMeta};
 
 
 
+impl FieldDefType {
+    /// Records the module a sibling type was referenced through; other types are unchanged.
+    fn in_module(self, module: Option<syn::Path>) -> Self {
+        match self {
+            FieldDefType::SiblingType(name, lst, _) => FieldDefType::SiblingType(name, lst, module),
+            other => other,
+        }
+    }
+
+    /// Whether this is one of the unsigned integer types.
+    pub fn is_unsigned_integer(&self) -> bool {
+        matches!(
+            self,
+            FieldDefType::U8
+                | FieldDefType::U16
+                | FieldDefType::U32
+                | FieldDefType::U64
+                | FieldDefType::Usize
+        )
+    }
+
+    /// Whether this is a 64-bit (or pointer-sized) integer, whose values may not fit in a JS
+    /// number without losing precision.
+    #[cfg_attr(not(feature = "safe_ints"), allow(dead_code))]
+    pub fn is_wide_integer(&self) -> bool {
+        matches!(
+            self,
+            FieldDefType::U64 | FieldDefType::I64 | FieldDefType::Usize | FieldDefType::Isize
+        )
+    }
+
+    /// Whether this is an integer or float type, which `min` / `max` apply to.
+    pub fn is_numeric(&self) -> bool {
+        matches!(
+            self,
+            FieldDefType::U8
+                | FieldDefType::U16
+                | FieldDefType::U32
+                | FieldDefType::U64
+                | FieldDefType::I8
+                | FieldDefType::I16
+                | FieldDefType::I32
+                | FieldDefType::I64
+                | FieldDefType::Usize
+                | FieldDefType::Isize
+                | FieldDefType::F32
+                | FieldDefType::F64
+                | FieldDefType::Number
+        )
+    }
+
+    /// Whether a value of this type is described by a single self-contained schema: strings,
+    /// numbers, booleans, formatted strings and ObjectId, as opposed to nested types,
+    /// maps and tuples.
+    pub fn is_scalar(&self) -> bool {
+        !matches!(
+            self,
+            FieldDefType::Unknown
+                | FieldDefType::External(_)
+                | FieldDefType::SiblingType(_, _, _)
+                | FieldDefType::Map(_, _)
+                | FieldDefType::Tuple(_)
+                | FieldDefType::Nested(_)
+        )
+    }
+}
+
 impl FieldDef {
+    /// Whether the field is a map keyed by ObjectId, whose keys are serialized as hex strings.
+    #[cfg(feature = "object_id")]
+    pub fn has_object_id_keys(&self) -> bool {
+        matches!(&self.field_type, FieldDefType::Map(key, _) if matches!(key.field_type, FieldDefType::ObjectId))
+    }
+
+    /// Whether the field is marked `read_only` via model_schema_prop.
+    pub fn is_read_only(&self) -> bool {
+        self.model_schema_prop_meta
+            .as_ref()
+            .is_some_and(|meta| meta.read_only)
+    }
+
+    /// Whether the field's Zod schema converts its input first, from `coerce` via model_schema_prop.
+    pub fn is_coerced(&self) -> bool {
+        self.model_schema_prop_meta.as_ref().is_some_and(|meta| meta.coerce)
+    }
+
+    /// Whether the field holds JSON encoded in a string, which the Zod schema parses, from
+    /// `raw_json(parse)` via model_schema_prop.
+    pub fn parses_raw_json(&self) -> bool {
+        self.model_schema_prop_meta.as_ref().is_some_and(|meta| meta.raw_json_parse)
+    }
+
+    /// The key of the field in a TypeScript type or Zod object, quoted when its serialized
+    /// name isn't a valid identifier (e.g. `"first-name"` with kebab-case).
+    pub fn property_key(&self) -> String {
+        let mut chars = self.name.chars();
+        let is_identifier = chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+        if is_identifier {
+            self.name.clone()
+        } else {
+            format!("{:?}", self.name)
+        }
+    }
+
+    /// The closed set of values of a string field, from `enum_values` via model_schema_prop.
+    pub fn enum_values(&self) -> Option<&[String]> {
+        self.model_schema_prop_meta
+            .as_ref()
+            .and_then(|meta| meta.enum_values.as_deref())
+    }
+
+    /// The TypeScript template literal type of a string field, from `ts_template` via
+    /// model_schema_prop.
+    pub fn ts_template(&self) -> Option<&str> {
+        self.model_schema_prop_meta
+            .as_ref()
+            .and_then(|meta| meta.ts_template.as_deref())
+    }
+
+    /// The raw TypeScript type replacing the field's own, from `as_ts` via model_schema_prop.
+    pub fn as_ts(&self) -> Option<&str> {
+        self.model_schema_prop_meta.as_ref().and_then(|meta| meta.as_ts.as_deref())
+    }
+
+    /// The raw Zod expression replacing the field's own schema, from `as_zod` via model_schema_prop.
+    pub fn as_zod(&self) -> Option<&str> {
+        self.model_schema_prop_meta.as_ref().and_then(|meta| meta.as_zod.as_deref())
+    }
+
+    /// The JSON schema `format` of a string field, from `format` via model_schema_prop.
+    pub fn string_format_name(&self) -> Option<&str> {
+        self.model_schema_prop_meta
+            .as_ref()
+            .and_then(|meta| meta.format.as_deref())
+    }
+
+    /// The validated format of a string field, when its `format` is one Zod has a validator for.
+    pub fn string_format(&self) -> Option<crate::features::string_formats::StringFormat> {
+        self.string_format_name()
+            .and_then(crate::features::string_formats::StringFormat::from_json_schema_format)
+    }
+
+    /// The regex a string or char field is checked against: `pattern`, or the one derived
+    /// from `ts_template`, via model_schema_prop.
+    pub fn string_pattern(&self) -> Option<String> {
+        let meta = self.model_schema_prop_meta.as_ref()?;
+        meta.pattern.clone().or_else(|| {
+            meta.ts_template
+                .as_deref()
+                .and_then(|template| crate::features::model_schema_prop::ts_template_pattern(template).ok())
+        })
+    }
+
+    /// The inclusive lower bound of a numeric field: `min` via model_schema_prop, raised to 1
+    /// for an unsigned `NonZero*` integer.
+    pub fn numeric_minimum(&self) -> Option<String> {
+        let min = self.model_schema_prop_meta.as_ref().and_then(|meta| meta.min.clone());
+        if self.is_non_zero && self.field_type.is_unsigned_integer() {
+            return match min {
+                Some(min) if min.parse::<f64>().is_ok_and(|min| min >= 1.0) => Some(min),
+                _ => Some("1".to_string()),
+            };
+        }
+        min
+    }
+
+    /// The inclusive upper bound of a numeric field, from `max` via model_schema_prop.
+    pub fn numeric_maximum(&self) -> Option<String> {
+        self.model_schema_prop_meta.as_ref().and_then(|meta| meta.max.clone())
+    }
+
+    /// Replaces the sibling types listed in `extern_map` (by Rust type name) with their external
+    /// definitions, here and in nested map, tuple and generic argument types.
+    pub fn map_extern_types(&mut self, extern_map: &BTreeMap<String, ExternType>) {
+        match &mut self.field_type {
+            FieldDefType::SiblingType(name, lst, _) => {
+                match extern_map.iter().find(|(rust_name, _)| safe_type_name(rust_name) == *name) {
+                    Some((_, extern_type)) => self.field_type = FieldDefType::External(extern_type.clone()),
+                    None => lst.iter_mut().for_each(|v| v.map_extern_types(extern_map)),
+                }
+            }
+            FieldDefType::Map(key, value) => {
+                key.map_extern_types(extern_map);
+                value.map_extern_types(extern_map);
+            }
+            FieldDefType::Tuple(lst) => lst.iter_mut().for_each(|v| v.map_extern_types(extern_map)),
+            FieldDefType::Nested(inner) => inner.map_extern_types(extern_map),
+            _ => {}
+        }
+    }
+
+    /// Collects the names of the sibling types this field references, including those nested
+    /// in maps, tuples and generic arguments.
+    pub fn collect_dependencies(&self, deps: &mut BTreeSet<String>) {
+        match &self.field_type {
+            FieldDefType::SiblingType(name, lst, _) => {
+                deps.insert(name.to_string());
+                lst.iter().for_each(|v| v.collect_dependencies(deps));
+            }
+            FieldDefType::Map(key, value) => {
+                key.collect_dependencies(deps);
+                value.collect_dependencies(deps);
+            }
+            FieldDefType::Tuple(lst) => lst.iter().for_each(|v| v.collect_dependencies(deps)),
+            FieldDefType::Nested(inner) => inner.collect_dependencies(deps),
+            _ => {}
+        }
+    }
+
+    /// The TypeScript type of a sequence item or map value. serde writes a `None` there as
+    /// `null`, so an optional one is `T | null` rather than `T | undefined`.
+    fn element_typescript_typename(&self) -> String {
+        if self.is_optional {
+            format!("{} | null", FieldDef { is_optional: false, ..self.clone() }.typescript_typename())
+        } else {
+            self.typescript_typename()
+        }
+    }
+
     pub fn typescript_typename(&self) -> String {
         let result = match &self.field_type {
             FieldDefType::Unknown => "unknown".to_string(),
+            FieldDefType::External(extern_type) => extern_type.typescript.clone(),
             FieldDefType::Tuple(lst) => {
                 let elements = lst
                     .iter()
@@ -62,7 +310,8 @@ impl FieldDef {
                     .join("; ");
                 format!("{{ {elements} }}")
             }
-            FieldDefType::SiblingType(name, lst) => {
+            FieldDefType::Nested(inner) => inner.element_typescript_typename(),
+            FieldDefType::SiblingType(name, lst, _) => {
                 if lst.is_empty() {
                     name.to_string()
                 } else {
@@ -76,22 +325,30 @@ impl FieldDef {
                 }
             }
             FieldDefType::Map(k, v) => {
-                format!(
-                    "Partial<Record<{}, {}>>",
-                    k.typescript_typename(),
-                    v.typescript_typename()
-                )
+                let key = match k.field_type {
+                    #[cfg(feature = "object_id")]
+                    FieldDefType::ObjectId => crate::features::object_id::get_object_id_key_typescript_type(),
+                    _ => k.typescript_typename(),
+                };
+                format!("Partial<Record<{key}, {}>>", v.element_typescript_typename())
             }
             FieldDefType::Boolean => "boolean".to_string(),
-            FieldDefType::String => "string".to_string(),
+            FieldDefType::String | FieldDefType::Char => match (self.enum_values(), self.ts_template()) {
+                (Some(values), _) => values.iter().map(|v| crate::utils::js_string_literal(v)).collect::<Vec<_>>().join(" | "),
+                (None, Some(template)) => format!("`{template}`"),
+                (None, None) => "string".to_string(),
+            },
             FieldDefType::StringLiteral(literal) => format!("\"{literal}\""),
             FieldDefType::U8 | FieldDefType::U16 | FieldDefType::U32 | FieldDefType::U64 
                 | FieldDefType::I8 | FieldDefType::I16 | FieldDefType::I32 | FieldDefType::I64 
                 | FieldDefType::Usize | FieldDefType::Isize => "number".to_string(),
-            FieldDefType::F32 | FieldDefType::F64 => "number".to_string(),
+            FieldDefType::F32 | FieldDefType::F64 | FieldDefType::Number => "number".to_string(),
+            FieldDefType::FormattedString(_) => "string".to_string(),
             #[cfg(feature = "object_id")]
             FieldDefType::ObjectId => crate::features::object_id::get_object_id_typescript_type(),
         };
+        // `as_ts` stands in for the element type; arrays and optionals still wrap it
+        let result = self.as_ts().map(str::to_string).unwrap_or(result);
         let pre_result = if self.is_array {
             format!("Array<{result}>")
         } else {
@@ -105,19 +362,47 @@ impl FieldDef {
         }
     }
 
+    /// The Zod schema of a sequence item or map value, `.or(z.null())` when it's optional (see
+    /// `element_typescript_typename`).
+    #[cfg(feature = "zod")]
+    fn element_zod_type(&self, zod: &str) -> String {
+        if self.is_optional {
+            format!("{}.or({zod}.null())", FieldDef { is_optional: false, ..self.clone() }.zod_type(zod))
+        } else {
+            self.zod_type(zod)
+        }
+    }
+
+    /// The Zod constructor of a primitive schema, e.g. `z.number()`, or `z.coerce.number()` when
+    /// the field coerces its input, so validations chained after it apply to the converted value.
     #[cfg(feature = "zod")]
-    pub fn zod_type(&self) -> String {
+    fn zod_primitive(&self, zod: &str, kind: &str) -> String {
+        if self.is_coerced() {
+            format!("{zod}.coerce.{kind}()")
+        } else {
+            format!("{zod}.{kind}()")
+        }
+    }
+
+    /// The Zod schema of the field, calling Zod through `zod`. `as_zod` and `extern_map`
+    /// schemas are used as written.
+    #[cfg(feature = "zod")]
+    pub fn zod_type(&self, zod: &str) -> String {
         let result = match &self.field_type {
-            FieldDefType::Unknown => "z.unknown()".to_string(),
+            // The callback keeps Zod's context argument from being passed to JSON.parse as a reviver
+            FieldDefType::Unknown if self.parses_raw_json() => format!("{zod}.string().transform((raw) => JSON.parse(raw))"),
+            FieldDefType::Unknown => format!("{zod}.unknown()"),
+            FieldDefType::External(extern_type) => extern_type.zod_schema(zod),
             FieldDefType::Tuple(lst) => {
                 let elements = lst
                     .iter()
-                    .map(|v| format!("{}: {}", v.name, v.zod_type()))
+                    .map(|v| format!("{}: {}", v.name, v.zod_type(zod)))
                     .collect::<Vec<_>>()
                     .join("; ");
                 format!("{{ {elements} }}")
             }
-            FieldDefType::SiblingType(name, lst) => {
+            FieldDefType::Nested(inner) => inner.element_zod_type(zod),
+            FieldDefType::SiblingType(name, lst, _) => {
                 if lst.is_empty() {
                     format!("{name}$Schema")
                 } else {
@@ -131,36 +416,174 @@ impl FieldDef {
                 }
             }
             FieldDefType::Map(k, v) => {
-                format!("z.record({}, {})", k.zod_type(), v.zod_type())
+                let key = match k.field_type {
+                    #[cfg(feature = "object_id")]
+                    FieldDefType::ObjectId => crate::features::object_id::get_object_id_key_zod_schema(zod),
+                    _ => k.zod_type(zod),
+                };
+                format!("{zod}.record({key}, {})", v.element_zod_type(zod))
             }
-            FieldDefType::Boolean => "z.boolean()".to_string(),
-            FieldDefType::String => {
-                let mut result = "z.string()".to_string();
-                // Add min length validation if specified
-                if let Some(ref meta) = self.model_schema_prop_meta
-                    && let Some(min_len) = meta.min_length {
+            FieldDefType::Boolean => self.zod_primitive(zod, "boolean"),
+            // A closed set of values replaces the string schema and its validations
+            FieldDefType::String | FieldDefType::Char => match self.enum_values() {
+                Some(values) => format!(
+                    "{zod}.enum([{}])",
+                    values.iter().map(|v| crate::utils::js_string_literal(v)).collect::<Vec<_>>().join(", ")
+                ),
+                None => {
+                    // A known format's validator stands in for `z.string()`; unknown ones are
+                    // only checked by the JSON schema
+                    let mut result = match self.string_format() {
+                        Some(format) => format.zod_schema(zod),
+                        None => self.zod_primitive(zod, "string"),
+                    };
+                    if let Some(ref meta) = self.model_schema_prop_meta {
+                        // Transforms run first so validations see the normalized value
+                        for transform in meta.string_transforms() {
+                            result = format!("{result}.{transform}()");
+                        }
+                    }
+                    if matches!(self.field_type, FieldDefType::Char) {
+                        result = format!("{result}.length(1)");
+                    }
+                    if let Some(pattern) = self.string_pattern() {
+                        result = format!("{result}.regex(/{}/)", crate::features::model_schema_prop::js_regex_source(&pattern));
+                    }
+                    // Add min length validation if specified
+                    if let Some(min_len) = self.model_schema_prop_meta.as_ref().and_then(|meta| meta.min_length) {
                         result = format!("{result}.min({min_len})");
                     }
-                result
+                    if let Some(max_len) = self.model_schema_prop_meta.as_ref().and_then(|meta| meta.max_length) {
+                        result = format!("{result}.max({max_len})");
+                    }
+                    result
+                }
             },
-            FieldDefType::StringLiteral(literal) => format!("z.literal(\"{literal}\")"),
+            FieldDefType::StringLiteral(literal) => format!("{zod}.literal(\"{literal}\")"),
             FieldDefType::U8 | FieldDefType::U16 | FieldDefType::U32 | FieldDefType::U64 
                 | FieldDefType::I8 | FieldDefType::I16 | FieldDefType::I32 | FieldDefType::I64 
                 | FieldDefType::Usize | FieldDefType::Isize => {
-                "z.number().int()".to_string()
+                let mut result = format!("{}.int()", self.zod_primitive(zod, "number"));
+                // Rejects values past Number.MAX_SAFE_INTEGER, which JSON.parse rounds
+                #[cfg(feature = "safe_ints")]
+                if self.field_type.is_wide_integer() {
+                    result = format!("{result}.safe()");
+                }
+                if let Some(min) = self.numeric_minimum() {
+                    result = format!("{result}.min({min})");
+                }
+                if let Some(max) = self.numeric_maximum() {
+                    result = format!("{result}.max({max})");
+                }
+                if self.is_non_zero && !self.field_type.is_unsigned_integer() {
+                    result = format!("{result}.refine(n => n !== 0)");
+                }
+                result
+            }
+            FieldDefType::F32 | FieldDefType::F64 | FieldDefType::Number => {
+                let mut result = self.zod_primitive(zod, "number");
+                if let Some(min) = self.numeric_minimum() {
+                    result = format!("{result}.min({min})");
+                }
+                if let Some(max) = self.numeric_maximum() {
+                    result = format!("{result}.max({max})");
+                }
+                result
+            }
+            FieldDefType::FormattedString(format) => format.zod_schema(zod),
+            #[cfg(feature = "object_id")]
+            FieldDefType::ObjectId => crate::features::object_id::get_object_id_zod_schema(zod),
+        };
+        let result = self.as_zod().map(str::to_string).unwrap_or(result);
+        let pre_result = if self.is_array {
+            format!("{zod}.array({result})")
+        } else {
+            result
+        };
+
+        let result = if self.is_optional {
+            format!("{pre_result}.or({zod}.undefined())")
+        } else {
+            pre_result
+        };
+
+        #[cfg(feature = "zod_describe")]
+        let result = crate::features::zod::with_description(result, self.description.as_deref());
+
+        result
+    }
+
+    /// The io-ts codec of a sequence item or map value, accepting `t.null` when it's optional
+    /// (see `element_typescript_typename`).
+    #[cfg(feature = "io_ts")]
+    fn element_io_ts_type(&self) -> String {
+        if self.is_optional {
+            format!("t.union([{}, t.null])", FieldDef { is_optional: false, ..self.clone() }.io_ts_type())
+        } else {
+            self.io_ts_type()
+        }
+    }
+
+    /// The io-ts codec of the field. Checks io-ts can't express (integers, string formats,
+    /// lengths) are left out.
+    #[cfg(feature = "io_ts")]
+    pub fn io_ts_type(&self) -> String {
+        let result = match &self.field_type {
+            FieldDefType::Unknown | FieldDefType::External(_) => "t.unknown".to_string(),
+            FieldDefType::Tuple(lst) => {
+                let elements = lst
+                    .iter()
+                    .map(|v| format!("{}: {}", v.name, v.io_ts_type()))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("t.type({{ {elements} }})")
+            }
+            FieldDefType::Nested(inner) => inner.element_io_ts_type(),
+            // Generic codecs are functions in io-ts, which the macro can't call by name
+            FieldDefType::SiblingType(name, lst, _) => {
+                if lst.is_empty() {
+                    name.to_string()
+                } else {
+                    "t.unknown".to_string()
+                }
             }
-            FieldDefType::F32 | FieldDefType::F64 => "z.number()".to_string(),
+            FieldDefType::Map(k, v) => {
+                let key = match k.field_type {
+                    #[cfg(feature = "object_id")]
+                    FieldDefType::ObjectId => "t.string".to_string(),
+                    _ => k.io_ts_type(),
+                };
+                format!("t.record({key}, {})", v.element_io_ts_type())
+            }
+            FieldDefType::Boolean => "t.boolean".to_string(),
+            FieldDefType::String | FieldDefType::Char => match self.enum_values() {
+                Some(values) => format!(
+                    "t.keyof({{ {} }})",
+                    values
+                        .iter()
+                        .map(|v| format!("{}: null", crate::utils::js_string_literal(v)))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+                None => "t.string".to_string(),
+            },
+            FieldDefType::StringLiteral(literal) => format!("t.literal(\"{literal}\")"),
+            FieldDefType::U8 | FieldDefType::U16 | FieldDefType::U32 | FieldDefType::U64
+                | FieldDefType::I8 | FieldDefType::I16 | FieldDefType::I32 | FieldDefType::I64
+                | FieldDefType::Usize | FieldDefType::Isize
+                | FieldDefType::F32 | FieldDefType::F64 | FieldDefType::Number => "t.number".to_string(),
+            FieldDefType::FormattedString(_) => "t.string".to_string(),
             #[cfg(feature = "object_id")]
-            FieldDefType::ObjectId => crate::features::object_id::get_object_id_zod_schema(),
+            FieldDefType::ObjectId => crate::features::object_id::get_object_id_io_ts_codec(),
         };
         let pre_result = if self.is_array {
-            format!("z.array({result})")
+            format!("t.array({result})")
         } else {
             result
         };
 
         if self.is_optional {
-            format!("{pre_result}.or(z.undefined())")
+            format!("t.union([{pre_result}, t.undefined])")
         } else {
             pre_result
         }
@@ -173,17 +596,63 @@ pub(crate) fn get_field_def(name: &str, ty: &Type, field_docs: &str) -> FieldDef
     let safe_name = safe_type_name(name);
     match ty {
         Type::Path(type_path) => {
+            // `venue::VenueJson` is referenced as `venue::VenueJson::__tixschema_json_schema_ref(stack, defs)`, so a sibling
+            // resolves without being imported
+            let module = module_path(&type_path.path);
             if let Some(segment) = type_path.path.segments.last() {
                 let ident = segment.ident.to_string();
                 match &segment.arguments {
+                    // Bytes/BytesMut serialize as a sequence of u8, just like Vec<u8>
+                    #[cfg(feature = "bytes")]
+                    PathArguments::None if crate::features::bytes::is_bytes_type(&ident) => FieldDef {
+                        is_optional: false,
+                        name: safe_name,
+                        field_type: FieldDefType::U8,
+                        is_array: true,
+                        array_num: None,
+                        docs: field_docs.to_string(),
+                        model_schema_prop_meta: None,
+                        is_non_zero: false,
+                        is_set: false,
+                        description: None,
+                    },
+                    // Arbitrary JSON, which any schema accepts
+                    PathArguments::None if is_serde_json_type(&type_path.path, "Value") => FieldDef {
+                        is_optional: false,
+                        name: safe_name,
+                        field_type: FieldDefType::Unknown,
+                        is_array: false,
+                        array_num: None,
+                        docs: field_docs.to_string(),
+                        model_schema_prop_meta: None,
+                        is_non_zero: false,
+                        is_set: false,
+                        description: None,
+                    },
+                    // Any `Number` other than serde_json's is a sibling type
+                    PathArguments::None if ident == "Number" && !is_serde_json_type(&type_path.path, "Number") => FieldDef {
+                        is_optional: false,
+                        name: safe_name,
+                        field_type: FieldDefType::SiblingType(ident, vec![], module),
+                        is_array: false,
+                        array_num: None,
+                        docs: field_docs.to_string(),
+                        model_schema_prop_meta: None,
+                        is_non_zero: false,
+                        is_set: false,
+                        description: None,
+                    },
                     PathArguments::None => FieldDef {
                         is_optional: false,
                         name: safe_name,
-                        field_type: get_field_def_type_or_sibling(&ident.to_string()),
+                        field_type: get_field_def_type_or_sibling(&ident.to_string()).in_module(module),
                         is_array: false,
                         array_num: None,
                         docs: field_docs.to_string(),
                         model_schema_prop_meta: None,
+                        is_non_zero: is_non_zero_type(&ident),
+                        is_set: false,
+                        description: None,
                     },
                     PathArguments::AngleBracketed(args) => {
                         let arg_types: Vec<FieldDef> = args
@@ -202,27 +671,77 @@ pub(crate) fn get_field_def(name: &str, ty: &Type, field_docs: &str) -> FieldDef
                             FieldDef {
                                 is_optional: false,
                                 name: safe_name,
-                                field_type: FieldDefType::SiblingType(ident.to_string(), vec![]),
+                                field_type: FieldDefType::SiblingType(ident.to_string(), vec![], module),
+                                is_array: false,
+                                array_num: None,
+                                docs: field_docs.to_string(),
+                                model_schema_prop_meta: None,
+                                is_non_zero: false,
+                                is_set: false,
+                                description: None,
+                            }
+                        } else if cfg!(feature = "chrono") && arg_types.len() == 1 && &ident == "DateTime" {
+                            // chrono's DateTime<Tz> serializes as an RFC 3339 string whatever
+                            // the time zone
+                            FieldDef {
+                                is_optional: false,
+                                name: safe_name,
+                                field_type: FieldDefType::FormattedString(StringFormat::DateTime),
                                 is_array: false,
                                 array_num: None,
                                 docs: field_docs.to_string(),
                                 model_schema_prop_meta: None,
+                                is_non_zero: false,
+                                is_set: false,
+                                description: None,
                             }
                         } else if arg_types.len() == 1 && &ident == "Option" {
                             let mut result = arg_types[0].clone();
                             result.name = safe_name;
+                            result.docs = field_docs.to_string();
                             result.is_optional = true;
                             result
-                        } else if arg_types.len() == 1 && &ident == "Vec" {
+                        } else if arg_types.len() == 1 && is_transparent_wrapper(&ident) {
+                            // Box<T>, Arc<T>, Rc<T> and Cow<'_, T> serialize exactly like T,
+                            // so they are resolved here and never reach the generators
                             let mut result = arg_types[0].clone();
                             result.name = safe_name;
-                            result.is_array = true;
+                            result.docs = field_docs.to_string();
+                            result
+                        } else if arg_types.len() == 1 && &ident == "NonZero" {
+                            // Generic form: std::num::NonZero<u32>
+                            let mut result = arg_types[0].clone();
+                            result.name = safe_name;
+                            result.docs = field_docs.to_string();
+                            result.is_non_zero = true;
                             result
-                        } else if arg_types.len() == 2 && &ident == "HashMap" {
-                            // Debug print to see what's happening
-                            if std::env::var("RUST_LOG") == Ok(String::from("trace")) {
-                                println!("Creating HashMap Map type - key: {:?}, value: {:?}", arg_types[0], arg_types[1]);
+                        } else if arg_types.len() == 1
+                            && is_sequence_type(&ident)
+                            && (arg_types[0].is_array || arg_types[0].is_optional)
+                        {
+                            // A sequence of sequences or optionals keeps the element whole, so
+                            // its own Array<...> isn't merged into the outer one and its
+                            // optionality doesn't leak to the field
+                            FieldDef {
+                                is_optional: false,
+                                name: safe_name,
+                                field_type: FieldDefType::Nested(Box::new(arg_types[0].clone())),
+                                is_array: true,
+                                array_num: None,
+                                docs: field_docs.to_string(),
+                                model_schema_prop_meta: None,
+                                is_non_zero: false,
+                                is_set: is_set_type(&ident),
+                                description: None,
                             }
+                        } else if arg_types.len() == 1 && is_sequence_type(&ident) {
+                            let mut result = arg_types[0].clone();
+                            result.name = safe_name;
+                            result.docs = field_docs.to_string();
+                            result.is_array = true;
+                            result.is_set = is_set_type(&ident);
+                            result
+                        } else if arg_types.len() == 2 && is_map_type(&ident) {
                             FieldDef {
                                 is_array: false,
                                 is_optional: false,
@@ -234,24 +753,27 @@ pub(crate) fn get_field_def(name: &str, ty: &Type, field_docs: &str) -> FieldDef
                                 ),
                                 docs: field_docs.to_string(),
                                 model_schema_prop_meta: None,
+                                is_non_zero: false,
+                                is_set: false,
+                                description: None,
                             }
                         } else {
-                            // Debug print to see what's happening with SiblingType
-                            if std::env::var("RUST_LOG") == Ok(String::from("trace")) {
-                                println!("Creating SiblingType - name: {ident}, arg_types: {arg_types:?}");
-                            }
                             FieldDef {
                                 is_optional: false,
                                 name: safe_name,
-                                field_type: FieldDefType::SiblingType(ident.to_string(), arg_types),
+                                field_type: FieldDefType::SiblingType(ident.to_string(), arg_types, module),
                                 is_array: false,
                                 array_num: None,
                                 docs: field_docs.to_string(),
                                 model_schema_prop_meta: None,
+                                is_non_zero: false,
+                                is_set: false,
+                                description: None,
                             }
                         }
                     }
-                    PathArguments::Parenthesized(_) => panic!("Unsupported field type"), //format!("({})", ident), // Function pointer types
+                    // Function trait paths, e.g. `Fn(u32)`, which syn doesn't parse as field types
+                    PathArguments::Parenthesized(_) => unreachable!("function trait path as a field type"),
                 }
             } else {
                 FieldDef {
@@ -262,6 +784,9 @@ pub(crate) fn get_field_def(name: &str, ty: &Type, field_docs: &str) -> FieldDef
                     array_num: None,
                     docs: field_docs.to_string(),
                     model_schema_prop_meta: None,
+                    is_non_zero: false,
+                    is_set: false,
+                    description: None,
                 }
             }
         }
@@ -272,18 +797,8 @@ pub(crate) fn get_field_def(name: &str, ty: &Type, field_docs: &str) -> FieldDef
             //     .map_or("".to_string(), |l| format!("'{}", l.ident));
             get_field_def(name, type_ref.elem.as_ref(), field_docs)
         }
-        Type::Array(type_array) => {
-            let mut def = get_field_def(name, &type_array.elem, field_docs);
-            def.is_array = true;
-            def.array_num = None; // type_array.len;
-            def
-        }
-        Type::Slice(type_slice) => {
-            let mut def = get_field_def(name, &type_slice.elem, field_docs);
-            def.is_array = true;
-            def.array_num = None; // type_array.len;
-            def
-        }
+        Type::Array(type_array) => array_of(get_field_def(name, &type_array.elem, field_docs)),
+        Type::Slice(type_slice) => array_of(get_field_def(name, &type_slice.elem, field_docs)),
         Type::Tuple(type_tuple) => {
             let elements: Vec<FieldDef> = type_tuple
                 .elems
@@ -299,6 +814,9 @@ pub(crate) fn get_field_def(name: &str, ty: &Type, field_docs: &str) -> FieldDef
                 array_num: None,
                 docs: field_docs.to_string(),
                 model_schema_prop_meta: None,
+                is_non_zero: false,
+                is_set: false,
+                description: None,
             }
         }
         _ => FieldDef {
@@ -309,14 +827,41 @@ pub(crate) fn get_field_def(name: &str, ty: &Type, field_docs: &str) -> FieldDef
             array_num: None,
             docs: field_docs.to_string(),
             model_schema_prop_meta: None,
+            is_non_zero: false,
+            is_set: false,
+            description: None,
         }, // Fallback for BareFn, ImplTrait, etc.
     }
 }
 
+/// The definition of an array or slice of `def`. Like `Vec`, an element that is a sequence or
+/// optional itself is kept whole in `Nested`.
+fn array_of(mut def: FieldDef) -> FieldDef {
+    if def.is_array || def.is_optional {
+        FieldDef {
+            is_optional: false,
+            name: def.name.clone(),
+            docs: def.docs.clone(),
+            field_type: FieldDefType::Nested(Box::new(def)),
+            is_array: true,
+            array_num: None,
+            model_schema_prop_meta: None,
+            is_non_zero: false,
+            is_set: false,
+            description: None,
+        }
+    } else {
+        def.is_array = true;
+        def.array_num = None; // type_array.len;
+        def
+    }
+}
+
 fn get_field_def_type_or_sibling(t_name: &str) -> FieldDefType {
     match t_name {
         "bool" => FieldDefType::Boolean,
-        "String" => FieldDefType::String,
+        "String" | "str" => FieldDefType::String,
+        "char" => FieldDefType::Char,
         "u8" => FieldDefType::U8,
         "u16" => FieldDefType::U16,
         "u32" => FieldDefType::U32,
@@ -329,12 +874,32 @@ fn get_field_def_type_or_sibling(t_name: &str) -> FieldDefType {
         "isize" => FieldDefType::Isize,
         "f32" => FieldDefType::F32,
         "f64" => FieldDefType::F64,
+        "Number" => FieldDefType::Number,
+        #[cfg(feature = "uuid")]
+        "Uuid" => FieldDefType::FormattedString(StringFormat::Uuid),
+        "IpAddr" => FieldDefType::FormattedString(StringFormat::Ip),
+        "Ipv4Addr" => FieldDefType::FormattedString(StringFormat::Ipv4),
+        "Ipv6Addr" => FieldDefType::FormattedString(StringFormat::Ipv6),
+        #[cfg(feature = "chrono")]
+        "NaiveDate" => FieldDefType::FormattedString(StringFormat::Date),
+        #[cfg(feature = "chrono")]
+        "NaiveDateTime" => FieldDefType::FormattedString(StringFormat::LocalDateTime),
+        "NonZeroU8" => FieldDefType::U8,
+        "NonZeroU16" => FieldDefType::U16,
+        "NonZeroU32" => FieldDefType::U32,
+        "NonZeroU64" => FieldDefType::U64,
+        "NonZeroI8" => FieldDefType::I8,
+        "NonZeroI16" => FieldDefType::I16,
+        "NonZeroI32" => FieldDefType::I32,
+        "NonZeroI64" => FieldDefType::I64,
+        "NonZeroUsize" => FieldDefType::Usize,
+        "NonZeroIsize" => FieldDefType::Isize,
         #[cfg(feature = "object_id")]
         "ObjectId" => {
             if crate::features::object_id::should_handle_as_object_id(t_name) {
                 FieldDefType::ObjectId
             } else {
-                FieldDefType::SiblingType(t_name.to_string(), vec![])
+                FieldDefType::SiblingType(t_name.to_string(), vec![], None)
             }
         }
         #[cfg(not(feature = "object_id"))]
@@ -344,13 +909,81 @@ fn get_field_def_type_or_sibling(t_name: &str) -> FieldDefType {
             eprintln!("         ObjectId will be treated as a custom type (may cause compilation errors)");
             eprintln!("         Enable the object_id feature: features = [\"object_id\"]");
             eprintln!("         Or add the required ObjectId type definition to your code");
-            FieldDefType::SiblingType(t_name.to_string(), vec![])
+            FieldDefType::SiblingType(t_name.to_string(), vec![], None)
         }
         type_name_json if type_name_json.ends_with("Json") => {
-            FieldDefType::SiblingType(safe_type_name(type_name_json), vec![])
+            FieldDefType::SiblingType(safe_type_name(type_name_json), vec![], None)
         }
-        type_name => FieldDefType::SiblingType(type_name.to_string(), vec![]),
+        type_name => FieldDefType::SiblingType(type_name.to_string(), vec![], None),
+    }
+}
+
+/// The `NameJson` type generated for a sibling type, qualified with the module it was
+/// referenced through.
+pub(crate) fn sibling_json_type(name: &str, module: &Option<syn::Path>) -> proc_macro2::TokenStream {
+    let json_ident = proc_macro2::Ident::new(&format!("{name}Json"), proc_macro2::Span::call_site());
+    match module {
+        Some(module) => quote::quote! { #module::#json_ident },
+        None => quote::quote! { #json_ident },
+    }
+}
+
+/// The module a type path goes through, e.g. `super::venue` for `super::venue::VenueJson`, or
+/// `None` for a bare name.
+fn module_path(path: &syn::Path) -> Option<syn::Path> {
+    if path.segments.len() < 2 {
+        return None;
     }
+    let mut module = path.clone();
+    module.segments.pop();
+    module.segments.pop_punct();
+    Some(module)
+}
+
+/// Returns true when a path refers to serde_json's `name` type (`Number`, `Value`): written out
+/// in full, or bare as brought in by `use serde_json::Number`.
+fn is_serde_json_type(path: &syn::Path, name: &str) -> bool {
+    let segments: Vec<String> = path.segments.iter().map(|s| s.ident.to_string()).collect();
+    segments == [name] || segments == ["serde_json", name]
+}
+
+/// Returns true for sequence collections that serialize as JSON arrays, sets included.
+fn is_sequence_type(ident: &str) -> bool {
+    matches!(ident, "Vec" | "VecDeque" | "LinkedList" | "HashSet" | "BTreeSet" | "IndexSet")
+}
+
+/// Returns true for set collections, which serialize as JSON arrays of unique items.
+fn is_set_type(ident: &str) -> bool {
+    matches!(ident, "HashSet" | "BTreeSet" | "IndexSet")
+}
+
+/// Returns true for map collections that serialize as JSON objects.
+fn is_map_type(ident: &str) -> bool {
+    matches!(ident, "HashMap" | "BTreeMap" | "IndexMap")
+}
+
+/// Returns true for wrappers that serde serializes transparently as their inner type
+/// (`Box<T>`, `Arc<T>`, `Rc<T>`, `Cow<'_, T>`), including slices such as `Arc<[T]>`.
+fn is_transparent_wrapper(ident: &str) -> bool {
+    matches!(ident, "Box" | "Arc" | "Rc" | "Cow")
+}
+
+/// Returns true for the `std::num::NonZero*` integer aliases, which serialize as
+/// plain integers but can never hold zero.
+fn is_non_zero_type(t_name: &str) -> bool {
+    matches!(
+        t_name,
+        "NonZeroU8"
+            | "NonZeroU16"
+            | "NonZeroU32"
+            | "NonZeroU64"
+            | "NonZeroI8"
+            | "NonZeroI16"
+            | "NonZeroI32"
+            | "NonZeroI64"
+            | "NonZeroUsize"
+            | "NonZeroIsize"
+    )
 }
 
 /// Parses serde attributes from a struct or enum.
@@ -1,50 +1,68 @@
 //! Code generation utilities and modules
-//! 
-//! This module contains the core code generation logic separated by functionality.
+//!
+//! This module contains the TypeScript text the macro assembles at expansion time, shared by
+//! structs, enums and their variants. The generated methods wrap it in the type's docs, which
+//! are only complete at runtime since they embed the JSON schema.
 
+#[cfg(feature = "typescript")]
 pub mod typescript;
 
-#[cfg(test)]
+use crate::features::model_schema_args::OptionalStyle;
 use crate::field_type::FieldDef;
 
-
 /// Common utilities for code generation
-#[cfg(test)]
 pub struct GenerationUtils;
 
-#[cfg(test)]
 impl GenerationUtils {
-    /// Formats documentation for TypeScript comments
-    pub fn format_docs(docs: &str) -> String {
-        if docs.is_empty() {
-            String::new()
-        } else {
-            docs.lines()
-                .map(|line| format!(" * {}", line))
+    /// Formats doc comment lines for a TypeScript comment, ending with an empty line. Without
+    /// docs, the name of the type, variant or field stands in for them.
+    pub fn format_docs(doc_lines: Option<Vec<String>>, name: &str) -> String {
+        match doc_lines {
+            Some(doc_lines) => doc_lines
+                .into_iter()
+                .flat_map(|v| v.lines().map(|l| l.to_owned()).collect::<Vec<_>>())
+                .chain(vec!["".to_string()])
+                .map(|l| format!(" * {l}"))
                 .collect::<Vec<_>>()
-                .join("\n")
+                .join("\n"),
+            None => [name.to_string(), "".to_string()]
+                .into_iter()
+                .map(|l| format!(" * {l}"))
+                .collect::<Vec<_>>()
+                .join("\n"),
         }
     }
 
-    /// Formats a field for TypeScript type definition
-    pub fn format_typescript_field(fld: &FieldDef) -> String {
+    /// Formats a field for a TypeScript type definition, with its docs (already formatted by
+    /// `format_docs`) and modifiers.
+    pub fn format_typescript_field(fld: &FieldDef, optional_style: OptionalStyle) -> String {
+        let (property, ts_type) = Self::typescript_property(fld, optional_style);
         format!(
-            "  /**\n{}\n**/\n  {}: {};",
-            Self::format_docs(&fld.docs),
-            fld.name,
-            fld.typescript_typename()
+            "  /**\n{}\n**/\n  {}{}: {};",
+            fld.docs,
+            Self::typescript_modifiers(fld),
+            property,
+            ts_type
         )
     }
 
-    // /// Formats a field for Zod schema definition
-    // #[cfg(feature = "zod")]
-    // pub fn format_zod_field(fld: &FieldDef) -> String {
-    //     format!("  {}: {},", fld.name, fld.zod_type())
-    // }
+    /// Returns the TypeScript property modifiers (e.g. `readonly `) for a field.
+    fn typescript_modifiers(fld: &FieldDef) -> &'static str {
+        if fld.is_read_only() { "readonly " } else { "" }
+    }
 
-    /// Generates safe type name by removing Json suffix for TypeScript
-    pub fn safe_typescript_name(rust_name: &str) -> String {
-        crate::utils::safe_type_name(rust_name)
+    /// Returns the TypeScript property key and type of a field. An optional field is
+    /// `key: T | undefined` by default, `key?: T` with `optionals_absent` and `key: T | null` with
+    /// `optionals_nullable`.
+    fn typescript_property(fld: &FieldDef, optional_style: OptionalStyle) -> (String, String) {
+        let required = FieldDef { is_optional: false, ..fld.clone() };
+        match optional_style {
+            OptionalStyle::Absent if fld.is_optional => (format!("{}?", fld.property_key()), required.typescript_typename()),
+            OptionalStyle::Nullable if fld.is_optional => {
+                (fld.property_key(), format!("{} | null", required.typescript_typename()))
+            }
+            _ => (fld.property_key(), fld.typescript_typename()),
+        }
     }
 }
 
@@ -53,31 +71,54 @@ mod tests {
     use super::*;
     use crate::field_type::{FieldDef, FieldDefType};
 
+    fn field(name: &str, is_optional: bool) -> FieldDef {
+        FieldDef {
+            is_optional,
+            name: name.to_string(),
+            docs: " * Test documentation\n * ".to_string(),
+            field_type: FieldDefType::String,
+            is_array: false,
+            array_num: None,
+            model_schema_prop_meta: None,
+            is_non_zero: false,
+            is_set: false,
+            description: None,
+        }
+    }
+
     #[test]
     fn test_format_docs() {
-        assert_eq!(GenerationUtils::format_docs(""), "");
-        assert_eq!(GenerationUtils::format_docs("Simple doc"), " * Simple doc");
+        assert_eq!(GenerationUtils::format_docs(None, "UserJson"), " * UserJson\n * ");
         assert_eq!(
-            GenerationUtils::format_docs("Line 1\nLine 2"),
-            " * Line 1\n * Line 2"
+            GenerationUtils::format_docs(Some(vec!["Simple doc".to_string()]), "UserJson"),
+            " * Simple doc\n * "
+        );
+        assert_eq!(
+            GenerationUtils::format_docs(Some(vec!["Line 1\nLine 2".to_string()]), "UserJson"),
+            " * Line 1\n * Line 2\n * "
         );
     }
 
     #[test]
     fn test_typescript_field_formatting() {
-        let field = FieldDef {
-            is_optional: false,
-            name: "test_field".to_string(),
-            docs: "Test documentation".to_string(),
-            field_type: FieldDefType::String,
-            is_array: false,
-            array_num: None,
-            model_schema_prop_meta: None,
-        };
+        assert_eq!(
+            GenerationUtils::format_typescript_field(&field("test_field", false), OptionalStyle::Undefined),
+            "  /**\n * Test documentation\n * \n**/\n  test_field: string;"
+        );
+    }
+
+    #[test]
+    fn test_typescript_field_optional_styles() {
+        let nickname = field("nickname", true);
 
-        let formatted = GenerationUtils::format_typescript_field(&field);
-        assert!(formatted.contains("test_field"));
-        assert!(formatted.contains("string"));
-        assert!(formatted.contains("Test documentation"));
+        assert!(
+            GenerationUtils::format_typescript_field(&nickname, OptionalStyle::Undefined)
+                .ends_with("  nickname: string | undefined;")
+        );
+        assert!(GenerationUtils::format_typescript_field(&nickname, OptionalStyle::Absent).ends_with("  nickname?: string;"));
+        assert!(
+            GenerationUtils::format_typescript_field(&nickname, OptionalStyle::Nullable)
+                .ends_with("  nickname: string | null;")
+        );
     }
-} 
\ No newline at end of file
+}
@@ -1,184 +1,78 @@
 //! TypeScript type generation module
-//! 
-//! This module handles the core TypeScript type generation that is always available
-//! regardless of feature flags.
-#[cfg(test)]
-use crate::field_type::FieldDef;
-
-#[cfg(test)]
-use super::GenerationUtils;
+//!
+//! This module builds the `export type` declarations of structs and plain enums. The
+//! generated `ts_definition()` methods put the type's docs in front of them.
 
-#[cfg(test)]
 /// TypeScript type generator
 pub struct TypeScriptGenerator;
 
-#[cfg(test)]
 impl TypeScriptGenerator {
-    /// Generates TypeScript type definition for a struct
-    pub fn generate_struct_type(
-        type_name: &str,
-        fields: &[FieldDef],
-        docs: &str,
-    ) -> String {
-        let fields_empty = fields.is_empty();
-        let item_name = GenerationUtils::safe_typescript_name(type_name);
-        
+    /// Generates the TypeScript type declaration of a struct from its fields, already formatted
+    /// by `GenerationUtils::format_typescript_field`, intersected with its flattened types
+    /// (e.g. `Base & `). A struct without any fields is `Record<string, never>`.
+    pub fn generate_struct_type(item_name: &str, intersected: &str, type_code: &str, fields_empty: bool) -> String {
         if fields_empty {
-            format!(
-                "/**\n{}\n**/\nexport type {} = Record<string, never>;",
-                GenerationUtils::format_docs(docs),
-                item_name
-            )
+            format!("export type {item_name} = Record<string, never>;")
         } else {
-            let type_code = fields
-                .iter()
-                .map(|fld| GenerationUtils::format_typescript_field(fld))
-                .collect::<Vec<_>>()
-                .join("\n");
-            
-            format!(
-                "/**\n{}\n**/\nexport type {} = {{\n{}\n}};",
-                GenerationUtils::format_docs(docs),
-                item_name,
-                type_code
-            )
+            format!("export type {item_name} = {intersected}{{\n{type_code}\n}};")
         }
     }
 
-    /// Generates TypeScript type definition for a plain enum
-    pub fn generate_plain_enum_type(
-        type_name: &str,
-        enum_options: &[String],
-        docs: &str,
-    ) -> String {
-        let item_name = GenerationUtils::safe_typescript_name(type_name);
+    /// Generates the TypeScript type declaration of a plain enum, the union of its values. A
+    /// `#[serde(other)]` variant adds `(string & {})`, which accepts any other string while
+    /// keeping the known values suggested by editors.
+    pub fn generate_plain_enum_type(item_name: &str, enum_options: &[String], has_catch_all: bool) -> String {
         let type_code = enum_options
             .iter()
-            .map(|v| format!("\"{}\"", v))
+            .map(|v| format!("\"{v}\""))
+            .chain(has_catch_all.then(|| "(string & {})".to_string()))
             .collect::<Vec<_>>()
             .join(" | ");
 
-        format!(
-            "/**\n{}\n**/\nexport type {} = {};",
-            GenerationUtils::format_docs(docs),
-            item_name,
-            type_code
-        )
+        format!("export type {item_name} = {type_code};")
     }
-
-    // /// Generates TypeScript type definition for a discriminated enum
-    // pub fn generate_discriminated_enum_type(
-    //     type_name: &str,
-    //     variants: &[(String, Vec<FieldDef>, String)], // (variant_name, fields, docs)
-    //     tag_name: &str,
-    //     docs: &str,
-    // ) -> String {
-    //     let item_name = GenerationUtils::safe_typescript_name(type_name);
-        
-    //     let type_code_items: Vec<String> = variants
-    //         .iter()
-    //         .map(|(variant_name, fields, variant_docs)| {
-    //             let mut variant_type_code = format!(
-    //                 "{{  /**\n{}\n**/\n  {}: \"{}\";",
-    //                 GenerationUtils::format_docs(variant_docs),
-    //                 tag_name,
-    //                 variant_name
-    //             );
-
-    //             for fld in fields {
-    //                 variant_type_code.push_str(&format!(
-    //                     "\n  /**\n{}\n**/\n  {}: {};",
-    //                     GenerationUtils::format_docs(&fld.docs),
-    //                     fld.name,
-    //                     fld.typescript_typename()
-    //                 ));
-    //             }
-
-    //             variant_type_code.push('}');
-    //             variant_type_code
-    //         })
-    //         .collect();
-
-    //     let type_code = type_code_items.join(" | ");
-
-    //     format!(
-    //         "/**\n{}\n**/\nexport type {} = {};",
-    //         GenerationUtils::format_docs(docs),
-    //         item_name,
-    //         type_code
-    //     )
-    // }
-
-    // /// Generates a complete TypeScript definition with optional imports
-    // pub fn generate_complete_definition(
-    //     typescript_type: &str,
-    //     includes_imports: bool,
-    // ) -> String {
-    //     if includes_imports {
-    //         #[cfg(feature = "zod")]
-    //         {
-    //             format!("import {{ z }} from \"zod\";\n\n{}", typescript_type)
-    //         }
-    //         #[cfg(not(feature = "zod"))]
-    //         {
-    //             typescript_type.to_string()
-    //         }
-    //     } else {
-    //         typescript_type.to_string()
-    //     }
-    // }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::field_type::{FieldDef, FieldDefType};
 
     #[test]
     fn test_generate_struct_type_empty() {
-        let result = TypeScriptGenerator::generate_struct_type("TestJson", &[], "Test docs");
-        assert!(result.contains("export type Test"));
-        assert!(result.contains("Record<string, never>"));
-        assert!(result.contains("Test docs"));
+        assert_eq!(
+            TypeScriptGenerator::generate_struct_type("Test", "", "", true),
+            "export type Test = Record<string, never>;"
+        );
     }
 
     #[test]
     fn test_generate_struct_type_with_fields() {
-        let fields = vec![
-            FieldDef {
-                is_optional: false,
-                name: "id".to_string(),
-                docs: "ID field".to_string(),
-                field_type: FieldDefType::String,
-                is_array: false,
-                array_num: None,
-                model_schema_prop_meta: None,
-            },
-            FieldDef {
-                is_optional: true,
-                name: "name".to_string(),
-                docs: "Name field".to_string(),
-                field_type: FieldDefType::String,
-                is_array: false,
-                array_num: None,
-                model_schema_prop_meta: None,
-            },
-        ];
+        let type_code = "  /**\n * ID field\n * \n**/\n  id: string;\n  /**\n * Name field\n * \n**/\n  name: string | undefined;\n";
+        let result = TypeScriptGenerator::generate_struct_type("User", "", type_code, false);
+
+        assert!(result.starts_with("export type User = {\n"));
+        assert!(result.contains("  id: string;\n"));
+        assert!(result.contains("  name: string | undefined;\n"));
+        assert!(result.ends_with("\n};"));
+    }
 
-        let result = TypeScriptGenerator::generate_struct_type("UserJson", &fields, "User struct");
-        assert!(result.contains("export type User"));
-        assert!(result.contains("id: string"));
-        assert!(result.contains("name: string | undefined"));
-        assert!(result.contains("User struct"));
+    #[test]
+    fn test_generate_struct_type_with_flattened_types() {
+        let result = TypeScriptGenerator::generate_struct_type("Order", "Audit & ", "  id: string;\n", false);
+        assert_eq!(result, "export type Order = Audit & {\n  id: string;\n\n};");
     }
 
     #[test]
     fn test_generate_plain_enum_type() {
         let options = vec!["active".to_string(), "inactive".to_string()];
-        let result = TypeScriptGenerator::generate_plain_enum_type("StatusJson", &options, "Status enum");
-        
-        assert!(result.contains("export type Status"));
-        assert!(result.contains("\"active\" | \"inactive\""));
-        assert!(result.contains("Status enum"));
+
+        assert_eq!(
+            TypeScriptGenerator::generate_plain_enum_type("Status", &options, false),
+            "export type Status = \"active\" | \"inactive\";"
+        );
+        assert_eq!(
+            TypeScriptGenerator::generate_plain_enum_type("Status", &options, true),
+            "export type Status = \"active\" | \"inactive\" | (string & {});"
+        );
     }
-} 
\ No newline at end of file
+}
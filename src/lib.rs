@@ -1,10 +1,18 @@
 mod field_type;
 mod model_schema;
+mod model_schema_alias;
+mod model_schema_bundle;
+mod model_schema_components;
+mod model_schema_union;
 mod utils;
 mod features;
 mod generation;
 
 use model_schema::exec_model_schema;
+use model_schema_alias::exec_model_schema_alias;
+use model_schema_bundle::{exec_model_schema_bundle, exec_model_schema_index};
+use model_schema_components::exec_model_schema_components;
+use model_schema_union::exec_model_schema_union;
 use proc_macro::TokenStream;
 use utils::safe_type_name;
 
@@ -91,7 +99,7 @@ use utils::safe_type_name;
 /// use serde::{Deserialize, Serialize};
 ///
 /// #[derive(Serialize, Deserialize)]
-/// #[serde(tag = "type", rename_all = "camelCase")]
+/// #[serde(tag = "type", rename_all = "camelCase", rename_all_fields = "camelCase")]
 /// #[model_schema()]
 /// pub enum Event {
 ///     UserCreated {
@@ -116,6 +124,146 @@ use utils::safe_type_name;
 /// // };
 /// ```
 ///
+/// `#[serde(untagged)]` enums become a plain union of their variants' values: an object for
+/// struct variants, the wrapped type for newtype variants and `null` for unit variants. The
+/// Zod schema is a `z.union([...])` and the JSON schema an `anyOf`.
+///
+/// A `#[serde(transparent)]` struct is written as its only field, so its schemas are that
+/// field's: `struct OrderIdJson(String)` is `export type OrderId = string;`.
+///
+/// ## Arguments
+///
+/// Tagged enums accept optional arguments:
+///
+/// - `tag = "kind"`: sets the discriminator field name, taking precedence over `#[serde(tag)]`
+/// - `untagged_fallback`: also accepts objects with an unknown tag in the TypeScript type
+///   (`| { type: string; [k: string]: unknown }`) and the Zod schema, for forward compatibility.
+///   The JSON schema is left strict.
+/// - `discriminator_description`: gives each `oneOf` branch of the JSON schema a `title` (the
+///   variant name) and a `description` (its doc comment), for generated API docs.
+///
+/// ```rust
+/// use tixschema::model_schema;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// #[serde(tag = "type")]
+/// #[model_schema(untagged_fallback)]
+/// pub enum Notification {
+///     Email { address: String },
+///     Sms { phone: String },
+/// }
+///
+/// // export const Notification$Schema = z.union([
+/// //   z.discriminatedUnion("type", [...]),
+/// //   z.looseObject({ type: z.string() }),
+/// // ]);
+/// ```
+///
+/// Structs and enums accept `rename_all = "camelCase"` (or any other serde casing, such as
+/// `"kebab-case"` or `"SCREAMING_SNAKE_CASE"`), which sets the casing without relying on the
+/// `serde` feature. It takes precedence over `#[serde(rename_all)]`. Keys that aren't valid
+/// identifiers, like `first-name`, are quoted in the TypeScript type and Zod object.
+///
+/// `rename_all` (the macro's or serde's) never applies to the types a field references: a
+/// `HashMap<String, ItemJson>` field of a camelCase struct only gets camelCase `Item` keys if
+/// `ItemJson` declares its own `rename_all`. The macro only sees the type it's applied to, so it
+/// can't check this. With `strict`, it emits a deprecation warning for each nested type
+/// referenced from a renamed struct or variant, as a reminder to check them; once checked,
+/// `#[allow(deprecated)]` on the type silences them.
+///
+/// `debug` generates a `FIELD_TREE` constant with a line for each field and the type the macro
+/// resolved it to, e.g. `` `EventJson` field `venues`: optional array of sibling `Venue` ``, to
+/// find out why a field maps to an unexpected TypeScript type or schema. The lines are also in
+/// the constant's docs.
+///
+/// `deserialize_shape` generates the shape serde reads rather than the one it writes, for
+/// request types: `#[serde(skip_deserializing)]` fields are left out instead of
+/// `#[serde(skip_serializing)]` ones.
+///
+/// `optionals_absent` matches the `serde_with::skip_serializing_none` setup, where `None`
+/// fields are left out rather than written: optional fields become `key?: T` in TypeScript and
+/// `.optional()` in Zod instead of `key: T | undefined`. The JSON schema is unchanged, since
+/// optional fields are never `required`.
+///
+/// `optionals_nullable` matches serde's default of writing `None` as `null`, as MongoDB drivers
+/// do for missing ObjectIds: optional fields become `key: T | null` in TypeScript,
+/// `.or(z.null())` in Zod, and in the JSON schema they're `required` with `"null"` added to
+/// their `"type"` (`["object", "null"]` for an `ObjectId`), or wrapped in `anyOf` otherwise.
+///
+/// `optional_style = "question_mark"` is the same as `optionals_absent`, for codebases that use
+/// `key?: T` with TypeScript's `exactOptionalPropertyTypes`. `optional_style = "null"` is
+/// `optionals_nullable`, and `optional_style = "undefined"` the default. Only one of the three
+/// arguments can be used.
+///
+/// `extern_map(GeoJson = "GeoJSON.Geometry")` renders fields of a type the macro can't look
+/// into, such as a third-party type, as the given TypeScript type, with `z.custom<T>()` as its
+/// Zod schema and `{}` as its JSON schema. `extern_map(Money(ts = "Dinero", zod = "Dinero$Schema"))`
+/// also sets the Zod schema.
+///
+/// `default_export` ends the output with a default export, for one `.ts` file per type:
+/// `export default User$Schema;` after `zod_schema()`, or `export type { User as default };`
+/// after `ts_definition()` without the `zod` feature. A module has one default export, so such
+/// types don't belong in a `model_schema_bundle!`.
+///
+/// `zod_import = "zod"` has the Zod schema call Zod through another identifier, e.g.
+/// `zod.string()` for code that imports it as `import * as zod from "zod"`. The default is `z`.
+/// `as_zod` and `extern_map` schemas are used as written, so they should call it the same way.
+///
+/// With the `fixtures` feature, `derive_default_instance` adds `default_json()`, which returns
+/// a skeleton instance: zero values for required fields, the first variant for enums, and the
+/// `default_json()` of nested types, which must use `derive_default_instance` as well.
+///
+/// With the `dual_schemas` feature, `zod_schema()` of a struct also declares a
+/// `{Name}$LooseSchema` constant built with `z.looseObject(...)`, which keeps unknown keys.
+///
+/// With the `enum_consts` feature, `ts_definition()` of a plain enum also exports
+/// `{Name}Values`, an `as const` array of the values in the union.
+///
+/// With the `io_ts` feature, `io_ts_codec()` returns an io-ts codec for the type, named after it
+/// (`export const User = t.type({ ... })`). It validates less than the Zod schema, see the README.
+///
+/// With the `reflect` feature, `ts_fields()` of a struct returns the serialized name and
+/// TypeScript type of each field.
+///
+/// With the `csv` feature, `csv_headers()` of a struct returns its serialized field names, for
+/// the header row of a CSV export.
+///
+/// With the `zod_describe` feature, each documented field's Zod schema ends with
+/// `.describe("...")` holding its doc comment.
+///
+/// The generated methods can be renamed with `ts_definition = "to_ts"`, `zod_schema = "zod"`,
+/// `json_schema = "schema"` and `emit = "emit_schema"`, e.g. to avoid colliding with existing
+/// methods. Other generated
+/// code, such as a parent embedding the type's JSON schema or `model_schema_bundle!`, calls the
+/// hidden `__tixschema_*` forwarders to these methods instead, so renaming them breaks nothing.
+///
+/// `rename = "Customer"` exports the type as `Customer` and `Customer$Schema` instead of the
+/// Rust name without its `Json` suffix. Fields of other types referencing it still use the
+/// derived name, so rename the types that aren't nested in others, such as API roots.
+///
+/// A unit-only enum with `#[serde(tag = "kind")]` is generated as a tagged union of objects
+/// (`{ kind: "active" } | { kind: "pending" }`), as serde writes it. Plain enums also accept
+/// `enum_as_object = "kind"`, which does the same without relying on the serde attribute.
+///
+/// Structs and tagged enums also accept `camel_schema_keys`, which asserts that every
+/// schema key is camelCase. Schema keys are single-sourced from the serialized name, because
+/// a Zod or JSON schema with different keys could never validate the payload. So the flag
+/// never renames anything: it rejects any key serde would not emit in camelCase.
+///
+/// ```compile_fail
+/// use tixschema::model_schema;
+/// use serde::{Deserialize, Serialize};
+///
+/// // error: `Profile` serializes the key `display_name`, which is not camelCase
+/// #[derive(Serialize, Deserialize)]
+/// #[serde(rename_all = "lowercase")]
+/// #[model_schema(camel_schema_keys)]
+/// pub struct Profile {
+///     display_name: String,
+/// }
+/// ```
+///
 /// ## MongoDB ObjectId Support
 ///
 /// When the `object_id` feature is enabled, the macro provides first-class support for MongoDB ObjectId types:
@@ -164,7 +312,8 @@ pub struct Document {
 ///
 ///
 /// ObjectId fields are serialized using MongoDB's standard format: `{ "$oid": "hex_string" }`
-/// and include proper validation for 24-character hexadecimal ObjectId strings.
+/// and include proper validation for 24-character hexadecimal ObjectId strings. With the
+/// `object_id_flexible` feature, the bare hex string is accepted too (`ObjectId | string`).
 ///
 #[proc_macro_attribute]
 pub fn model_schema(args: TokenStream, input: TokenStream) -> TokenStream {
@@ -198,6 +347,35 @@ pub fn model_schema(args: TokenStream, input: TokenStream) -> TokenStream {
 /// ## Parameters
 ///
 /// - `as`: Specifies an explicit type to use for the field in TypeScript
+/// - `literal`: Renders the field as a string literal type
+/// - `minLength`: Minimum length for string fields
+/// - `maxLength`: Maximum length for string fields
+/// - `min` / `max`: Inclusive bounds for integer and float fields, e.g. `min = 0, max = 100`
+/// - `deprecated`: Marks the field as deprecated in the generated schemas only, adding
+///   `@deprecated <reason>` to the TypeScript docs and `"deprecated": true` to the JSON schema
+/// - `read_only` / `write_only`: Marks response-only or request-only fields, emitting
+///   `readOnly` / `writeOnly` in the JSON schema (a `readonly` property in TypeScript).
+///   Read-only fields stay in `required`; OpenAPI applies that only to responses.
+/// - `trim` / `lowercase` / `uppercase`: Zod-only string transforms (`.trim()`,
+///   `.toLowerCase()`, `.toUpperCase()`), applied before `minLength` is checked
+/// - `required_with = "other_field"`: Requires this field whenever `other_field` is present,
+///   via the root `dependentRequired` JSON schema keyword and a Zod `.refine`
+/// - `enum_values = ["a", "b"]`: Restricts a `String` field to a closed set of values, rendered
+///   as `"a" | "b"`, `z.enum(["a", "b"])` and a JSON schema `enum`
+/// - `coerce`: Converts the input of a number, boolean or `String` field before validating it,
+///   with `z.coerce.number()` / `z.coerce.boolean()` / `z.coerce.string()` (Zod only)
+/// - `raw_json`: Renders a `String` field holding serialized JSON as any JSON (`unknown`,
+///   `z.unknown()`, `{}`); `raw_json(parse)` has the Zod schema parse the string instead
+/// - `pattern = "^[a-z]+$"`: Checks a `String` or `char` field against a regex (Zod `.regex()`,
+///   JSON schema `pattern`)
+/// - `ts_template = "user_${string}"`: Types a `String` field as a TypeScript template literal,
+///   checked with the equivalent regex in Zod and JSON schema
+/// - `keyPattern = "^[a-z-]+$"`: Checks the keys of a `String`-keyed map against a regex (Zod
+///   record key schema, JSON schema `propertyNames`)
+/// - `format = "email"`: Sets the JSON schema `format` of a `String` field; `email`, `uri`,
+///   `date-time`, `date`, `uuid`, `ipv4` and `ipv6` also get their Zod validator (`z.email()`, ...)
+/// - `as_ts = "Date"` / `as_zod = "z.coerce.date()"`: Replaces the TypeScript type / Zod schema of
+///   the field (or its elements) with raw text; the JSON schema is unchanged
 ///
 /// ## Example
 ///
@@ -234,3 +412,199 @@ pub fn model_schema_prop(_args: TokenStream, input: TokenStream) -> TokenStream
     // For now, simply pass through the input
     input
 }
+
+/// # model_schema_union
+///
+/// Composes types already annotated with `#[model_schema()]` into a single union, for example
+/// several discriminated enums sharing a tag that the frontend handles as one big union.
+///
+/// The macro declares an uninhabited enum with the given name carrying the usual
+/// `ts_definition()`, `zod_schema()` and `json_schema()` methods. Members are referenced by
+/// name, so their own definitions must be emitted alongside the union.
+///
+/// ## Usage
+///
+/// ```rust
+/// use tixschema::{model_schema, model_schema_union};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[model_schema()]
+/// #[derive(Serialize, Deserialize)]
+/// #[serde(tag = "type")]
+/// pub enum UserEventJson {
+///     Created { user_id: String },
+/// }
+///
+/// #[model_schema()]
+/// #[derive(Serialize, Deserialize)]
+/// #[serde(tag = "type")]
+/// pub enum BillingEventJson {
+///     Charged { amount: u32 },
+/// }
+///
+/// model_schema_union!(pub AllEventsJson = UserEventJson | BillingEventJson);
+///
+/// // Generates:
+/// // export type AllEvents = UserEvent | BillingEvent;
+/// // export const AllEvents$Schema: ZodType<AllEvents> = z.union([UserEvent$Schema, BillingEvent$Schema]);
+/// ```
+#[proc_macro]
+pub fn model_schema_union(input: TokenStream) -> TokenStream {
+    exec_model_schema_union(input)
+}
+
+/// # model_schema_alias
+///
+/// Exposes a type already annotated with `#[model_schema()]` under another name, for
+/// re-exports and aliases that `#[model_schema]` can't target (attribute macros don't apply
+/// to `type Foo = Bar;`).
+///
+/// Like `model_schema_union!`, the macro declares an uninhabited enum with the given name.
+/// Its `ts_definition()` and `zod_schema()` reference the target by name, and `json_schema()`
+/// returns the target's schema.
+///
+/// ## Usage
+///
+/// ```rust
+/// use tixschema::{model_schema, model_schema_alias};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[model_schema()]
+/// #[derive(Serialize, Deserialize)]
+/// pub struct UserJson {
+///     pub id: String,
+/// }
+///
+/// model_schema_alias!(pub AccountJson = UserJson);
+///
+/// // Generates:
+/// // export type Account = User;
+/// // export const Account$Schema: ZodType<Account> = User$Schema;
+/// ```
+#[proc_macro]
+pub fn model_schema_alias(input: TokenStream) -> TokenStream {
+    exec_model_schema_alias(input)
+}
+
+/// # model_schema_bundle
+///
+/// Expands to a `String` with the TypeScript types and Zod schemas of the listed types, ready
+/// to be written to a single generated file.
+///
+/// Types listed more than once are declared once. Declarations are topologically sorted by
+/// the generated `schema_dependencies()` of each type, so `Address$Schema` is declared
+/// before the schemas that use it: starting from each listed type in order, its dependencies
+/// are emitted first (depth-first), then the type itself. Dependencies that aren't part of
+/// the bundle are left for the file to import.
+///
+/// When types reference each other in a cycle, no order declares every schema before its
+/// use. The sort keeps going, and references to a schema that is declared further down are
+/// wrapped in `z.lazy(() => ...)`.
+///
+/// ## Usage
+///
+/// ```rust
+/// use tixschema::{model_schema, model_schema_bundle};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[model_schema()]
+/// #[derive(Serialize, Deserialize)]
+/// pub struct AddressJson {
+///     pub city: String,
+/// }
+///
+/// #[model_schema()]
+/// #[derive(Serialize, Deserialize)]
+/// pub struct UserJson {
+///     pub address: AddressJson,
+/// }
+///
+/// let bundle = model_schema_bundle!(UserJson, AddressJson);
+///
+/// // Declares Address (type and schema) first, then User:
+/// // export const Address$Schema: ZodType<Address> = z.strictObject({ ... });
+/// // ...
+/// // export const User$Schema: ZodType<User> = z.strictObject({ address: Address$Schema, ... });
+/// ```
+#[proc_macro]
+pub fn model_schema_bundle(input: TokenStream) -> TokenStream {
+    exec_model_schema_bundle(input)
+}
+
+/// # model_schema_index
+///
+/// Expands to a `String` with an `index.ts` barrel re-exporting the listed types, for output
+/// written as one file per type (`User.ts`, `Address.ts`, ...). Each type gets an
+/// `export * from "./User";` line, named after its generated type, in listing order and once.
+///
+/// `export *` doesn't forward default exports, so the barrel only re-exports the named
+/// `User` type and `User$Schema`. A single-file `model_schema_bundle!` needs no barrel, as
+/// every declaration in it is already exported.
+///
+/// ## Usage
+///
+/// ```rust
+/// use tixschema::{model_schema, model_schema_index};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[model_schema()]
+/// #[derive(Serialize, Deserialize)]
+/// pub struct AddressJson {
+///     pub city: String,
+/// }
+///
+/// #[model_schema()]
+/// #[derive(Serialize, Deserialize)]
+/// pub struct UserJson {
+///     pub address: AddressJson,
+/// }
+///
+/// let index = model_schema_index!(UserJson, AddressJson);
+///
+/// // export * from "./User";
+/// // export * from "./Address";
+/// ```
+#[proc_macro]
+pub fn model_schema_index(input: TokenStream) -> TokenStream {
+    exec_model_schema_index(input)
+}
+
+/// # model_schema_components
+///
+/// Expands to a `serde_json::Value` holding the `components.schemas` object of an OpenAPI
+/// document, with the JSON schema of every listed type under its generated name.
+///
+/// Nested types are inlined by `json_schema()`. In the components, a nested schema equal to
+/// another listed type's becomes a `{ "$ref": "#/components/schemas/Name" }` to its entry, so
+/// shared definitions appear once. Nested types that aren't listed stay inlined. Requires the
+/// `jsonschema` feature; without it the object is empty.
+///
+/// ## Usage
+///
+/// ```rust
+/// use tixschema::{model_schema, model_schema_components};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[model_schema()]
+/// #[derive(Serialize, Deserialize)]
+/// pub struct AddressJson {
+///     pub city: String,
+/// }
+///
+/// #[model_schema()]
+/// #[derive(Serialize, Deserialize)]
+/// pub struct UserJson {
+///     pub address: AddressJson,
+/// }
+///
+/// let schemas = model_schema_components!(UserJson, AddressJson);
+///
+/// // {
+/// //   "User": { "properties": { "address": { "$ref": "#/components/schemas/Address" } }, ... },
+/// //   "Address": { "properties": { "city": { "type": "string" } }, ... }
+/// // }
+/// ```
+#[proc_macro]
+pub fn model_schema_components(input: TokenStream) -> TokenStream {
+    exec_model_schema_components(input)
+}
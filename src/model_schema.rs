@@ -1,162 +1,879 @@
 use std::fmt::Write;
-use std::{collections::HashMap, env};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 
 use proc_macro::TokenStream;
 use quote::quote;
 use syn::{Field, Item, parse_macro_input};
 
 use crate::{
-    field_type::{FieldDef, FieldDefType, get_field_def, is_plain_enum},
-    safe_type_name,
+    features::model_schema_args::{MethodNames, ModelSchemaArgs, OptionalStyle},
+    field_type::{FieldDef, FieldDefType, get_field_def, is_plain_enum, sibling_json_type},
+    generation::GenerationUtils,
     utils::{get_field_docs, get_variant_docs},
 };
 
+#[cfg(any(feature = "typescript", feature = "zod"))]
+use crate::features::model_schema_args::CodegenOptions;
+
 #[cfg(feature = "serde")]
-use crate::field_type::{parse_serde_field_attributes, parse_serde_type_attributes};
+use crate::field_type::{VariantShape, parse_serde_field_attributes, parse_serde_type_attributes};
 
 #[cfg(feature = "typescript")]
 use crate::utils::{get_enum_docs, get_struct_docs};
+#[cfg(feature = "typescript")]
+use crate::generation::typescript::TypeScriptGenerator;
+
+#[cfg(feature = "fixtures")]
+use crate::features::fixtures::{
+    generate_discriminated_enum_default_json_method, generate_plain_enum_default_json_method,
+    generate_struct_default_json_method,
+};
+#[cfg(all(feature = "fixtures", feature = "serde"))]
+use crate::features::fixtures::{generate_transparent_struct_default_json_method, generate_untagged_enum_default_json_method};
+
+#[cfg(feature = "reflect")]
+use crate::features::reflect::generate_ts_fields_method;
+
+#[cfg(feature = "csv")]
+use crate::features::csv::generate_csv_headers_method;
+
+#[cfg(feature = "io_ts")]
+use crate::features::io_ts::{
+    generate_discriminated_enum_io_ts_codec_method, generate_plain_enum_io_ts_codec_method,
+    generate_struct_io_ts_codec_method,
+};
+#[cfg(all(feature = "io_ts", feature = "serde"))]
+use crate::features::io_ts::{generate_transparent_struct_io_ts_codec_method, generate_untagged_enum_io_ts_codec_method};
 
 /// Executes the model_schema macro processing to generate TypeScript and Zod schema definitions.
 ///
 /// This function is the main entry point for the model_schema macro and handles both struct and enum types.
-pub(crate) fn exec_model_schema(_args: TokenStream, input: TokenStream) -> TokenStream {
+pub(crate) fn exec_model_schema(args: TokenStream, input: TokenStream) -> TokenStream {
+    let mut schema_args = ModelSchemaArgs::default();
+    let args_parser = syn::meta::parser(|meta| schema_args.parse(meta));
+    parse_macro_input!(args with args_parser);
+
     let item = parse_macro_input!(input as Item);
+    if let Err(err) = check_target(&item, &schema_args).and_then(|()| check_field_types(&item, &schema_args)) {
+        return err.to_compile_error().into();
+    }
+
+    let mut output = match item {
+        Item::Struct(item_struct) => process_struct(item_struct, &schema_args),
+        Item::Enum(item_enum) => process_enum(item_enum, &schema_args),
+        _ => unreachable!("checked by check_target"),
+    };
+
+    if !cfg!(any(feature = "typescript", feature = "zod", feature = "jsonschema")) {
+        output.extend(TokenStream::from(no_output_feature_warning()));
+    }
+
+    output
+}
+
+/// Checks that the macro is applied to a struct or an enum, with arguments that apply to it.
+fn check_target(item: &Item, schema_args: &ModelSchemaArgs) -> syn::Result<()> {
     match item {
-        Item::Struct(item_struct) => process_struct(item_struct),
-        Item::Enum(item_enum) => process_enum(item_enum),
-        _ => panic!("Unsupported target for model_schema"),
+        Item::Struct(item_struct) if schema_args.has_enum_args() => Err(syn::Error::new_spanned(
+            &item_struct.ident,
+            "model_schema arguments `tag`, `untagged_fallback`, `enum_as_object` and `discriminator_description` are only supported on enums",
+        )),
+        Item::Struct(item_struct) => check_transparent_struct(item_struct, schema_args),
+        Item::Enum(item_enum) => check_enum_args(item_enum, schema_args),
+        Item::Union(item_union) => Err(syn::Error::new_spanned(
+            item_union.union_token,
+            "model_schema doesn't support unions: serde can't derive them, so they have no JSON shape. Use an enum instead",
+        )),
+        other => Err(syn::Error::new_spanned(other, "model_schema only supports structs and enums")),
+    }
+}
+
+/// Checks that a `#[serde(transparent)]` struct has the single field serde writes it as.
+fn check_transparent_struct(item_struct: &syn::ItemStruct, schema_args: &ModelSchemaArgs) -> syn::Result<()> {
+    #[cfg(feature = "serde")]
+    if parse_serde_type_attributes(&item_struct.attrs).transparent {
+        let kept = item_struct
+            .fields
+            .iter()
+            .filter(|field| !is_skipped_field(&mut (*field).clone(), schema_args.deserialize_shape))
+            .count();
+        if kept != 1 {
+            return Err(syn::Error::new_spanned(
+                &item_struct.fields,
+                format!(
+                    "`#[serde(transparent)]` struct `{}` must have exactly one field that isn't skipped",
+                    item_struct.ident
+                ),
+            ));
+        }
+    }
+    #[cfg(not(feature = "serde"))]
+    let _ = (item_struct, schema_args);
+
+    Ok(())
+}
+
+/// Checks that the enum-only arguments fit the kind of enum they're applied to, pointing at
+/// the argument that doesn't.
+fn check_enum_args(item_enum: &syn::ItemEnum, schema_args: &ModelSchemaArgs) -> syn::Result<()> {
+    #[cfg(feature = "serde")]
+    let (has_serde_tag, untagged) = {
+        let serde_type_meta = parse_serde_type_attributes(&item_enum.attrs);
+        (serde_type_meta.tag.is_some(), serde_type_meta.untagged)
+    };
+    #[cfg(not(feature = "serde"))]
+    let (has_serde_tag, untagged) = (false, false);
+
+    // Untagged enums are written as their variants' values, with no tag to name or fall back on
+    let tag_args = [
+        ("tag", schema_args.tag.is_some()),
+        ("untagged_fallback", schema_args.untagged_fallback),
+        ("enum_as_object", schema_args.enum_as_object.is_some()),
+    ];
+    if untagged && let Some((arg, _)) = tag_args.iter().find(|(_, used)| *used) {
+        return Err(syn::Error::new(
+            schema_args.span(arg),
+            "model_schema arguments `tag`, `untagged_fallback` and `enum_as_object` are not supported on `#[serde(untagged)]` enums",
+        ));
+    }
+
+    let all_unit = is_plain_enum(item_enum);
+    if schema_args.enum_as_object.is_some() && (!all_unit || schema_args.tag.is_some()) {
+        return Err(syn::Error::new(
+            schema_args.span("enum_as_object"),
+            "model_schema argument `enum_as_object` only applies to plain enums and replaces `tag`",
+        ));
+    }
+
+    // A plain enum is a union of strings, without the objects these arguments shape
+    let is_plain = all_unit
+        && !untagged
+        && !has_serde_tag
+        && schema_args.tag.is_none()
+        && schema_args.enum_as_object.is_none();
+    let object_args = [
+        ("untagged_fallback", schema_args.untagged_fallback),
+        ("camel_schema_keys", schema_args.camel_schema_keys),
+        ("discriminator_description", schema_args.discriminator_description),
+    ];
+    if is_plain && let Some((arg, _)) = object_args.iter().find(|(_, used)| *used) {
+        return Err(syn::Error::new(
+            schema_args.span(arg),
+            "model_schema arguments `untagged_fallback`, `camel_schema_keys` and `discriminator_description` are not supported on plain enums",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Checks that the type of every generated field is supported, so the error points at the
+/// field's type instead of being a macro panic.
+fn check_field_types(item: &Item, schema_args: &ModelSchemaArgs) -> syn::Result<()> {
+    let fields: Vec<&Field> = match item {
+        Item::Struct(item_struct) => item_struct.fields.iter().collect(),
+        Item::Enum(item_enum) => item_enum.variants.iter().flat_map(|variant| variant.fields.iter()).collect(),
+        _ => Vec::new(),
+    };
+
+    for original in fields {
+        let mut field = original.clone();
+        if is_skipped_field(&mut field, schema_args.deserialize_shape) {
+            continue;
+        }
+        crate::features::model_schema_prop::parse_model_schema_prop_attributes(&field.attrs)?;
+        let mut f_def = typed_field_def(&None, &mut field);
+        let prop_meta = f_def.model_schema_prop_meta.clone().unwrap_or_default();
+        if prop_meta.raw_json && !matches!(f_def.field_type, FieldDefType::String) {
+            return Err(syn::Error::new_spanned(
+                &field.ty,
+                format!(
+                    "model_schema_prop `raw_json` on field `{}` only applies to String fields holding serialized JSON",
+                    f_def.name
+                ),
+            ));
+        }
+        apply_model_schema_props(&mut f_def);
+        if let Some(template) = f_def.ts_template() {
+            if !matches!(f_def.field_type, FieldDefType::String) || f_def.enum_values().is_some() {
+                return Err(model_schema_prop_error(
+                    original,
+                    "ts_template",
+                    format!("model_schema_prop `ts_template` on field `{}` only applies to String fields without enum_values", f_def.name),
+                ));
+            }
+            if let Err(err) = crate::features::model_schema_prop::ts_template_pattern(template) {
+                return Err(model_schema_prop_error(
+                    original,
+                    "ts_template",
+                    format!("model_schema_prop `ts_template` on field `{}`: {err}", f_def.name),
+                ));
+            }
+        }
+        if f_def.model_schema_prop_meta.as_ref().is_some_and(|meta| meta.pattern.is_some())
+            && (!matches!(f_def.field_type, FieldDefType::String | FieldDefType::Char)
+                || f_def.enum_values().is_some()
+                || f_def.ts_template().is_some())
+        {
+            return Err(syn::Error::new_spanned(
+                &field.ty,
+                format!(
+                    "model_schema_prop `pattern` on field `{}` only applies to String and char fields without enum_values or ts_template",
+                    f_def.name
+                ),
+            ));
+        }
+        if f_def.string_format_name().is_some() {
+            if !matches!(f_def.field_type, FieldDefType::String) || f_def.enum_values().is_some() {
+                return Err(syn::Error::new_spanned(
+                    &field.ty,
+                    format!("model_schema_prop `format` on field `{}` only applies to String fields without enum_values", f_def.name),
+                ));
+            }
+            // `z.coerce` has no format validators, e.g. `z.coerce.email()`
+            if f_def.is_coerced() {
+                return Err(model_schema_prop_error(
+                    original,
+                    "format",
+                    format!("model_schema_prop `format` on field `{}` can't be combined with `coerce`", f_def.name),
+                ));
+            }
+        }
+        if f_def.model_schema_prop_meta.as_ref().is_some_and(|meta| meta.key_pattern.is_some()) && !has_string_keys(&f_def) {
+            return Err(syn::Error::new_spanned(
+                &field.ty,
+                format!("model_schema_prop `keyPattern` on field `{}` only applies to maps with String keys", f_def.name),
+            ));
+        }
+        // `as_zod` replaces the whole expression, so `z.coerce` has nothing to apply to
+        if f_def.as_zod().is_some() && f_def.is_coerced() {
+            return Err(model_schema_prop_error(
+                original,
+                "as_zod",
+                format!("model_schema_prop `as_zod` on field `{}` can't be combined with `coerce`", f_def.name),
+            ));
+        }
+        if f_def.is_coerced() && !is_coercible(&f_def) {
+            return Err(syn::Error::new_spanned(
+                &field.ty,
+                format!(
+                    "model_schema_prop `coerce` on field `{}` only applies to number, boolean, String and char fields",
+                    f_def.name
+                ),
+            ));
+        }
+        f_def.map_extern_types(&schema_args.extern_map);
+        if let Some(message) = unsupported_type_error(&f_def, &f_def) {
+            return Err(syn::Error::new_spanned(&field.ty, message));
+        }
+        // A literal is always a string, so a number or boolean field would fail to validate
+        // every value serde writes for it
+        if let Some(literal) = f_def.model_schema_prop_meta.as_ref().and_then(|meta| meta.literal.as_ref())
+            && !matches!(f_def.field_type, FieldDefType::StringLiteral(_))
+        {
+            let ty = &field.ty;
+            return Err(syn::Error::new_spanned(
+                ty,
+                format!(
+                    "model_schema_prop `literal` on field `{}` only applies to String fields, but the field is `{}`; the literal \"{literal}\" would always be a string",
+                    f_def.name,
+                    quote!(#ty).to_string().replace(' ', "")
+                ),
+            ));
+        }
+        if let Some(values) = f_def.enum_values() {
+            if !matches!(f_def.field_type, FieldDefType::String | FieldDefType::Char) {
+                return Err(syn::Error::new_spanned(
+                    &field.ty,
+                    format!("model_schema_prop `enum_values` on field `{}` only applies to String and char fields", f_def.name),
+                ));
+            }
+            if values.is_empty() {
+                return Err(syn::Error::new_spanned(
+                    &field.ty,
+                    format!("model_schema_prop `enum_values` on field `{}` needs at least one value", f_def.name),
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Whether Zod can coerce the field's input with `z.coerce`, which only has constructors for
+/// numbers, booleans and plain strings.
+fn is_coercible(field_def: &FieldDef) -> bool {
+    match field_def.field_type {
+        FieldDefType::String | FieldDefType::Char => field_def.enum_values().is_none(),
+        FieldDefType::Boolean
+        | FieldDefType::U8
+        | FieldDefType::U16
+        | FieldDefType::U32
+        | FieldDefType::U64
+        | FieldDefType::I8
+        | FieldDefType::I16
+        | FieldDefType::I32
+        | FieldDefType::I64
+        | FieldDefType::Usize
+        | FieldDefType::Isize
+        | FieldDefType::F32
+        | FieldDefType::F64
+        | FieldDefType::Number => true,
+        _ => false,
+    }
+}
+
+/// Returns an error message for the first type in `field_def`, looking through map keys and
+/// values and nested sequences, that the schemas can't describe: a generic type of the user's,
+/// or a tuple, which serde writes as an array.
+fn unsupported_type_error(field: &FieldDef, field_def: &FieldDef) -> Option<String> {
+    match &field_def.field_type {
+        FieldDefType::SiblingType(name, lst, _) if !lst.is_empty() => Some(format!(
+            "Unsupported generic type on field `{}`: `{name}<..>` has no JSON schema. Use a concrete type, or map it with `extern_map` or `#[model_schema_prop(as = \"...\")]`",
+            field.name
+        )),
+        FieldDefType::Tuple(_) => Some(format!(
+            "Unsupported tuple type on field `{}`: serde writes tuples as arrays, which the schemas can't describe. Use a struct, or map it with `#[model_schema_prop(as = \"...\")]`",
+            field.name
+        )),
+        FieldDefType::Map(key, value) => {
+            unsupported_type_error(field, key).or_else(|| unsupported_type_error(field, value))
+        }
+        FieldDefType::Nested(inner) => unsupported_type_error(field, inner),
+        _ => None,
+    }
+}
+
+/// Whether the field is a map (not a sequence of maps) with String keys, the only keys a
+/// `keyPattern` can check.
+fn has_string_keys(field_def: &FieldDef) -> bool {
+    !field_def.is_array
+        && matches!(&field_def.field_type, FieldDefType::Map(key, _) if matches!(key.field_type, FieldDefType::String))
+}
+
+/// Returns an error about the model_schema_prop `option` of a field, pointing at the attribute
+/// setting it.
+fn model_schema_prop_error(field: &Field, option: &str, message: String) -> syn::Error {
+    let attr = field.attrs.iter().find(|attr| {
+        attr.path().is_ident("model_schema_prop")
+            && attr.meta.require_list().is_ok_and(|list| {
+                list.tokens
+                    .clone()
+                    .into_iter()
+                    .any(|token| matches!(token, proc_macro2::TokenTree::Ident(ident) if ident == option))
+            })
+    });
+    match attr {
+        Some(attr) => syn::Error::new_spanned(attr, message),
+        None => syn::Error::new_spanned(&field.ty, message),
+    }
+}
+
+/// Emits a deprecation warning at the call site when none of the output features are
+/// enabled, since the generated `impl` block would otherwise be silently empty.
+fn no_output_feature_warning() -> proc_macro2::TokenStream {
+    expansion_warning(
+        "model_schema_has_no_output_feature",
+        "#[model_schema] generates no methods: enable at least one of the `typescript`, `zod` or `jsonschema` features of tixschema",
+        &[],
+    )
+}
+
+/// Emits `note` as a compiler warning at the call site. Proc macros can't emit warnings on
+/// stable, but the use of a `#[deprecated]` item can, which `-D warnings` also turns into an
+/// error. The `#[allow(...)]` attributes of the annotated type are copied over, so
+/// `#[allow(deprecated)]` on it silences the warning.
+fn expansion_warning(name: &str, note: &str, attrs: &[syn::Attribute]) -> proc_macro2::TokenStream {
+    let name = syn::Ident::new(name, proc_macro2::Span::call_site());
+    let allows = attrs.iter().filter(|attr| attr.path().is_ident("allow"));
+    quote! {
+        #(#allows)*
+        const _: () = {
+            #[deprecated(note = #note)]
+            #[allow(non_upper_case_globals)]
+            const #name: () = ();
+            #name
+        };
     }
 }
 
 /// Processes a struct item and generates TypeScript and Zod schema definitions for it.
-fn process_struct(mut item_struct: syn::ItemStruct) -> TokenStream {
+fn process_struct(mut item_struct: syn::ItemStruct, schema_args: &ModelSchemaArgs) -> TokenStream {
     let name = &item_struct.ident;
 
     #[cfg(feature = "serde")]
-    let rename_all = parse_serde_type_attributes(&item_struct.attrs).rename_all;
+    let serde_type_meta = parse_serde_type_attributes(&item_struct.attrs);
+
+    // A transparent struct is written as its only field, so it has no object schema
+    #[cfg(feature = "serde")]
+    if serde_type_meta.transparent {
+        return process_transparent_struct(item_struct, schema_args);
+    }
+
+    #[cfg(feature = "serde")]
+    let rename_all = serde_type_meta.rename_all;
     #[cfg(not(feature = "serde"))]
     let rename_all = None;
 
-    #[cfg(any(feature = "typescript", feature = "zod"))]
-    let item_name = safe_type_name(&name.to_string());
+    // `#[model_schema(rename_all = "...")]` takes precedence over serde's
+    let rename_all = schema_args.rename_all.clone().or(rename_all);
+
+    #[cfg(any(feature = "typescript", feature = "zod", feature = "jsonschema", feature = "io_ts"))]
+    let item_name = schema_args.item_name(name);
 
-    // Process all fields in the struct
+    // Process all fields in the struct. A flattened `HashMap<String, T>` doesn't become a
+    // property: its entries are the struct's additional properties, all of type T. A flattened
+    // struct doesn't either: its fields are merged into this one's.
     let mut field_defs = Vec::new();
+    let mut catchall: Option<FieldDef> = None;
+    let mut flattened: Vec<FieldDef> = Vec::new();
+    let mut field_spans = Vec::new();
+    let mut serialized_names: HashMap<String, String> = HashMap::new();
     for field in &mut item_struct.fields {
-        let f_def = process_field(&rename_all, field);
+        if is_skipped_field(field, schema_args.deserialize_shape) {
+            continue;
+        }
+
+        #[cfg(feature = "serde")]
+        let is_flattened = parse_serde_field_attributes(&field.attrs).flatten;
+        #[cfg(not(feature = "serde"))]
+        let is_flattened = false;
+
+        let mut f_def = process_field(&rename_all, field);
+        f_def.map_extern_types(&schema_args.extern_map);
+        if let Some(ident) = &field.ident {
+            serialized_names.insert(ident.to_string(), f_def.name.clone());
+        }
+        if is_flattened
+            && !f_def.is_array
+            && let FieldDefType::Map(_, value) = &f_def.field_type
+        {
+            catchall = Some(value.as_ref().clone());
+            continue;
+        }
+        if is_flattened
+            && !f_def.is_array
+            && !f_def.is_optional
+            && matches!(&f_def.field_type, FieldDefType::SiblingType(_, generics, _) if generics.is_empty())
+        {
+            flattened.push(f_def);
+            continue;
+        }
+        field_spans.push(field_span(field));
         field_defs.push(f_def);
     }
 
+    if let Err(err) = check_duplicate_names(&name.to_string(), &field_defs, &field_spans) {
+        return err.to_compile_error().into();
+    }
+
+    if schema_args.camel_schema_keys
+        && let Err(err) = check_camel_schema_keys(name, &field_defs, &field_spans)
+    {
+        return err.to_compile_error().into();
+    }
+
+    let warnings = if schema_args.strict {
+        warn_nested_casing(&name.to_string(), &rename_all, &field_defs, &flattened, &item_struct.attrs)
+    } else {
+        proc_macro2::TokenStream::new()
+    };
+
+    let field_tree = schema_args.debug.then(|| {
+        crate::features::debug::field_tree_notes(&name.to_string(), field_defs.iter().chain(&flattened).chain(&catchall))
+    });
+
+    let dependent_required = match collect_dependent_required(name, &mut field_defs, &serialized_names) {
+        Ok(dependent_required) => dependent_required,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    #[cfg(any(feature = "typescript", feature = "zod"))]
+    let dependencies = {
+        let mut deps = BTreeSet::new();
+        field_defs
+            .iter()
+            .chain(&catchall)
+            .chain(&flattened)
+            .for_each(|fld| fld.collect_dependencies(&mut deps));
+        deps
+    };
+
+    #[cfg(feature = "fixtures")]
+    let default_json_method = schema_args
+        .derive_default_instance
+        .then(|| generate_struct_default_json_method(&field_defs));
+
+    #[cfg(feature = "io_ts")]
+    let io_ts_codec_method = generate_struct_io_ts_codec_method(&item_name, &field_defs);
+
+    #[cfg(feature = "reflect")]
+    let ts_fields_method = generate_ts_fields_method(&field_defs);
+
+    #[cfg(feature = "csv")]
+    let csv_headers_method = generate_csv_headers_method(&field_defs);
+
+    #[cfg(any(feature = "typescript", feature = "zod"))]
+    let codegen_options = schema_args.codegen_options();
+
     // Generate TypeScript type and Zod schema code
     let mut type_code = String::new();
     let mut schema_code = String::new();
     let mut opts = Vec::new();
     let mut json_schema_fields: Vec<proc_macro2::TokenStream> = Vec::new();
 
+    // The flattened structs' fields come first, as serde writes them in declaration order
+    let flattened_names: Vec<(String, Option<syn::Path>)> = flattened
+        .iter()
+        .filter_map(|fld| match &fld.field_type {
+            FieldDefType::SiblingType(sibling, _, module) => Some((sibling.clone(), module.clone())),
+            _ => None,
+        })
+        .collect();
+    for (sibling, _) in &flattened_names {
+        #[cfg(feature = "zod")]
+        if let Err(err) = writeln!(schema_code, "  ...{sibling}$Schema.shape,") {
+            panic!("Failed to write Zod schema: {err}");
+        }
+        #[cfg(not(feature = "zod"))]
+        let _ = sibling;
+    }
+
+    let optional_style = schema_args.optional_style();
     for fld in field_defs {
-        write_field_type_and_schema(&mut type_code, &mut schema_code, &fld);
+        write_field_type_and_schema(
+            &mut type_code,
+            &mut schema_code,
+            &fld,
+            optional_style,
+            #[cfg(feature = "zod")]
+            &codegen_options.zod_import,
+        );
 
         if fld.is_optional {
             opts.push(fld.name.to_string());
         }
 
-        json_schema_fields.push(build_field_schema(&fld));
+        json_schema_fields.push(build_property_schema(&fld, optional_style));
+    }
+
+    if let Some(ref value) = catchall {
+        // Known fields don't have to match T, so the index signature stays `unknown`
+        if let Err(err) = writeln!(
+            type_code,
+            "  /**\n * Additional properties: {}\n**/\n  [key: string]: unknown;",
+            value.typescript_typename()
+        ) {
+            panic!("Failed to write TypeScript type: {err}");
+        }
     }
 
     #[cfg(feature = "typescript")]
-    let fields_empty = json_schema_fields.is_empty();
+    let fields_empty = json_schema_fields.is_empty() && catchall.is_none() && flattened_names.is_empty();
+
+    // `Base & Extra & { ... }`
+    #[cfg(feature = "typescript")]
+    let intersected = flattened_names.iter().map(|(sibling, _)| format!("{sibling} & ")).collect::<String>();
 
     #[cfg(feature = "zod")]
-    let show_opts = "";
+    let show_opts = {
+        let mut show_opts = catchall
+            .as_ref()
+            .map(|value| format!(".catchall({})", value.zod_type(&codegen_options.zod_import)))
+            .unwrap_or_default();
+        for (trigger, dependent) in &dependent_required {
+            show_opts.push_str(&format!(
+                ".refine(v => v[\"{trigger}\"] === undefined || v[\"{dependent}\"] !== undefined, {{ message: \"{dependent} is required when {trigger} is present\", path: [\"{dependent}\"] }})"
+            ));
+        }
+        show_opts
+    };
+
+    #[cfg(feature = "jsonschema")]
+    let catchall_schema = catchall.as_ref().map(catchall_json_schema);
+
+    #[cfg(feature = "jsonschema")]
+    let flattened_schemas: Vec<proc_macro2::TokenStream> = flattened_names
+        .iter()
+        .map(|(sibling, module)| {
+            let sibling_json = sibling_json_type(sibling, module);
+            inlined_json_schema(quote! { #sibling_json })
+        })
+        .collect();
+
+    #[cfg(not(any(feature = "zod", feature = "jsonschema")))]
+    let _ = &dependent_required;
 
     #[cfg(feature = "typescript")]
-    let docs = match get_struct_docs(&item_struct) {
-        Some(doc_lines) => doc_lines
-            .into_iter()
-            .flat_map(|v| v.lines().map(|l| l.to_owned()).collect::<Vec<_>>())
-            .chain(vec!["".to_string()])
-            .map(|l| format!(" * {l}"))
-            .collect::<Vec<_>>()
-            .join("\n"),
-        None => [name.to_string(), "".to_string()]
-            .into_iter()
-            .map(|l| format!(" * {l}"))
-            .collect::<Vec<_>>()
-            .join("\n"),
-    };
+    let docs = GenerationUtils::format_docs(get_struct_docs(&item_struct), &name.to_string());
+
+    #[cfg(any(feature = "typescript", feature = "zod", feature = "jsonschema"))]
+    let method_names = schema_args.method_names();
 
     // Generate the final output with conditional compilation
     #[cfg(feature = "jsonschema")]
-    let json_schema_method = generate_json_schema_method(&json_schema_fields);
+    let json_schema_method = generate_json_schema_method(
+        &json_schema_fields,
+        &flattened_schemas,
+        catchall_schema.as_ref(),
+        &dependent_required,
+        &method_names,
+        &item_name,
+    );
 
     #[cfg(feature = "typescript")]
     let ts_definition_method =
-        generate_ts_definition_method(&docs, &item_name, &type_code, fields_empty);
+        generate_ts_definition_method(
+            &docs,
+            &item_name,
+            &intersected,
+            &type_code,
+            fields_empty,
+            &method_names,
+            &codegen_options,
+        );
 
     #[cfg(feature = "zod")]
-    let zod_schema_method = generate_zod_schema_method(&item_name, &schema_code, show_opts);
+    let zod_schema_method =
+        generate_zod_schema_method(&item_name, &schema_code, &show_opts, &method_names, &codegen_options);
 
-    let impl_items: Vec<proc_macro2::TokenStream> = vec![
+    #[allow(unused_mut)]
+    let mut impl_items: Vec<proc_macro2::TokenStream> = vec![
         #[cfg(feature = "jsonschema")]
         json_schema_method,
         #[cfg(feature = "typescript")]
         ts_definition_method,
         #[cfg(feature = "zod")]
         zod_schema_method,
+        #[cfg(any(feature = "typescript", feature = "zod"))]
+        generate_schema_dependencies_method(&dependencies),
     ];
 
+    if let Some(notes) = &field_tree {
+        impl_items.push(generate_field_tree_const(notes));
+    }
+
+    #[cfg(feature = "fixtures")]
+    impl_items.extend(default_json_method);
+
+    #[cfg(feature = "io_ts")]
+    impl_items.push(io_ts_codec_method);
+
+    #[cfg(feature = "reflect")]
+    impl_items.push(ts_fields_method);
+
+    #[cfg(feature = "csv")]
+    impl_items.push(csv_headers_method);
+
+    #[cfg(any(feature = "typescript", feature = "zod", feature = "jsonschema"))]
+    impl_items.push(generate_emit_method(&method_names));
+    #[cfg(any(feature = "typescript", feature = "zod", feature = "jsonschema"))]
+    impl_items.push(generate_method_aliases(&method_names));
+    #[cfg(feature = "zod")]
+    impl_items.push(generate_zod_import_method(&codegen_options.zod_import));
+
+    let (impl_generics, ty_generics, where_clause) = item_struct.generics.split_for_impl();
+
     let output = quote! {
         #item_struct
 
-        impl #name {
+        impl #impl_generics #name #ty_generics #where_clause {
             #(#impl_items) *
         }
+
+        #warnings
+    };
+
+    TokenStream::from(output)
+}
+
+/// Processes a `#[serde(transparent)]` struct. serde writes it as its only field that isn't
+/// skipped, so its schemas are that field's, declared under the struct's name: a newtype around
+/// `String` is `export type OrderId = string;`.
+#[cfg(feature = "serde")]
+fn process_transparent_struct(mut item_struct: syn::ItemStruct, schema_args: &ModelSchemaArgs) -> TokenStream {
+    let name = item_struct.ident.clone();
+
+    let mut fields = Vec::new();
+    for field in &mut item_struct.fields {
+        if !is_skipped_field(field, schema_args.deserialize_shape) {
+            fields.push(field);
+        }
+    }
+    let [field] = fields.as_mut_slice() else {
+        unreachable!("checked by check_target");
+    };
+    let mut inner = process_field(&None, field);
+    inner.map_extern_types(&schema_args.extern_map);
+
+    let field_tree = schema_args
+        .debug
+        .then(|| crate::features::debug::field_tree_notes(&name.to_string(), std::slice::from_ref(&inner)));
+
+    #[cfg(any(feature = "typescript", feature = "zod", feature = "jsonschema", feature = "io_ts"))]
+    let item_name = schema_args.item_name(&name);
+
+    #[cfg(any(feature = "typescript", feature = "zod"))]
+    let dependencies = {
+        let mut deps = BTreeSet::new();
+        inner.collect_dependencies(&mut deps);
+        deps
     };
 
-    if env::var("RUST_LOG") == Ok(String::from("trace")) {
-        let output_str = output.to_string();
-        println!("{output_str}");
+    #[cfg(feature = "typescript")]
+    let docs = GenerationUtils::format_docs(get_struct_docs(&item_struct), &name.to_string());
+
+    #[cfg(feature = "jsonschema")]
+    let main_schema_code = value_json_schema(&inner);
+
+    #[cfg(any(feature = "typescript", feature = "zod", feature = "jsonschema"))]
+    let method_names = schema_args.method_names();
+    #[cfg(any(feature = "typescript", feature = "zod"))]
+    let codegen_options = schema_args.codegen_options();
+
+    #[allow(unused_mut)]
+    let mut impl_items: Vec<proc_macro2::TokenStream> = vec![
+        #[cfg(feature = "jsonschema")]
+        generate_discriminated_enum_json_schema_method(&main_schema_code, &method_names, &item_name),
+        #[cfg(feature = "typescript")]
+        generate_discriminated_enum_ts_definition_method(
+            &docs,
+            &item_name,
+            &inner.typescript_typename(),
+            None,
+            &method_names,
+            &codegen_options,
+        ),
+        #[cfg(feature = "zod")]
+        generate_discriminated_enum_zod_schema_method(
+            &item_name,
+            &inner.zod_type(&codegen_options.zod_import),
+            &method_names,
+            &codegen_options,
+        ),
+        #[cfg(any(feature = "typescript", feature = "zod"))]
+        generate_schema_dependencies_method(&dependencies),
+    ];
+
+    if let Some(notes) = &field_tree {
+        impl_items.push(generate_field_tree_const(notes));
     }
 
+    #[cfg(feature = "fixtures")]
+    if schema_args.derive_default_instance {
+        impl_items.push(generate_transparent_struct_default_json_method(&inner));
+    }
+
+    #[cfg(feature = "io_ts")]
+    impl_items.push(generate_transparent_struct_io_ts_codec_method(&item_name, &inner));
+
+    #[cfg(any(feature = "typescript", feature = "zod", feature = "jsonschema"))]
+    impl_items.push(generate_emit_method(&method_names));
+    #[cfg(any(feature = "typescript", feature = "zod", feature = "jsonschema"))]
+    impl_items.push(generate_method_aliases(&method_names));
+    #[cfg(feature = "zod")]
+    impl_items.push(generate_zod_import_method(&codegen_options.zod_import));
+
+    let (impl_generics, ty_generics, where_clause) = item_struct.generics.split_for_impl();
+
+    let output = quote! {
+        #item_struct
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            #(#impl_items) *
+        }
+    };
+
     TokenStream::from(output)
 }
 
 /// Processes an enum item and generates TypeScript and Zod schema definitions for it.
-fn process_enum(item_enum: syn::ItemEnum) -> TokenStream {
+fn process_enum(item_enum: syn::ItemEnum, schema_args: &ModelSchemaArgs) -> TokenStream {
     let name = item_enum.ident.clone();
 
     #[cfg(feature = "serde")]
     let serde_type_meta = parse_serde_type_attributes(&item_enum.attrs);
 
-    let item_name = safe_type_name(&name.to_string());
+    let item_name = schema_args.item_name(&name);
+
+    #[cfg(feature = "serde")]
+    let has_serde_tag = serde_type_meta.tag.is_some();
+    #[cfg(not(feature = "serde"))]
+    let has_serde_tag = false;
+
+    // Untagged enums are a union of their variants' values, unit variants included
+    #[cfg(feature = "serde")]
+    if serde_type_meta.untagged {
+        // `#[model_schema(rename_all = "...")]` takes precedence over serde's, though variant
+        // names aren't written for untagged enums
+        let rename_all = schema_args.rename_all.clone().or(serde_type_meta.rename_all);
+
+        return process_untagged_enum(
+            item_enum,
+            &name,
+            &rename_all,
+            &serde_type_meta.rename_all_fields,
+            schema_args,
+            &item_name,
+        );
+    }
 
-    if is_plain_enum(&item_enum) {
+    // An enum of unit variants (or without variants) is a tagged union when a tag is declared
+    // for it, as serde writes each unit variant as `{ "type": "Name" }`
+    let all_unit = is_plain_enum(&item_enum);
+    let is_plain = all_unit && !has_serde_tag && schema_args.tag.is_none();
+    if is_plain && schema_args.enum_as_object.is_none() {
         #[cfg(feature = "serde")]
-        let rename_all = &serde_type_meta.rename_all;
+        let rename_all = serde_type_meta.rename_all;
 
         #[cfg(not(feature = "serde"))]
-        let rename_all = &None;
+        let rename_all = None;
+
+        // `#[model_schema(rename_all = "...")]` takes precedence over serde's
+        let rename_all = schema_args.rename_all.clone().or(rename_all);
 
-        process_plain_enum(item_enum, &name, rename_all, &item_name)
+        process_plain_enum(
+            item_enum,
+            &name,
+            &rename_all,
+            &item_name,
+            &schema_args.method_names(),
+            #[cfg(any(feature = "typescript", feature = "zod"))]
+            &schema_args.codegen_options(),
+            schema_args.derive_default_instance,
+        )
     } else {
         #[cfg(feature = "serde")]
-        let (tag_name, rename_all) = (
+        let (tag_name, rename_all, rename_all_fields) = (
             serde_type_meta
                 .tag
                 .as_ref()
                 .map_or_else(|| "type".to_string(), Clone::clone),
             serde_type_meta.rename_all,
+            serde_type_meta.rename_all_fields,
         );
 
         #[cfg(not(feature = "serde"))]
-        let (tag_name, rename_all) = ("type".to_string(), None);
+        let (tag_name, rename_all, rename_all_fields) = ("type".to_string(), None, None);
+
+        // `#[model_schema(rename_all = "...")]` takes precedence over serde's, and like it
+        // only renames the tag values
+        let rename_all = schema_args.rename_all.clone().or(rename_all);
+
+        // An explicit `#[model_schema(tag = "...")]` takes precedence over the serde tag.
+        // Plain enums wrapped with `enum_as_object` become unit variants of a tagged union.
+        let tag_name = schema_args
+            .enum_as_object
+            .clone()
+            .or_else(|| schema_args.tag.clone())
+            .unwrap_or(tag_name);
 
         process_discriminated_enum(
             item_enum,
             &name,
             &tag_name,
             &rename_all,
+            &rename_all_fields,
+            schema_args,
             &item_name,
         )
     }
@@ -168,32 +885,51 @@ fn process_plain_enum(
     name: &syn::Ident,
     rename_all: &Option<String>,
     item_name: &str,
+    method_names: &MethodNames,
+    #[cfg(any(feature = "typescript", feature = "zod"))] codegen_options: &CodegenOptions,
+    derive_default_instance: bool,
 ) -> TokenStream {
     let mut enum_options = Vec::new();
+    // A `#[serde(other)]` variant accepts any other string, so the set of values is open
+    #[allow(unused_mut)]
+    let mut has_catch_all = false;
 
     for item in &mut item_enum.variants {
         #[cfg(feature = "serde")]
-        let field_rename = parse_serde_field_attributes(&item.attrs).rename;
+        let serde_meta = parse_serde_field_attributes(&item.attrs);
+        #[cfg(feature = "serde")]
+        if serde_meta.other {
+            has_catch_all = true;
+            continue;
+        }
+        #[cfg(feature = "serde")]
+        let field_rename = serde_meta.rename;
         #[cfg(not(feature = "serde"))]
         let field_rename = None;
 
-        let final_name = get_final_name(item.ident.to_string(), &field_rename, rename_all);
+        let final_name = get_final_variant_name(item.ident.to_string(), &field_rename, rename_all);
         enum_options.push(final_name);
     }
 
     #[cfg(feature = "typescript")]
-    let type_code = enum_options
-        .iter()
-        .map(|v| format!("\"{v}\""))
-        .collect::<Vec<_>>()
-        .join(" | ");
+    let declaration = TypeScriptGenerator::generate_plain_enum_type(item_name, &enum_options, has_catch_all);
 
     #[cfg(feature = "zod")]
-    let schema_code = enum_options
-        .iter()
-        .map(|v| format!("\"{v}\""))
-        .collect::<Vec<_>>()
-        .join(", ");
+    let schema_code = {
+        let zod = &codegen_options.zod_import;
+        let values = enum_options
+            .iter()
+            .map(|v| format!("\"{v}\""))
+            .collect::<Vec<_>>()
+            .join(", ");
+        if has_catch_all && enum_options.is_empty() {
+            format!("{zod}.string()")
+        } else if has_catch_all {
+            format!("{zod}.union([{zod}.enum([{values}]), {zod}.string()])")
+        } else {
+            format!("{zod}.enum([{values}])")
+        }
+    };
 
     // Enumerate the strings with indices
     let enumerated: Vec<proc_macro2::TokenStream> = enum_options
@@ -204,50 +940,71 @@ fn process_plain_enum(
         .collect();
 
     #[cfg(feature = "typescript")]
-    let docs = match get_enum_docs(&item_enum) {
-        Some(doc_lines) => doc_lines
-            .into_iter()
-            .flat_map(|v| v.lines().map(|l| l.to_owned()).collect::<Vec<_>>())
-            .chain(vec!["".to_string()])
-            .map(|l| format!(" * {l}"))
-            .collect::<Vec<_>>()
-            .join("\n"),
-        None => [name.to_string(), "".to_string()]
-            .into_iter()
-            .map(|l| format!(" * {l}"))
-            .collect::<Vec<_>>()
-            .join("\n"),
-    };
+    let docs = GenerationUtils::format_docs(get_enum_docs(&item_enum), &name.to_string());
 
     // Generate conditional methods
     #[cfg(feature = "jsonschema")]
-    let json_schema_method = generate_plain_enum_json_schema_method(&enumerated);
+    let json_schema_method = generate_plain_enum_json_schema_method(&enum_options, has_catch_all, method_names, item_name);
 
     #[cfg(feature = "typescript")]
     let ts_definition_method =
-        generate_plain_enum_ts_definition_method(&docs, item_name, &type_code);
+        generate_plain_enum_ts_definition_method(
+            &docs,
+            item_name,
+            &declaration,
+            &enum_options,
+            method_names,
+            codegen_options,
+        );
     #[cfg(feature = "zod")]
-    let zod_schema_method = generate_plain_enum_zod_schema_method(item_name, &schema_code);
+    let zod_schema_method =
+        generate_plain_enum_zod_schema_method(item_name, &schema_code, method_names, codegen_options);
 
     #[cfg(not(any(feature = "typescript", feature = "zod")))]
     let _ = item_name;
+    #[cfg(not(any(feature = "typescript", feature = "zod", feature = "jsonschema")))]
+    let _ = method_names;
+    #[cfg(not(any(feature = "typescript", feature = "zod", feature = "jsonschema", feature = "io_ts")))]
+    let _ = has_catch_all;
 
-    let impl_items: Vec<proc_macro2::TokenStream> = vec![
+    #[allow(unused_mut)]
+    let mut impl_items: Vec<proc_macro2::TokenStream> = vec![
         #[cfg(feature = "jsonschema")]
         json_schema_method,
         #[cfg(feature = "typescript")]
         ts_definition_method,
         #[cfg(feature = "zod")]
         zod_schema_method,
+        #[cfg(any(feature = "typescript", feature = "zod"))]
+        generate_schema_dependencies_method(&BTreeSet::new()),
     ];
 
+    #[cfg(feature = "fixtures")]
+    if derive_default_instance {
+        impl_items.push(generate_plain_enum_default_json_method(enum_options.first()));
+    }
+    #[cfg(not(feature = "fixtures"))]
+    let _ = derive_default_instance;
+
+    #[cfg(feature = "io_ts")]
+    impl_items.push(generate_plain_enum_io_ts_codec_method(item_name, &enum_options, has_catch_all));
+
+    #[cfg(any(feature = "typescript", feature = "zod", feature = "jsonschema"))]
+    impl_items.push(generate_emit_method(method_names));
+    #[cfg(any(feature = "typescript", feature = "zod", feature = "jsonschema"))]
+    impl_items.push(generate_method_aliases(method_names));
+    #[cfg(feature = "zod")]
+    impl_items.push(generate_zod_import_method(&codegen_options.zod_import));
+
     // Use the enumerated values in the quote! macro
     let enum_values = &enumerated;
 
+    let (impl_generics, ty_generics, where_clause) = item_enum.generics.split_for_impl();
+
     let output = quote! {
         #item_enum
 
-        impl #name {
+        impl #impl_generics #name #ty_generics #where_clause {
             #(#impl_items) *
 
             pub fn enum_members() -> Vec<String> {
@@ -258,160 +1015,583 @@ fn process_plain_enum(
         }
     };
 
-    if env::var("RUST_LOG") == Ok(String::from("trace")) {
-        let output_str = output.to_string();
-        println!("{output_str}");
-    }
-
     TokenStream::from(output)
 }
 
 /// Processes a discriminated enum (tagged union in TypeScript) and generates its definitions.
+///
+/// As in serde, `rename_all` only renames the tag values. Variant fields follow the
+/// variant's own `rename_all`, falling back to the enum's `rename_all_fields`.
+///
+/// With `untagged_fallback`, the TypeScript type and Zod schema also accept any object
+/// carrying an unknown tag, so newer backends don't break older frontends.
 fn process_discriminated_enum(
     mut item_enum: syn::ItemEnum,
     name: &syn::Ident,
     tag_name: &str,
     rename_all: &Option<String>,
+    rename_all_fields: &Option<String>,
+    schema_args: &ModelSchemaArgs,
     item_name: &str,
 ) -> TokenStream {
-    let mut discriminator_field_defs: HashMap<String, Vec<FieldDef>> = HashMap::new();
-    let mut discriminator_field_docs: HashMap<String, String> = HashMap::new();
+    let untagged_fallback = schema_args.untagged_fallback;
+    // In declaration order, so the generated union lists variants as the Rust source does
+    let mut discriminator_field_defs: Vec<(String, Vec<FieldDef>)> = Vec::new();
+    // The payload of newtype variants, e.g. `Home(AddressJson)`, which serde writes next to the tag
+    let mut newtype_variants: BTreeMap<String, FieldDef> = BTreeMap::new();
+    let mut discriminator_field_docs: BTreeMap<String, String> = BTreeMap::new();
+    // The `title` (variant name) and `description` (doc comment) of each `oneOf` branch
+    let mut discriminator_annotations: BTreeMap<String, (String, Option<String>)> = BTreeMap::new();
     let mut json_schema_variants: Vec<proc_macro2::TokenStream> = Vec::new();
+    let mut warnings = proc_macro2::TokenStream::new();
+    #[cfg(any(feature = "typescript", feature = "zod"))]
+    let mut dependencies = BTreeSet::new();
+    // The first variant in declaration order, for `default_json()`
+    #[cfg(feature = "fixtures")]
+    let mut first_variant: Option<(String, Vec<FieldDef>)> = None;
 
     // Process each variant in the enum
+    let mut field_tree = Vec::new();
     for item in &mut item_enum.variants {
         #[cfg(feature = "serde")]
-        let field_rename = parse_serde_field_attributes(&item.attrs).rename;
+        let (field_rename, variant_rename_all) = {
+            let variant_meta = parse_serde_field_attributes(&item.attrs);
+            (variant_meta.rename, variant_meta.rename_all)
+        };
         #[cfg(not(feature = "serde"))]
-        let field_rename = None;
+        let (field_rename, variant_rename_all) = (None, None);
 
-        let final_name = get_final_name(item.ident.to_string(), &field_rename, rename_all);
+        let final_name = get_final_variant_name(item.ident.to_string(), &field_rename, rename_all);
+        let fields_rename_all = variant_rename_all.or_else(|| rename_all_fields.clone());
 
         let mut field_defs: Vec<FieldDef> = Vec::new();
+        let mut field_spans = Vec::new();
         let mut json_schema_fields: Vec<proc_macro2::TokenStream> = Vec::new();
+        let is_newtype = matches!(&item.fields, syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1);
+        // serde can't write a tuple next to a tag: it rejects them in internally tagged enums
+        if matches!(&item.fields, syn::Fields::Unnamed(fields) if fields.unnamed.len() > 1) {
+            return syn::Error::new_spanned(
+                &*item,
+                format!(
+                    "Tuple variant `{name}::{}` is not supported in a tagged enum: use a struct variant, or a newtype variant wrapping a struct",
+                    item.ident
+                ),
+            )
+            .to_compile_error()
+            .into();
+        }
 
         for field in &mut item.fields {
-            let f_def = process_field(rename_all, field);
+            if is_skipped_field(field, schema_args.deserialize_shape) {
+                continue;
+            }
+            let mut f_def = process_field(&fields_rename_all, field);
+            f_def.map_extern_types(&schema_args.extern_map);
+            if is_newtype {
+                if !is_struct_payload(&f_def) {
+                    return syn::Error::new_spanned(
+                        &*item,
+                        format!(
+                            "Newtype variant `{name}::{}` must wrap a struct: serde writes its fields next to the `{tag_name}` tag",
+                            item.ident
+                        ),
+                    )
+                    .to_compile_error()
+                    .into();
+                }
+                #[cfg(any(feature = "typescript", feature = "zod"))]
+                f_def.collect_dependencies(&mut dependencies);
+                newtype_variants.insert(final_name.clone(), f_def);
+                continue;
+            }
             json_schema_fields.push(build_field_schema(&f_def));
+            field_spans.push(field_span(field));
             field_defs.push(f_def);
         }
 
-        discriminator_field_defs.insert(final_name.clone(), field_defs);
-        let discriminator_docs = match get_variant_docs(item) {
-            Some(doc_lines) => doc_lines
-                .into_iter()
-                .flat_map(|v| v.lines().map(|l| l.to_owned()).collect::<Vec<_>>())
-                .chain(vec!["".to_string()])
-                .map(|l| format!(" * {l}"))
-                .collect::<Vec<_>>()
-                .join("\n"),
-            None => [final_name.to_string(), "".to_string()]
-                .into_iter()
-                .map(|l| format!(" * {l}"))
-                .collect::<Vec<_>>()
-                .join("\n"),
-        };
-        discriminator_field_docs.insert(final_name, discriminator_docs);
+        #[cfg(any(feature = "typescript", feature = "zod"))]
+        field_defs.iter().for_each(|fld| fld.collect_dependencies(&mut dependencies));
+
+        if let Err(err) = check_duplicate_names(&format!("{name}::{}", item.ident), &field_defs, &field_spans) {
+            return err.to_compile_error().into();
+        }
+        if let Err(err) = check_tag_collision(&format!("{name}::{}", item.ident), tag_name, &field_defs, &field_spans) {
+            return err.to_compile_error().into();
+        }
+
+        if schema_args.camel_schema_keys
+            && let Err(err) = check_camel_schema_keys(&item.ident, &field_defs, &field_spans)
+        {
+            return err.to_compile_error().into();
+        }
+
+        if schema_args.strict {
+            warnings.extend(warn_nested_casing(
+                &format!("{name}::{}", item.ident),
+                &fields_rename_all,
+                &field_defs,
+                &[],
+                &item_enum.attrs,
+            ));
+        }
+
+        if schema_args.debug {
+            field_tree.extend(crate::features::debug::field_tree_notes(
+                &format!("{name}::{}", item.ident),
+                field_defs.iter().chain(newtype_variants.get(&final_name)),
+            ));
+        }
+
+        #[cfg(feature = "fixtures")]
+        if first_variant.is_none() {
+            first_variant = Some((final_name.clone(), field_defs.clone()));
+        }
+
+        discriminator_field_defs.push((final_name.clone(), field_defs));
+        let discriminator_docs = GenerationUtils::format_docs(get_variant_docs(item), &final_name);
+        discriminator_field_docs.insert(final_name.clone(), discriminator_docs);
+        discriminator_annotations.insert(
+            final_name,
+            (item.ident.to_string(), get_variant_docs(item).map(|lines| lines.join("\n"))),
+        );
     }
 
+    #[cfg(feature = "io_ts")]
+    let io_ts_codec_method = generate_discriminated_enum_io_ts_codec_method(
+        item_name,
+        tag_name,
+        discriminator_field_defs.iter().map(|(discriminator_value, field_defs)| (discriminator_value, field_defs)),
+        &newtype_variants,
+        untagged_fallback,
+    );
+
+    #[cfg(any(feature = "typescript", feature = "zod"))]
+    let codegen_options = schema_args.codegen_options();
+    #[cfg(feature = "zod")]
+    let zod = codegen_options.zod_import.as_str();
+
     let mut type_code_items = Vec::new();
     let mut schema_code_items = Vec::new();
 
     // Generate TypeScript and Zod schema for each variant
     for (discriminator_value, field_defs) in discriminator_field_defs {
-        let (variant_type_code, variant_schema_code, optional_fields, json_schema_variant) =
-            generate_variant_code(
-                tag_name,
-                &discriminator_value,
-                field_defs,
-                &discriminator_field_docs[&discriminator_value],
-            );
+        let discriminator_docs = &discriminator_field_docs[&discriminator_value];
+        let (variant_type_code, variant_schema_code, json_schema_variant) =
+            match newtype_variants.get(&discriminator_value) {
+                Some(payload) => generate_newtype_variant_code(
+                    tag_name,
+                    &discriminator_value,
+                    payload,
+                    discriminator_docs,
+                    #[cfg(feature = "zod")]
+                    zod,
+                ),
+                None => {
+                    let (variant_type_code, variant_schema_code, _, json_schema_variant) =
+                        generate_variant_code(
+                            Some((tag_name, &discriminator_value)),
+                            field_defs,
+                            discriminator_docs,
+                            schema_args.optional_style(),
+                            #[cfg(feature = "zod")]
+                            zod,
+                        );
+                    #[cfg(feature = "zod")]
+                    let variant_schema_code = format!("{zod}.strictObject({variant_schema_code})");
+                    (variant_type_code, variant_schema_code, json_schema_variant)
+                }
+            };
 
-        type_code_items.push(variant_type_code);
-        schema_code_items.push((variant_schema_code, optional_fields));
+        let json_schema_variant = if schema_args.discriminator_description {
+            let (title, description) = &discriminator_annotations[&discriminator_value];
+            annotate_json_schema_variant(json_schema_variant, title, description.as_deref())
+        } else {
+            json_schema_variant
+        };
+
+        type_code_items.push((discriminator_value, variant_type_code));
+        schema_code_items.push(variant_schema_code);
         json_schema_variants.push(json_schema_variant);
     }
 
+    // Without variants no value is valid. A single variant keeps its one-element `oneOf`, like
+    // the one-option `z.discriminatedUnion`, so the shape doesn't change as variants are added.
     #[cfg(feature = "jsonschema")]
-    let main_schema_code = quote! {
-        let mut schema_obj = serde_json::Map::new();
-        schema_obj.insert("type".to_string(), serde_json::Value::String("object".to_string()));
-        schema_obj.insert("oneOf".to_string(), {
-            let result: Vec<serde_json::Value> = vec![
-                #(#json_schema_variants), *
-            ];
-
-            serde_json::Value::Array(result)
-        });
+    let main_schema_code = match json_schema_variants.as_slice() {
+        [] => quote! { serde_json::Value::Bool(false) },
+        _ => quote! {
+            let mut schema_obj = serde_json::Map::new();
+            schema_obj.insert("type".to_string(), serde_json::Value::String("object".to_string()));
+            schema_obj.insert("oneOf".to_string(), {
+                let result: Vec<serde_json::Value> = vec![
+                    #(#json_schema_variants), *
+                ];
+
+                serde_json::Value::Array(result)
+            });
 
-        serde_json::Value::Object(schema_obj)
+            serde_json::Value::Object(schema_obj)
+        },
     };
 
     #[cfg(feature = "typescript")]
-    let type_code = type_code_items.join(" | ");
+    let type_code = {
+        let variants_code = type_code_items
+            .iter()
+            .map(|(_, variant_type_code)| variant_type_code.as_str())
+            .collect::<Vec<_>>()
+            .join(" | ");
+        let variants_code = if variants_code.is_empty() { "never".to_string() } else { variants_code };
+
+        if untagged_fallback {
+            format!("{variants_code} | {{ {tag_name}: string; [k: string]: unknown }}")
+        } else {
+            variants_code
+        }
+    };
 
     // Generate Zod schema conditionally
     #[cfg(feature = "zod")]
-    let schema_code = format!(
-        "z.discriminatedUnion(\"{tag_name}\", [{}])",
-        schema_code_items
-            .iter()
-            .map(|(v, _opts)| format!("z.strictObject({}){}", v, ""))
-            .collect::<Vec<_>>()
-            .join(", ")
-    );
+    let schema_code = {
+        // `z.discriminatedUnion` only accepts object options, which newtype variants aren't
+        let options = schema_code_items.join(", ");
+        let discriminated_union = match schema_code_items.as_slice() {
+            [] => format!("{zod}.never()"),
+            _ if newtype_variants.is_empty() => format!("{zod}.discriminatedUnion(\"{tag_name}\", [{options}])"),
+            _ => format!("{zod}.union([{options}])"),
+        };
 
-    #[cfg(feature = "typescript")]
-    let docs = match get_enum_docs(&item_enum) {
-        Some(doc_lines) => doc_lines
-            .into_iter()
-            .flat_map(|v| v.lines().map(|l| l.to_owned()).collect::<Vec<_>>())
-            .chain(vec!["".to_string()])
-            .map(|l| format!(" * {l}"))
-            .collect::<Vec<_>>()
-            .join("\n"),
-        None => [name.to_string(), "".to_string()]
-            .into_iter()
-            .map(|l| format!(" * {l}"))
-            .collect::<Vec<_>>()
-            .join("\n"),
+        if untagged_fallback {
+            format!("{zod}.union([{discriminated_union}, {zod}.looseObject({{ {tag_name}: {zod}.string() }})])")
+        } else {
+            discriminated_union
+        }
     };
 
+    #[cfg(not(any(feature = "typescript", feature = "zod")))]
+    let _ = untagged_fallback;
+
+    #[cfg(feature = "typescript")]
+    let docs = GenerationUtils::format_docs(get_enum_docs(&item_enum), &name.to_string());
+
+    #[cfg(any(feature = "typescript", feature = "zod", feature = "jsonschema"))]
+    let method_names = schema_args.method_names();
+
     #[cfg(feature = "jsonschema")]
-    let json_schema_method = generate_discriminated_enum_json_schema_method(&main_schema_code);
+    let json_schema_method =
+        generate_discriminated_enum_json_schema_method(&main_schema_code, &method_names, item_name);
 
     #[cfg(feature = "typescript")]
     let ts_definition_method =
-        generate_discriminated_enum_ts_definition_method(&docs, item_name, &type_code);
+        generate_discriminated_enum_ts_definition_method(
+            &docs,
+            item_name,
+            &type_code,
+            Some(&type_code_items),
+            &method_names,
+            &codegen_options,
+        );
 
     #[cfg(feature = "zod")]
-    let zod_schema_method = generate_discriminated_enum_zod_schema_method(item_name, &schema_code);
+    let zod_schema_method =
+        generate_discriminated_enum_zod_schema_method(item_name, &schema_code, &method_names, &codegen_options);
 
     #[cfg(not(any(feature = "typescript", feature = "zod")))]
     let _ = item_name;
 
-    let impl_items: Vec<proc_macro2::TokenStream> = vec![
+    #[cfg(feature = "fixtures")]
+    let default_json_method = schema_args.derive_default_instance.then(|| {
+        generate_discriminated_enum_default_json_method(tag_name, first_variant.as_ref())
+    });
+
+    #[allow(unused_mut)]
+    let mut impl_items: Vec<proc_macro2::TokenStream> = vec![
         #[cfg(feature = "jsonschema")]
         json_schema_method,
         #[cfg(feature = "typescript")]
         ts_definition_method,
         #[cfg(feature = "zod")]
         zod_schema_method,
+        #[cfg(any(feature = "typescript", feature = "zod"))]
+        generate_schema_dependencies_method(&dependencies),
     ];
 
+    if schema_args.debug {
+        impl_items.push(generate_field_tree_const(&field_tree));
+    }
+
+    #[cfg(feature = "fixtures")]
+    impl_items.extend(default_json_method);
+
+    #[cfg(feature = "io_ts")]
+    impl_items.push(io_ts_codec_method);
+
+    #[cfg(any(feature = "typescript", feature = "zod", feature = "jsonschema"))]
+    impl_items.push(generate_emit_method(&method_names));
+    #[cfg(any(feature = "typescript", feature = "zod", feature = "jsonschema"))]
+    impl_items.push(generate_method_aliases(&method_names));
+    #[cfg(feature = "zod")]
+    impl_items.push(generate_zod_import_method(&codegen_options.zod_import));
+
+    let (impl_generics, ty_generics, where_clause) = item_enum.generics.split_for_impl();
+
     let output = quote! {
         #item_enum
 
-        impl #name {
+        impl #impl_generics #name #ty_generics #where_clause {
             #(#impl_items) *
         }
+
+        #warnings
+    };
+
+    TokenStream::from(output)
+}
+
+/// Processes a `#[serde(untagged)]` enum, whose values are those of its variants without a tag:
+/// `null` for unit variants, the wrapped value for newtype variants and an object of the
+/// fields for struct variants. It's a plain union in TypeScript and Zod and an `anyOf` in JSON
+/// schema.
+#[cfg(feature = "serde")]
+fn process_untagged_enum(
+    mut item_enum: syn::ItemEnum,
+    name: &syn::Ident,
+    rename_all: &Option<String>,
+    rename_all_fields: &Option<String>,
+    schema_args: &ModelSchemaArgs,
+    item_name: &str,
+) -> TokenStream {
+    let mut variants: Vec<VariantShape> = Vec::new();
+    let mut type_code_items: Vec<String> = Vec::new();
+    let mut schema_code_items: Vec<String> = Vec::new();
+    let mut json_schema_variants: Vec<proc_macro2::TokenStream> = Vec::new();
+    let mut warnings = proc_macro2::TokenStream::new();
+    #[cfg(any(feature = "typescript", feature = "zod"))]
+    let mut dependencies = BTreeSet::new();
+    #[cfg(any(feature = "typescript", feature = "zod"))]
+    let codegen_options = schema_args.codegen_options();
+    #[cfg(feature = "zod")]
+    let zod = codegen_options.zod_import.as_str();
+
+    let mut field_tree = Vec::new();
+    for item in &mut item_enum.variants {
+        let variant_meta = parse_serde_field_attributes(&item.attrs);
+        let final_name = get_final_variant_name(item.ident.to_string(), &variant_meta.rename, rename_all);
+        let fields_rename_all = variant_meta.rename_all.or_else(|| rename_all_fields.clone());
+
+        let variant = match &item.fields {
+            syn::Fields::Unit => VariantShape::Unit,
+            syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                let field = item.fields.iter_mut().next().expect("newtype variant has one field");
+                let mut f_def = process_field(&fields_rename_all, field);
+                f_def.map_extern_types(&schema_args.extern_map);
+                VariantShape::Newtype(Box::new(f_def))
+            }
+            syn::Fields::Unnamed(_) => {
+                return syn::Error::new_spanned(
+                    &*item,
+                    format!(
+                        "Tuple variant `{name}::{}` of an untagged enum is not supported: only unit, newtype and struct variants are",
+                        item.ident
+                    ),
+                )
+                .to_compile_error()
+                .into();
+            }
+            syn::Fields::Named(_) => {
+                let mut field_defs = Vec::new();
+                let mut field_spans = Vec::new();
+                for field in &mut item.fields {
+                    if is_skipped_field(field, schema_args.deserialize_shape) {
+                        continue;
+                    }
+                    let mut f_def = process_field(&fields_rename_all, field);
+                    f_def.map_extern_types(&schema_args.extern_map);
+                    field_spans.push(field_span(field));
+                    field_defs.push(f_def);
+                }
+                if let Err(err) = check_duplicate_names(&format!("{name}::{}", item.ident), &field_defs, &field_spans) {
+                    return err.to_compile_error().into();
+                }
+                if schema_args.camel_schema_keys
+                    && let Err(err) = check_camel_schema_keys(&item.ident, &field_defs, &field_spans)
+                {
+                    return err.to_compile_error().into();
+                }
+                if schema_args.strict {
+                    warnings.extend(warn_nested_casing(
+                        &format!("{name}::{}", item.ident),
+                        &fields_rename_all,
+                        &field_defs,
+                        &[],
+                        &item_enum.attrs,
+                    ));
+                }
+                VariantShape::Struct(field_defs)
+            }
+        };
+
+        let variant_fields: &[FieldDef] = match &variant {
+            VariantShape::Unit => &[],
+            VariantShape::Newtype(payload) => std::slice::from_ref(payload),
+            VariantShape::Struct(field_defs) => field_defs,
+        };
+
+        #[cfg(any(feature = "typescript", feature = "zod"))]
+        variant_fields.iter().for_each(|fld| fld.collect_dependencies(&mut dependencies));
+
+        if schema_args.debug {
+            field_tree.extend(crate::features::debug::field_tree_notes(&format!("{name}::{}", item.ident), variant_fields));
+        }
+
+        let variant_docs = GenerationUtils::format_docs(get_variant_docs(item), &final_name);
+        let (variant_type_code, variant_schema_code, json_schema_variant) = match &variant {
+            VariantShape::Unit => {
+                #[cfg(feature = "zod")]
+                let variant_schema_code = format!("{zod}.null()");
+                #[cfg(not(feature = "zod"))]
+                let variant_schema_code = String::new();
+                ("null".to_string(), variant_schema_code, quote! { serde_json::json!({ "type": "null" }) })
+            }
+            VariantShape::Newtype(payload) => {
+                #[cfg(feature = "zod")]
+                let variant_schema_code = payload.zod_type(zod);
+                #[cfg(not(feature = "zod"))]
+                let variant_schema_code = String::new();
+                (payload.typescript_typename(), variant_schema_code, value_json_schema(payload))
+            }
+            VariantShape::Struct(field_defs) => {
+                let (variant_type_code, variant_schema_code, _, json_schema_variant) = generate_variant_code(
+                    None,
+                    field_defs.clone(),
+                    &variant_docs,
+                    schema_args.optional_style(),
+                    #[cfg(feature = "zod")]
+                    zod,
+                );
+                #[cfg(feature = "zod")]
+                let variant_schema_code = format!("{zod}.strictObject({variant_schema_code})");
+                // Unlike tagged enums there is no enclosing `"type": "object"`, and `properties`
+                // and `required` alone accept any non-object value
+                let json_schema_variant = quote! {
+                    {
+                        let mut variant_schema = #json_schema_variant;
+                        variant_schema
+                            .as_object_mut()
+                            .expect("struct variant schema is an object")
+                            .insert("type".to_string(), serde_json::Value::String("object".to_string()));
+                        variant_schema
+                    }
+                };
+                (variant_type_code, variant_schema_code, json_schema_variant)
+            }
+        };
+
+        let json_schema_variant = if schema_args.discriminator_description {
+            let description = get_variant_docs(item).map(|lines| lines.join("\n"));
+            annotate_json_schema_variant(json_schema_variant, &item.ident.to_string(), description.as_deref())
+        } else {
+            json_schema_variant
+        };
+
+        type_code_items.push(variant_type_code);
+        schema_code_items.push(variant_schema_code);
+        json_schema_variants.push(json_schema_variant);
+        variants.push(variant);
+    }
+
+    // Without variants no value is valid
+    #[cfg(feature = "jsonschema")]
+    let main_schema_code = match json_schema_variants.as_slice() {
+        [] => quote! { serde_json::Value::Bool(false) },
+        _ => quote! {
+            let mut schema_obj = serde_json::Map::new();
+            schema_obj.insert("anyOf".to_string(), {
+                let result: Vec<serde_json::Value> = vec![
+                    #(#json_schema_variants), *
+                ];
+
+                serde_json::Value::Array(result)
+            });
+
+            serde_json::Value::Object(schema_obj)
+        },
+    };
+
+    #[cfg(not(feature = "jsonschema"))]
+    let _ = json_schema_variants;
+
+    #[cfg(feature = "typescript")]
+    let type_code = match type_code_items.as_slice() {
+        [] => "never".to_string(),
+        _ => type_code_items.join(" | "),
     };
 
-    if env::var("RUST_LOG") == Ok(String::from("trace")) {
-        let output_str = output.to_string();
-        println!("{output_str}");
+    #[cfg(feature = "zod")]
+    let schema_code = match schema_code_items.as_slice() {
+        [] => format!("{zod}.never()"),
+        _ => format!("{zod}.union([{}])", schema_code_items.join(", ")),
+    };
+
+    #[cfg(not(any(feature = "typescript", feature = "zod")))]
+    let _ = (item_name, type_code_items, schema_code_items);
+
+    #[cfg(feature = "typescript")]
+    let docs = GenerationUtils::format_docs(get_enum_docs(&item_enum), &name.to_string());
+
+    #[cfg(any(feature = "typescript", feature = "zod", feature = "jsonschema"))]
+    let method_names = schema_args.method_names();
+
+    #[allow(unused_mut)]
+    let mut impl_items: Vec<proc_macro2::TokenStream> = vec![
+        #[cfg(feature = "jsonschema")]
+        generate_discriminated_enum_json_schema_method(&main_schema_code, &method_names, item_name),
+        #[cfg(feature = "typescript")]
+        generate_discriminated_enum_ts_definition_method(
+            &docs,
+            item_name,
+            &type_code,
+            None,
+            &method_names,
+            &codegen_options,
+        ),
+        #[cfg(feature = "zod")]
+        generate_discriminated_enum_zod_schema_method(item_name, &schema_code, &method_names, &codegen_options),
+        #[cfg(any(feature = "typescript", feature = "zod"))]
+        generate_schema_dependencies_method(&dependencies),
+    ];
+
+    if schema_args.debug {
+        impl_items.push(generate_field_tree_const(&field_tree));
+    }
+
+    #[cfg(feature = "fixtures")]
+    if schema_args.derive_default_instance {
+        impl_items.push(generate_untagged_enum_default_json_method(variants.first()));
     }
 
+    #[cfg(feature = "io_ts")]
+    impl_items.push(generate_untagged_enum_io_ts_codec_method(item_name, &variants));
+
+    #[cfg(any(feature = "typescript", feature = "zod", feature = "jsonschema"))]
+    impl_items.push(generate_emit_method(&method_names));
+    #[cfg(any(feature = "typescript", feature = "zod", feature = "jsonschema"))]
+    impl_items.push(generate_method_aliases(&method_names));
+    #[cfg(feature = "zod")]
+    impl_items.push(generate_zod_import_method(&codegen_options.zod_import));
+
+    let (impl_generics, ty_generics, where_clause) = item_enum.generics.split_for_impl();
+
+    let output = quote! {
+        #item_enum
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            #(#impl_items) *
+        }
+
+        #warnings
+    };
+
     TokenStream::from(output)
 }
 
@@ -421,11 +1601,13 @@ fn generate_type_schema(
     type_json_schema: proc_macro2::TokenStream,
 ) -> proc_macro2::TokenStream {
     if fld.is_array {
+        let unique_items = unique_items_keyword(fld);
         quote! {
             properties.insert(#field_name_str.to_string(), {
                 serde_json::json!({
                     "type": "array",
                     "items": #type_json_schema
+                    #unique_items
                 })
             });
         }
@@ -436,20 +1618,31 @@ fn generate_type_schema(
     }
 }
 
-/// Generates TypeScript and Zod schema code for a discriminated enum variant.
+/// Generates TypeScript and Zod schema code for an enum struct variant, with its tag name and
+/// value, or without a tag for untagged enums, whose variant docs precede the object instead.
 fn generate_variant_code(
-    tag_name: &str,
-    discriminator_value: &str,
+    tag: Option<(&str, &str)>,
     field_defs: Vec<FieldDef>,
     discriminator_docs: &str,
+    optional_style: OptionalStyle,
+    #[cfg(feature = "zod")] zod: &str,
 ) -> (String, String, Vec<String>, proc_macro2::TokenStream) {
     // Generate TypeScript type code
-    let mut variant_type_code =
-        format!("{{  /**\n{discriminator_docs}\n**/\n  {tag_name}: \"{discriminator_value}\";\n");
+    let mut variant_type_code = match tag {
+        Some((tag_name, discriminator_value)) => {
+            format!("{{  /**\n{discriminator_docs}\n**/\n  {tag_name}: \"{discriminator_value}\";\n")
+        }
+        None => format!("/**\n{discriminator_docs}\n**/\n{{\n"),
+    };
 
     // Generate Zod schema code
-    let mut variant_schema_code =
-        format!("{{\n  {tag_name}: z.literal(\"{discriminator_value}\"),\n");
+    #[cfg(feature = "zod")]
+    let mut variant_schema_code = match tag {
+        Some((tag_name, discriminator_value)) => format!("{{\n  {tag_name}: {zod}.literal(\"{discriminator_value}\"),\n"),
+        None => "{\n".to_string(),
+    };
+    #[cfg(not(feature = "zod"))]
+    let mut variant_schema_code = String::new();
 
     let mut optional_fields = Vec::new();
     let mut json_schema_variant_fields = Vec::new();
@@ -457,21 +1650,15 @@ fn generate_variant_code(
     // Process each field in the variant
     for fld in &field_defs {
         // Add TypeScript type definition
-        if let Err(err) = writeln!(
-            variant_type_code,
-            "  /**\n{}\n**/\n  {}: {};",
-            fld.docs,
-            fld.name,
-            fld.typescript_typename()
-        ) {
+        if let Err(err) = writeln!(variant_type_code, "{}", GenerationUtils::format_typescript_field(fld, optional_style)) {
             panic!("Failed to write TypeScript type: {err}");
         }
 
         // Add Zod schema definition - conditionally
         #[cfg(feature = "zod")]
         {
-            let zod_field_type = fld.zod_type();
-            if let Err(err) = writeln!(variant_schema_code, "  {}: {},", fld.name, zod_field_type) {
+            let zod_field_type = zod_property_schema(fld, optional_style, zod);
+            if let Err(err) = writeln!(variant_schema_code, "  {}: {},", fld.property_key(), zod_field_type) {
                 panic!("Failed to write Zod schema: {err}");
             }
         }
@@ -482,9 +1669,7 @@ fn generate_variant_code(
             let _ = &variant_schema_code; // Suppress unused variable warning
         }
 
-        if fld.name != tag_name {
-            json_schema_variant_fields.push(build_field_schema(fld));
-        }
+        json_schema_variant_fields.push(build_property_schema(fld, optional_style));
 
         if fld.is_optional {
             optional_fields.push(fld.name.to_string());
@@ -496,8 +1681,18 @@ fn generate_variant_code(
     variant_schema_code.push('}');
 
     // Create JSON schema for this variant
-    let discriminator_value_str = discriminator_value.to_string();
-    let tag_name_str = tag_name.to_string();
+    let tag_property = tag.map(|(tag_name, discriminator_value)| {
+        quote! {
+            properties.insert(
+                #tag_name.to_string(),
+                serde_json::json!({
+                    "type": "string",
+                    "const": #discriminator_value,
+                }),
+            );
+            required.push(serde_json::Value::String(#tag_name.to_string()));
+        }
+    });
 
     let json_schema_variant = quote! {
         {
@@ -509,14 +1704,7 @@ fn generate_variant_code(
             let mut properties = serde_json::Map::new();
             let mut required = Vec::new();
 
-            properties.insert(
-                #tag_name_str.to_string(),
-                serde_json::json!({
-                    "type": "string",
-                    "const": #discriminator_value_str,
-                }),
-            );
-            required.push(serde_json::Value::String(#tag_name_str.to_string()));
+            #tag_property
 
             #(#json_schema_variant_fields)*
 
@@ -539,691 +1727,282 @@ fn generate_variant_code(
     )
 }
 
-/// Builds JSON schema for a field.
-fn build_field_schema(fld: &FieldDef) -> proc_macro2::TokenStream {
-    let field_name = &fld.name;
-    let field_name_str = field_name.to_string();
-    let field_type = &fld.field_type;
-
-    let schema_code = match field_type {
-        FieldDefType::String => {
-            if fld.is_array {
-                // Check if minLength is specified for array items
-                if let Some(ref meta) = fld.model_schema_prop_meta {
-                    if let Some(min_len) = meta.min_length {
-                        quote! {
-                            properties.insert(#field_name_str.to_string(), {
-                                serde_json::json!({
-                                    "type": "array",
-                                    "items": serde_json::json!({ "type": "string", "minLength": #min_len })
-                                })
-                            });
-                        }
-                    } else {
-                        quote! {
-                            properties.insert(#field_name_str.to_string(), {
-                                serde_json::json!({
-                                    "type": "array",
-                                    "items": serde_json::json!({ "type": "string" })
-                                })
-                            });
-                        }
-                    }
-                } else {
-                    quote! {
-                        properties.insert(#field_name_str.to_string(), {
-                            serde_json::json!({
-                                "type": "array",
-                                "items": serde_json::json!({ "type": "string" })
-                            })
-                        });
-                    }
-                }
-            } else {
-                // Check if minLength is specified
-                if let Some(ref meta) = fld.model_schema_prop_meta {
-                    if let Some(min_len) = meta.min_length {
-                        quote! {
-                            properties.insert(#field_name_str.to_string(), {
-                                serde_json::json!({
-                                    "type": "string",
-                                    "minLength": #min_len
-                                })
-                            });
-                        }
-                    } else {
-                        quote! {
-                            properties.insert(#field_name_str.to_string(), {
-                                serde_json::json!({
-                                    "type": "string",
-                                })
-                            });
-                        }
-                    }
-                } else {
-                    quote! {
-                        properties.insert(#field_name_str.to_string(), {
-                            serde_json::json!({
-                                "type": "string",
-                            })
-                        });
-                    }
-                }
+/// Adds a `title` (the variant name) and, when the variant is documented, a `description` to
+/// the JSON schema of a union branch, for `discriminator_description`.
+fn annotate_json_schema_variant(
+    json_schema_variant: proc_macro2::TokenStream,
+    title: &str,
+    description: Option<&str>,
+) -> proc_macro2::TokenStream {
+    let description = description.map(|description| {
+        quote! { schema_obj.insert("description".to_string(), serde_json::json!(#description)); }
+    });
+    quote! {
+        {
+            let mut variant = #json_schema_variant;
+            if let Some(schema_obj) = variant.as_object_mut() {
+                schema_obj.insert("title".to_string(), serde_json::json!(#title));
+                #description
             }
+            variant
         }
-        FieldDefType::StringLiteral(literal) => {
-            if fld.is_array {
-                quote! {
-                    properties.insert(#field_name_str.to_string(), {
-                        serde_json::json!({
-                            "type": "array",
-                            "items": serde_json::json!({ "type": "string", "const": #literal })
-                        })
-                    });
-                }
-            } else {
-                quote! {
-                    properties.insert(#field_name_str.to_string(), {
+    }
+}
+
+/// Returns true when a newtype variant's payload is a single struct, whose fields serde can
+/// write next to the tag, rather than a primitive, collection or generic type.
+fn is_struct_payload(payload: &FieldDef) -> bool {
+    matches!(&payload.field_type, FieldDefType::SiblingType(_, lst, _) if lst.is_empty())
+        && !payload.is_array
+        && !payload.is_optional
+}
+
+/// Generates TypeScript, Zod and JSON schema code for a newtype variant, e.g. `Home(AddressJson)`.
+///
+/// Serde writes the fields of the wrapped struct next to the tag, so the variant is the
+/// struct's type with the tag added. As the struct's own schemas reject unknown keys, Zod strips
+/// the tag before validating the payload, and the JSON schema is the struct's with the tag
+/// property added.
+fn generate_newtype_variant_code(
+    tag_name: &str,
+    discriminator_value: &str,
+    payload: &FieldDef,
+    discriminator_docs: &str,
+    #[cfg(feature = "zod")] zod: &str,
+) -> (String, String, proc_macro2::TokenStream) {
+    let FieldDefType::SiblingType(name, _, module) = &payload.field_type else {
+        unreachable!("newtype variant payloads are checked with `is_struct_payload`");
+    };
+    let payload_json = sibling_json_type(name, module);
+    // The tag is added to the payload's own schema, so it's never a `$ref`
+    let payload_schema = inlined_json_schema(quote! { #payload_json });
+
+    let variant_type_code = format!(
+        "({{  /**\n{discriminator_docs}\n**/\n  {tag_name}: \"{discriminator_value}\";\n}} & {})",
+        payload.typescript_typename()
+    );
+
+    #[cfg(feature = "zod")]
+    let variant_schema_code = {
+        let any = if cfg!(feature = "typescript") { ": any" } else { "" };
+        format!(
+            "{zod}.looseObject({{ {tag_name}: {zod}.literal(\"{discriminator_value}\") }}).and({zod}.preprocess(({{ {tag_name}: _, ...payload }}{any}) => payload, {}))",
+            payload.zod_type(zod)
+        )
+    };
+    #[cfg(not(feature = "zod"))]
+    let variant_schema_code = String::new();
+
+    let json_schema_variant = quote! {
+        {
+            let mut variant = #payload_schema;
+            if let Some(schema_obj) = variant.as_object_mut() {
+                if let Some(properties) = schema_obj
+                    .entry("properties")
+                    .or_insert_with(|| serde_json::json!({}))
+                    .as_object_mut()
+                {
+                    properties.insert(
+                        #tag_name.to_string(),
                         serde_json::json!({
                             "type": "string",
-                            "const": #literal
-                        })
-                    });
-                }
-            }
-        }
-        FieldDefType::U32
-        | FieldDefType::U16
-        | FieldDefType::U8
-        | FieldDefType::U64
-        | FieldDefType::I8
-        | FieldDefType::I16
-        | FieldDefType::I32
-        | FieldDefType::I64
-        | FieldDefType::Usize
-        | FieldDefType::Isize => {
-            if fld.is_array {
-                quote! {
-                    properties.insert(#field_name_str.to_string(), {
-                        serde_json::json!({
-                            "type": "array",
-                            "items": serde_json::json!({ "type": "integer" })
-                        })
-                    });
-                }
-            } else {
-                quote! {
-                    properties.insert(#field_name_str.to_string(), {
-                        serde_json::json!({
-                            "type": "integer",
-                        })
-                    });
+                            "const": #discriminator_value,
+                        }),
+                    );
                 }
-            }
-        }
-        FieldDefType::F32 | FieldDefType::F64 => {
-            if fld.is_array {
-                quote! {
-                    properties.insert(#field_name_str.to_string(), {
-                        serde_json::json!({
-                            "type": "array",
-                            "items": serde_json::json!({ "type": "number" })
-                        })
-                    });
-                }
-            } else {
-                quote! {
-                    properties.insert(#field_name_str.to_string(), {
-                        serde_json::json!({
-                            "type": "number",
-                        })
-                    });
+                if let Some(required) = schema_obj
+                    .entry("required")
+                    .or_insert_with(|| serde_json::json!([]))
+                    .as_array_mut()
+                {
+                    required.insert(0, serde_json::Value::String(#tag_name.to_string()));
                 }
             }
+            variant
         }
-        FieldDefType::Boolean => {
-            if fld.is_array {
-                quote! {
-                    properties.insert(#field_name_str.to_string(), {
-                        serde_json::json!({
-                            "type": "array",
-                            "items": serde_json::json!({ "type": "boolean" })
-                        })
-                    });
-                }
-            } else {
-                quote! {
-                    properties.insert(#field_name_str.to_string(), {
-                        serde_json::json!({
-                            "type": "boolean",
-                        })
-                    });
-                }
-            }
+    };
+
+    (variant_type_code, variant_schema_code, json_schema_variant)
+}
+
+/// Builds JSON schema for a field.
+fn build_field_schema(fld: &FieldDef) -> proc_macro2::TokenStream {
+    let field_name_str = fld.name.to_string();
+    let schema_code = build_field_property(fld);
+    let annotations_code = build_field_annotations(fld);
+
+    let required_code = if !fld.is_optional {
+        quote! {
+            required.push(serde_json::Value::String(#field_name_str.to_string()));
         }
-        #[cfg(feature = "object_id")]
-        FieldDefType::ObjectId => {
-            if fld.is_array {
-                quote! {
-                    properties.insert(#field_name_str.to_string(), {
-                        serde_json::json!({
-                            "type": "array",
-                            "items": serde_json::json!({
-                                "type": "object",
-                                "properties": {
-                                    "$oid": { "type": "string" }
-                                },
-                                "required": ["$oid"],
-                                "additionalProperties": false
-                            })
-                        })
-                    });
-                }
-            } else {
-                quote! {
-                    properties.insert(#field_name_str.to_string(), {
-                        serde_json::json!({
-                            "type": "object",
-                            "properties": {
-                                "$oid": { "type": "string" }
-                            },
-                            "required": ["$oid"],
-                            "additionalProperties": false
-                        })
-                    });
-                }
+    } else {
+        quote! {}
+    };
+
+    quote! {
+        #schema_code
+        #annotations_code
+        #required_code
+    }
+}
+
+/// Builds the code inserting a field's JSON schema into `properties`, without marking it
+/// required.
+fn build_field_property(fld: &FieldDef) -> proc_macro2::TokenStream {
+    let field_name = &fld.name;
+    let field_name_str = field_name.to_string();
+    let field_type = &fld.field_type;
+
+    match field_type {
+        // Strings, numbers, booleans, formatted strings and ObjectId, alone or in a sequence
+        field_type if field_type.is_scalar() => {
+            let value_schema = scalar_value_json_schema(fld);
+            quote! {
+                properties.insert(#field_name_str.to_string(), serde_json::json!(#value_schema));
             }
         }
-        FieldDefType::SiblingType(name, lst) => {
-            if env::var("RUST_LOG") == Ok(String::from("trace")) {
-                println!("SiblingType => name: {name}, lst: {lst:?}");
-            }
-            if (name == "Vec" || name == "HashSet") && lst.len() == 1 {
-                quote! {
-                    properties.insert(#field_name_str.to_string(), {
-                        serde_json::json!({
-                            "type": "array",
-                            "items": {
-                                "type": "string", // This would need to be mapped based on inner_type
-                            }
-                        })
-                    });
-                }
-            } else if (name == "HashMap" || name == "BTreeMap") && lst.len() == 2 {
-                if env::var("RUST_LOG") == Ok(String::from("trace")) {
-                    println!("HashMap => field_name: {field_name_str}, lst: {lst:?}");
-                }
-                quote! {
-                    properties.insert(#field_name_str.to_string(), {
-                        serde_json::json!({
-                            "type": "object",
-                            "additionalProperties": true
-                        })
-                    });
-                }
-            } else if lst.is_empty() {
-                let name_ident = proc_macro2::Ident::new(
-                    format!("{name}Json").as_str(),
-                    proc_macro2::Span::call_site(),
-                );
-                let type_json_schema = quote! { #name_ident::json_schema() };
+        // Arbitrary JSON, such as `serde_json::Value`, and external types match the empty schema
+        // JSON written as a string, from `raw_json(parse)`
+        FieldDefType::Unknown if fld.parses_raw_json() => generate_type_schema(
+            fld,
+            &field_name_str,
+            quote! { serde_json::json!({ "type": "string", "contentMediaType": "application/json" }) },
+        ),
+        FieldDefType::Unknown | FieldDefType::External(_) => generate_type_schema(fld, &field_name_str, quote! { serde_json::json!({}) }),
+        FieldDefType::SiblingType(name, lst, module) => {
+            if lst.is_empty() {
+                let name_json = sibling_json_type(name, module);
+                let type_json_schema = nested_json_schema(quote! { #name_json });
 
                 generate_type_schema(fld, &field_name_str, type_json_schema)
             } else {
-                panic!("Unsupported generic type: {name} - {lst:?}");
+                unreachable!("checked by check_field_types")
             }
         }
+        // Nested sequences, e.g. Vec<Vec<u32>>: the items are the schema of the inner sequence
+        FieldDefType::Nested(inner) => generate_type_schema(fld, &field_name_str, value_json_schema(inner)),
+        // Sequences of maps, e.g. Vec<HashMap<String, u32>>: the items are the schema of one map
+        FieldDefType::Map(_, _) if fld.is_array => {
+            let map = FieldDef { is_array: false, is_optional: false, ..fld.clone() };
+            generate_type_schema(fld, &field_name_str, value_json_schema(&map))
+        }
         FieldDefType::Map(key, value) => {
-            if env::var("RUST_LOG") == Ok(String::from("trace")) {
-                println!("Map => field_name: {field_name_str}, key: {key:?}, value: {value:?}");
-            }
-
             match &key.field_type {
-                FieldDefType::String => match &value.field_type {
-                    FieldDefType::String => {
-                        if value.is_array {
-                            quote! {
-                                properties.insert(#field_name_str.to_string(), {
-                                    serde_json::json!({
-                                        "type": "object",
-                                        "additionalProperties": {
-                                            "type": "array",
-                                            "items": { "type": "string" }
-                                        }
-                                    })
-                                });
-                            }
-                        } else {
-                            quote! {
-                                properties.insert(#field_name_str.to_string(), {
-                                    serde_json::json!({
-                                        "type": "object",
-                                        "additionalProperties": {
-                                            "type": "string"
-                                        }
-                                    })
-                                });
-                            }
-                        }
-                    }
-                    FieldDefType::U8
-                    | FieldDefType::U16
-                    | FieldDefType::U32
-                    | FieldDefType::U64
-                    | FieldDefType::I8
-                    | FieldDefType::I16
-                    | FieldDefType::I32
-                    | FieldDefType::I64
-                    | FieldDefType::Usize
-                    | FieldDefType::Isize => {
-                        if value.is_array {
-                            quote! {
-                                properties.insert(#field_name_str.to_string(), {
-                                    serde_json::json!({
-                                        "type": "object",
-                                        "additionalProperties": {
-                                            "type": "array",
-                                            "items": { "type": "integer" }
-                                        }
-                                    })
-                                });
-                            }
-                        } else {
-                            quote! {
-                                properties.insert(#field_name_str.to_string(), {
-                                    serde_json::json!({
-                                        "type": "object",
-                                        "additionalProperties": {
-                                            "type": "integer"
-                                        }
-                                    })
-                                });
-                            }
-                        }
-                    }
-                    FieldDefType::F32 | FieldDefType::F64 => {
-                        if value.is_array {
-                            quote! {
-                                properties.insert(#field_name_str.to_string(), {
-                                    serde_json::json!({
-                                        "type": "object",
-                                        "additionalProperties": {
-                                            "type": "array",
-                                            "items": { "type": "number" }
-                                        }
-                                    })
-                                });
-                            }
-                        } else {
-                            quote! {
-                                properties.insert(#field_name_str.to_string(), {
-                                    serde_json::json!({
-                                        "type": "object",
-                                        "additionalProperties": {
-                                            "type": "number"
-                                        }
-                                    })
-                                });
-                            }
-                        }
-                    }
-                    FieldDefType::Boolean => {
-                        if value.is_array {
-                            quote! {
-                                properties.insert(#field_name_str.to_string(), {
-                                    serde_json::json!({
-                                        "type": "object",
-                                        "additionalProperties": {
-                                            "type": "array",
-                                            "items": { "type": "boolean" }
-                                        }
-                                    })
-                                });
-                            }
-                        } else {
-                            quote! {
-                                properties.insert(#field_name_str.to_string(), {
-                                    serde_json::json!({
-                                        "type": "object",
-                                        "additionalProperties": {
-                                            "type": "boolean"
-                                        }
-                                    })
-                                });
-                            }
-                        }
-                    }
-                    #[cfg(feature = "object_id")]
-                    FieldDefType::ObjectId => {
-                        if value.is_array {
-                            quote! {
-                                properties.insert(#field_name_str.to_string(), {
-                                    serde_json::json!({
-                                        "type": "object",
-                                        "additionalProperties": {
-                                            "type": "array",
-                                            "items": {
-                                                "type": "object",
-                                                "properties": {
-                                                    "$oid": { "type": "string" }
-                                                },
-                                                "required": ["$oid"],
-                                                "additionalProperties": false
-                                            }
-                                        }
-                                    })
-                                });
-                            }
-                        } else {
-                            quote! {
-                                properties.insert(#field_name_str.to_string(), {
-                                    serde_json::json!({
-                                        "type": "object",
-                                        "additionalProperties": {
-                                            "type": "object",
-                                            "properties": {
-                                                "$oid": { "type": "string" }
-                                            },
-                                            "required": ["$oid"],
-                                            "additionalProperties": false
-                                        }
-                                    })
-                                });
-                            }
-                        }
-                    }
-                    FieldDefType::Map(inner_key, inner_value) => {
-                        if env::var("RUST_LOG") == Ok(String::from("trace")) {
-                            println!(
-                                "Map Value is another Map => inner_key: {:?}, inner_value: {:?}, is_array: {}",
-                                inner_key, inner_value, value.is_array
+                // JSON object keys are strings, so this is a string-keyed map whose keys must
+                // be ObjectId hex strings
+                #[cfg(feature = "object_id")]
+                FieldDefType::ObjectId => {
+                    let string_keyed = FieldDef {
+                        field_type: FieldDefType::Map(
+                            Box::new(FieldDef {
+                                field_type: FieldDefType::String,
+                                ..key.as_ref().clone()
+                            }),
+                            value.clone(),
+                        ),
+                        ..fld.clone()
+                    };
+                    let map_schema = build_field_property(&string_keyed);
+                    let key_pattern = crate::features::object_id::get_object_id_key_pattern();
+                    quote! {
+                        #map_schema
+                        if let Some(serde_json::Value::Object(map_schema)) = properties.get_mut(#field_name_str) {
+                            map_schema.insert(
+                                "propertyNames".to_string(),
+                                serde_json::json!({ "type": "string", "pattern": #key_pattern }),
                             );
                         }
-
-                        // Handle Vec<HashMap<String, T>> case
-                        if value.is_array && matches!(inner_key.field_type, FieldDefType::String) {
-                            let inner_value_schema = match &inner_value.field_type {
-                                FieldDefType::U8
-                                | FieldDefType::U16
-                                | FieldDefType::U32
-                                | FieldDefType::U64
-                                | FieldDefType::I8
-                                | FieldDefType::I16
-                                | FieldDefType::I32
-                                | FieldDefType::I64
-                                | FieldDefType::Usize
-                                | FieldDefType::Isize => {
-                                    quote! { { "type": "integer" } }
-                                }
-                                FieldDefType::F32 | FieldDefType::F64 => {
-                                    quote! { { "type": "number" } }
-                                }
-                                FieldDefType::String => {
-                                    quote! { { "type": "string" } }
-                                }
-                                FieldDefType::Boolean => {
-                                    quote! { { "type": "boolean" } }
-                                }
-                                #[cfg(feature = "object_id")]
-                                FieldDefType::ObjectId => {
-                                    quote! { {
-                                        "type": "object",
-                                        "properties": {
-                                            "$oid": { "type": "string" }
-                                        },
-                                        "required": ["$oid"],
-                                        "additionalProperties": false
-                                    } }
-                                }
-                                _ => {
-                                    quote! { true }
-                                }
-                            };
-
-                            quote! {
-                                properties.insert(#field_name_str.to_string(), {
-                                    serde_json::json!({
-                                        "type": "object",
-                                        "additionalProperties": {
-                                            "type": "array",
-                                            "items": {
-                                                "type": "object",
-                                                "additionalProperties": #inner_value_schema
-                                            }
-                                        }
-                                    })
-                                });
-                            }
-                        } else {
-                            // Fallback for non-array Maps or complex cases
-                            quote! {
-                                properties.insert(#field_name_str.to_string(), {
-                                    serde_json::json!({
-                                        "type": "object",
-                                        "additionalProperties": true
-                                    })
-                                });
-                            }
-                        }
                     }
-                    FieldDefType::SiblingType(value_type_name, value_args) => {
-                        if env::var("RUST_LOG") == Ok(String::from("trace")) {
-                            println!(
-                                "Map Value SiblingType => value_type_name: {value_type_name}, value_args: {value_args:?}"
-                            );
-                        }
-
-                        // Handle Vec<T> as map value
-                        if value_type_name == "Vec" && value_args.len() == 1 {
-                            let inner_type = &value_args[0];
-                            match &inner_type.field_type {
-                                // Vec<HashMap<String, T>>
-                                FieldDefType::Map(inner_key, inner_value) => {
-                                    match &inner_key.field_type {
-                                        FieldDefType::String => {
-                                            let inner_value_schema = match &inner_value.field_type {
-                                                FieldDefType::U8
-                                                | FieldDefType::U16
-                                                | FieldDefType::U32
-                                                | FieldDefType::U64
-                                                | FieldDefType::I8
-                                                | FieldDefType::I16
-                                                | FieldDefType::I32
-                                                | FieldDefType::I64
-                                                | FieldDefType::Usize
-                                                | FieldDefType::Isize => {
-                                                    quote! { { "type": "integer" } }
-                                                }
-                                                FieldDefType::F32 | FieldDefType::F64 => {
-                                                    quote! { { "type": "number" } }
-                                                }
-                                                FieldDefType::String => {
-                                                    quote! { { "type": "string" } }
-                                                }
-                                                FieldDefType::Boolean => {
-                                                    quote! { { "type": "boolean" } }
-                                                }
-                                                _ => {
-                                                    quote! { true }
-                                                }
-                                            };
-
-                                            quote! {
-                                                properties.insert(#field_name_str.to_string(), {
-                                                    serde_json::json!({
-                                                        "type": "object",
-                                                        "additionalProperties": {
-                                                            "type": "array",
-                                                            "items": {
-                                                                "type": "object",
-                                                                "additionalProperties": #inner_value_schema
-                                                            }
-                                                        }
-                                                    })
-                                                });
-                                            }
-                                        }
-                                        _ => {
-                                            quote! {
-                                                properties.insert(#field_name_str.to_string(), {
-                                                    serde_json::json!({
-                                                        "type": "object",
-                                                        "additionalProperties": true
-                                                    })
-                                                });
-                                            }
-                                        }
-                                    }
-                                }
-                                // Vec<primitive>
-                                FieldDefType::U8
-                                | FieldDefType::U16
-                                | FieldDefType::U32
-                                | FieldDefType::U64
-                                | FieldDefType::I8
-                                | FieldDefType::I16
-                                | FieldDefType::I32
-                                | FieldDefType::I64
-                                | FieldDefType::Usize
-                                | FieldDefType::Isize => {
-                                    quote! {
-                                        properties.insert(#field_name_str.to_string(), {
-                                            serde_json::json!({
-                                                "type": "object",
-                                                "additionalProperties": {
-                                                    "type": "array",
-                                                    "items": { "type": "integer" }
-                                                }
-                                            })
-                                        });
-                                    }
-                                }
-                                FieldDefType::F32 | FieldDefType::F64 => {
-                                    quote! {
-                                        properties.insert(#field_name_str.to_string(), {
-                                            serde_json::json!({
-                                                "type": "object",
-                                                "additionalProperties": {
-                                                    "type": "array",
-                                                    "items": { "type": "number" }
-                                                }
-                                            })
-                                        });
-                                    }
-                                }
-                                FieldDefType::String => {
-                                    quote! {
-                                        properties.insert(#field_name_str.to_string(), {
-                                            serde_json::json!({
-                                                "type": "object",
-                                                "additionalProperties": {
-                                                    "type": "array",
-                                                    "items": { "type": "string" }
-                                                }
-                                            })
-                                        });
-                                    }
-                                }
-                                FieldDefType::Boolean => {
-                                    quote! {
-                                        properties.insert(#field_name_str.to_string(), {
-                                            serde_json::json!({
-                                                "type": "object",
-                                                "additionalProperties": {
-                                                    "type": "array",
-                                                    "items": { "type": "boolean" }
-                                                }
-                                            })
-                                        });
-                                    }
-                                }
-                                #[cfg(feature = "object_id")]
-                                FieldDefType::ObjectId => {
-                                    quote! {
-                                        properties.insert(#field_name_str.to_string(), {
-                                            serde_json::json!({
-                                                "type": "object",
-                                                "additionalProperties": {
-                                                    "type": "array",
-                                                    "items": {
-                                                        "type": "object",
-                                                        "properties": {
-                                                            "$oid": { "type": "string" }
-                                                        },
-                                                        "required": ["$oid"],
-                                                        "additionalProperties": false
-                                                    }
-                                                }
-                                            })
-                                        });
-                                    }
-                                }
-                                _ => {
-                                    quote! {
-                                        properties.insert(#field_name_str.to_string(), {
-                                            serde_json::json!({
-                                                "type": "object",
-                                                "additionalProperties": true
-                                            })
-                                        });
-                                    }
-                                }
-                            }
-                        } else {
-                            // Other SiblingType cases - fallback to generic
-                            quote! {
-                                properties.insert(#field_name_str.to_string(), {
-                                    serde_json::json!({
-                                        "type": "object",
-                                        "additionalProperties": true
-                                    })
-                                });
-                            }
+                }
+                // Formatted, char and `keyPattern` keys, e.g. HashMap<Uuid, T>: a string-keyed
+                // map whose keys have the format (or a single character, or the pattern)
+                FieldDefType::FormattedString(_) | FieldDefType::Char | FieldDefType::String
+                    if !matches!(key.field_type, FieldDefType::String) || key.string_pattern().is_some() =>
+                {
+                    let string_keyed = FieldDef {
+                        field_type: FieldDefType::Map(
+                            Box::new(FieldDef {
+                                field_type: FieldDefType::String,
+                                model_schema_prop_meta: None,
+                                ..key.as_ref().clone()
+                            }),
+                            value.clone(),
+                        ),
+                        ..fld.clone()
+                    };
+                    let map_schema = build_field_property(&string_keyed);
+                    let key_schema = scalar_json_schema(key);
+                    quote! {
+                        #map_schema
+                        if let Some(serde_json::Value::Object(map_schema)) = properties.get_mut(#field_name_str) {
+                            map_schema.insert("propertyNames".to_string(), serde_json::json!(#key_schema));
                         }
                     }
-                    _ => {
-                        quote! {
-                            properties.insert(#field_name_str.to_string(), {
-                                serde_json::json!({
-                                    "type": "object",
-                                    "additionalProperties": true
-                                })
-                            });
-                        }
+                }
+                // String keys, e.g. HashMap<String, u32> or HashMap<String, Vec<VenueJson>>. A
+                // `None` value is kept in the map and serialized as null, so optional values
+                // are nullable.
+                FieldDefType::String => {
+                    let value_schema = value_json_schema(value);
+                    quote! {
+                        properties.insert(#field_name_str.to_string(), {
+                            serde_json::json!({
+                                "type": "object",
+                                "additionalProperties": #value_schema
+                            })
+                        });
                     }
-                },
-                FieldDefType::SiblingType(key_type_name, lst) if lst.is_empty() => {
-                    let key_type_name_ident = proc_macro2::Ident::new(
-                        format!("{key_type_name}Json").as_str(),
-                        proc_macro2::Span::call_site(),
-                    );
+                }
+                // Integer keys, e.g. BTreeMap<u32, String>: serde_json writes them as strings
+                // of digits
+                FieldDefType::U8
+                | FieldDefType::U16
+                | FieldDefType::U32
+                | FieldDefType::U64
+                | FieldDefType::I8
+                | FieldDefType::I16
+                | FieldDefType::I32
+                | FieldDefType::I64
+                | FieldDefType::Usize
+                | FieldDefType::Isize => {
+                    let value_schema = value_json_schema(value);
+                    let key_pattern =
+                        if key.field_type.is_unsigned_integer() { "^(0|[1-9][0-9]*)$" } else { "^(0|-?[1-9][0-9]*)$" };
+                    quote! {
+                        properties.insert(#field_name_str.to_string(), {
+                            serde_json::json!({
+                                "type": "object",
+                                "additionalProperties": #value_schema,
+                                "propertyNames": { "type": "string", "pattern": #key_pattern }
+                            })
+                        });
+                    }
+                }
+                FieldDefType::SiblingType(key_type_name, lst, key_module) if lst.is_empty() => {
+                    let key_type_name_ident = sibling_json_type(key_type_name, key_module);
 
-                    let value_schema_code = match &value.field_type {
-                        FieldDefType::SiblingType(value_type_name, lst) if lst.is_empty() => {
-                            let value_type_name_ident = proc_macro2::Ident::new(
-                                format!("{value_type_name}Json").as_str(),
-                                proc_macro2::Span::call_site(),
-                            );
-                            quote! { let value_schema = #value_type_name_ident::json_schema(); }
-                        }
-                        _ => {
-                            panic!("Unsupported map value type: {:?}", value.field_type);
-                        }
-                    };
+                    let value_schema = value_json_schema(value);
 
                     quote! {
                         let mut map_properties = serde_json::Map::new();
 
-                        #value_schema_code
+                        let value_schema = #value_schema;
 
                         for enum_key in #key_type_name_ident::enum_members() {
                             map_properties.insert(enum_key.to_string(), value_schema.clone());
                         }
 
+                        // A key enum with a `#[serde(other)]` variant also accepts unknown keys
+                        let additional_properties = if #key_type_name_ident::__tixschema_json_schema().get("enum").is_some() {
+                            serde_json::Value::Bool(false)
+                        } else {
+                            value_schema.clone()
+                        };
+
                         let mut json_schema_def = serde_json::json!({
                             "type": "object",
                             "properties": map_properties,
-                            "additionalProperties": false
+                            "additionalProperties": additional_properties
                         });
 
                         properties.insert(#field_name_str.to_string(), {
@@ -1233,10 +2012,6 @@ fn build_field_schema(fld: &FieldDef) -> proc_macro2::TokenStream {
                 }
 
                 _ => {
-                    if env::var("RUST_LOG") == Ok(String::from("trace")) {
-                        println!("Map Key Type {:?}", key.field_type);
-                    }
-
                     quote! {
                         properties.insert(#field_name_str.to_string(), {
                             serde_json::json!({
@@ -1248,53 +2023,308 @@ fn build_field_schema(fld: &FieldDef) -> proc_macro2::TokenStream {
                 }
             }
         }
-        fld_def => {
-            if env::var("RUST_LOG") == Ok(String::from("trace")) {
-                println!("Other => field_name: {field_name_str}, fld_def: {fld_def:?}");
-            }
+        _ => {
             let name = &fld.name;
             let name_ident = proc_macro2::Ident::new(
                 format!("{name}Json").as_str(),
                 proc_macro2::Span::call_site(),
             );
-            let type_json_schema = quote! { #name_ident::json_schema() };
+            let type_json_schema = nested_json_schema(quote! { #name_ident });
             quote! {
                 properties.insert(#field_name_str.to_string(), #type_json_schema);
             }
         }
+    }
+}
+
+/// Adds model_schema_prop annotations (such as deprecation) to a field's already
+/// inserted JSON schema property.
+fn build_field_annotations(fld: &FieldDef) -> proc_macro2::TokenStream {
+    let field_name_str = fld.name.to_string();
+    let mut annotations = Vec::new();
+
+    if let Some(ref meta) = fld.model_schema_prop_meta
+        && let Some(ref reason) = meta.deprecated {
+            annotations.push(quote! {
+                prop.insert("deprecated".to_string(), serde_json::Value::Bool(true));
+                prop.insert("$comment".to_string(), serde_json::Value::String(#reason.to_string()));
+            });
+        }
+
+    if let Some(ref meta) = fld.model_schema_prop_meta {
+        if meta.read_only {
+            annotations.push(quote! {
+                prop.insert("readOnly".to_string(), serde_json::Value::Bool(true));
+            });
+        }
+        if meta.write_only {
+            annotations.push(quote! {
+                prop.insert("writeOnly".to_string(), serde_json::Value::Bool(true));
+            });
+        }
+    }
+
+    if annotations.is_empty() {
+        return quote! {};
+    }
+
+    quote! {
+        if let Some(serde_json::Value::Object(prop)) = properties.get_mut(#field_name_str) {
+            #(#annotations)*
+        }
+    }
+}
+
+/// Returns an expression building the JSON schema of a value nested in a sequence or map, whatever
+/// its type. It's the property schema of the value, built into a scratch object. An optional
+/// value is kept as null, so it's nullable.
+fn value_json_schema(fld: &FieldDef) -> proc_macro2::TokenStream {
+    let property = build_field_property(fld);
+    let name = &fld.name;
+    // In parentheses, so `json!` doesn't take the block for an object
+    let schema = quote! {
+        ({
+            let mut properties = serde_json::Map::new();
+            #property
+            properties.remove(#name).unwrap_or(serde_json::Value::Bool(true))
+        })
     };
+    if fld.is_optional {
+        quote! { serde_json::json!({ "anyOf": [#schema, { "type": "null" }] }) }
+    } else {
+        schema
+    }
+}
 
-    let required_code = if !fld.is_optional {
-        quote! {
-            required.push(serde_json::Value::String(#field_name_str.to_string()));
+/// The expression building the schema of the nested type `type_path` inside the body of a
+/// `json_schema()` method: its whole schema, or a `$ref` for a shared definition or a
+/// recursive type (see `features::jsonschema::json_schema_methods`).
+fn nested_json_schema(type_path: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    quote! { #type_path::__tixschema_json_schema_ref(stack, defs) }
+}
+
+/// The expression building the whole schema of the nested type `type_path`, never a `$ref`,
+/// for the schemas that are merged into another one, such as flattened structs.
+fn inlined_json_schema(type_path: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    quote! { #type_path::__tixschema_json_schema_in(stack, defs) }
+}
+
+/// Builds the JSON schema object literal of a single scalar value (see
+/// `FieldDefType::is_scalar`), shared by fields, map values and sequence items.
+fn scalar_json_schema(fld: &FieldDef) -> proc_macro2::TokenStream {
+    match &fld.field_type {
+        FieldDefType::String | FieldDefType::Char => {
+            if let Some(values) = fld.enum_values() {
+                return quote! { { "type": "string", "enum": [#(#values),*] } };
+            }
+            // JSON schema lengths count code points, so a char is exactly one
+            let mut keywords = Vec::new();
+            if matches!(fld.field_type, FieldDefType::Char) {
+                keywords.push(quote! { , "minLength": 1, "maxLength": 1 });
+            } else if let Some(ref meta) = fld.model_schema_prop_meta {
+                if let Some(min_len) = meta.min_length {
+                    keywords.push(quote! { , "minLength": #min_len });
+                }
+                if let Some(max_len) = meta.max_length {
+                    keywords.push(quote! { , "maxLength": #max_len });
+                }
+            }
+            if let Some(pattern) = fld.string_pattern() {
+                keywords.push(quote! { , "pattern": #pattern });
+            }
+            if let Some(format) = fld.string_format_name() {
+                keywords.push(quote! { , "format": #format });
+            }
+            quote! { { "type": "string" #(#keywords)* } }
+        }
+        FieldDefType::StringLiteral(literal) => quote! { { "type": "string", "const": #literal } },
+        FieldDefType::U8
+        | FieldDefType::U16
+        | FieldDefType::U32
+        | FieldDefType::U64
+        | FieldDefType::I8
+        | FieldDefType::I16
+        | FieldDefType::I32
+        | FieldDefType::I64
+        | FieldDefType::Usize
+        | FieldDefType::Isize => integer_json_schema(fld),
+        FieldDefType::F32 | FieldDefType::F64 | FieldDefType::Number => float_json_schema(fld),
+        FieldDefType::Boolean => quote! { { "type": "boolean" } },
+        FieldDefType::FormattedString(format) => match format.json_schema_formats() {
+            [] => match format.json_schema_pattern() {
+                Some(pattern) => quote! { { "type": "string", "pattern": #pattern } },
+                None => quote! { { "type": "string" } },
+            },
+            [format] => quote! { { "type": "string", "format": #format } },
+            formats => quote! { { "type": "string", "anyOf": [#({ "format": #formats }),*] } },
+        },
+        #[cfg(all(feature = "object_id", not(feature = "object_id_flexible")))]
+        FieldDefType::ObjectId => quote! { {
+            "type": "object",
+            "properties": {
+                "$oid": { "type": "string" }
+            },
+            "required": ["$oid"],
+            "additionalProperties": false
+        } },
+        // Either the extended JSON `{ "$oid": ... }` or the bare hex string
+        #[cfg(feature = "object_id_flexible")]
+        FieldDefType::ObjectId => {
+            let pattern = crate::features::object_id::get_object_id_key_pattern();
+            quote! { {
+                "anyOf": [
+                    {
+                        "type": "object",
+                        "properties": {
+                            "$oid": { "type": "string", "pattern": #pattern }
+                        },
+                        "required": ["$oid"],
+                        "additionalProperties": false
+                    },
+                    { "type": "string", "pattern": #pattern }
+                ]
+            } }
+        }
+        _ => quote! { true },
+    }
+}
+
+/// Builds the JSON schema object literal of a scalar value, or of a sequence of them.
+fn scalar_value_json_schema(fld: &FieldDef) -> proc_macro2::TokenStream {
+    let schema = scalar_json_schema(fld);
+    if fld.is_array {
+        let unique_items = unique_items_keyword(fld);
+        quote! { { "type": "array", "items": #schema #unique_items } }
+    } else {
+        schema
+    }
+}
+
+/// The `uniqueItems` keyword of a set, which serde writes as an array without duplicates, to
+/// follow the `items` of its array schema.
+fn unique_items_keyword(fld: &FieldDef) -> Option<proc_macro2::TokenStream> {
+    fld.is_set.then(|| quote! { , "uniqueItems": true })
+}
+
+/// Builds the JSON schema object literal for an integer field, including the
+/// "not zero" constraint carried by `NonZero*` types.
+fn integer_json_schema(fld: &FieldDef) -> proc_macro2::TokenStream {
+    let mut keywords = numeric_bound_keywords(fld);
+
+    // With `safe_ints`, 64-bit integers without their own bounds are limited to the range of
+    // Number.MAX_SAFE_INTEGER (2^53 - 1)
+    #[cfg(feature = "safe_ints")]
+    if fld.field_type.is_wide_integer() {
+        if fld.numeric_minimum().is_none() && !fld.field_type.is_unsigned_integer() {
+            keywords.push(quote! { , "minimum": -9007199254740991i64 });
         }
-    } else {
-        quote! {}
+        if fld.numeric_maximum().is_none() {
+            keywords.push(quote! { , "maximum": 9007199254740991i64 });
+        }
+    }
+
+    if fld.is_non_zero && !fld.field_type.is_unsigned_integer() {
+        keywords.push(quote! { , "not": { "const": 0 } });
+    }
+    quote! { { "type": "integer" #(#keywords)* } }
+}
+
+/// The `minimum` / `maximum` keywords of a numeric field, from `min` / `max` via
+/// model_schema_prop and the lower bound of unsigned `NonZero*` integers.
+fn numeric_bound_keywords(fld: &FieldDef) -> Vec<proc_macro2::TokenStream> {
+    // The bounds are validated number literals, e.g. `-10` or `99.5`
+    let literal = |bound: String| -> proc_macro2::TokenStream {
+        bound.parse().unwrap_or_else(|err| panic!("invalid numeric bound `{bound}`: {err}"))
     };
+    let mut keywords = Vec::new();
+    if let Some(min) = fld.numeric_minimum().map(literal) {
+        keywords.push(quote! { , "minimum": #min });
+    }
+    if let Some(max) = fld.numeric_maximum().map(literal) {
+        keywords.push(quote! { , "maximum": #max });
+    }
+    keywords
+}
+
+/// Builds the JSON schema object literal for a float field. With the `float_precision_hints`
+/// feature, `f32` and `f64` are told apart through the `float` / `double` formats.
+fn float_json_schema(fld: &FieldDef) -> proc_macro2::TokenStream {
+    #[cfg_attr(not(feature = "float_precision_hints"), allow(unused_mut))]
+    let mut keywords = numeric_bound_keywords(fld);
+
+    #[cfg(feature = "float_precision_hints")]
+    match fld.field_type {
+        FieldDefType::F32 => keywords.push(quote! { , "format": "float" }),
+        FieldDefType::F64 => keywords.push(quote! { , "format": "double" }),
+        // serde_json::Number may hold an integer, so it gets no precision hint
+        _ => {}
+    }
+
+    quote! { { "type": "number" #(#keywords)* } }
+}
+
+/// Returns the Zod schema of a field. With `optionals_absent`, an optional field uses
+/// `.optional()` so the key itself may be missing, rather than `.or(z.undefined())`. With
+/// `optionals_nullable` it's `.or(z.null())`.
+#[cfg(feature = "zod")]
+fn zod_property_schema(fld: &FieldDef, optional_style: OptionalStyle, zod: &str) -> String {
+    let required = FieldDef { is_optional: false, ..fld.clone() };
+    match optional_style {
+        OptionalStyle::Absent if fld.is_optional => format!("{}.optional()", required.zod_type(zod)),
+        OptionalStyle::Nullable if fld.is_optional => format!("{}.or({zod}.null())", required.zod_type(zod)),
+        _ => fld.zod_type(zod),
+    }
+}
+
+/// Builds the JSON schema of a field as `build_field_schema` does, except that with
+/// `optionals_nullable` an optional field is required and also accepts `null`: a `"type"`
+/// gains `"null"`, other schemas are wrapped in `anyOf`.
+fn build_property_schema(fld: &FieldDef, optional_style: OptionalStyle) -> proc_macro2::TokenStream {
+    if optional_style != OptionalStyle::Nullable || !fld.is_optional {
+        return build_field_schema(fld);
+    }
 
+    let required = FieldDef { is_optional: false, ..fld.clone() };
+    let schema = build_field_schema(&required);
+    let field_name_str = fld.name.to_string();
     quote! {
-        #schema_code
-        #required_code
+        #schema
+        if let Some(prop) = properties.remove(#field_name_str) {
+            let nullable = match prop {
+                serde_json::Value::Object(mut prop)
+                    if prop.get("type").is_some_and(serde_json::Value::is_string)
+                        && !prop.contains_key("enum")
+                        && !prop.contains_key("const") =>
+                {
+                    let ty = prop.remove("type").unwrap();
+                    prop.insert("type".to_string(), serde_json::json!([ty, "null"]));
+                    serde_json::Value::Object(prop)
+                }
+                prop => serde_json::json!({ "anyOf": [prop, { "type": "null" }] }),
+            };
+            properties.insert(#field_name_str.to_string(), nullable);
+        }
     }
 }
 
 /// Writes the TypeScript type and conditionally Zod schema for a field to the provided buffers.
-fn write_field_type_and_schema(type_code: &mut String, schema_code: &mut String, fld: &FieldDef) {
+fn write_field_type_and_schema(
+    type_code: &mut String,
+    schema_code: &mut String,
+    fld: &FieldDef,
+    optional_style: OptionalStyle,
+    #[cfg(feature = "zod")] zod: &str,
+) {
     // Always write TypeScript type
-    if let Err(err) = writeln!(
-        type_code,
-        "  /**\n{}\n**/\n  {}: {};",
-        fld.docs,
-        fld.name,
-        fld.typescript_typename()
-    ) {
+    if let Err(err) = writeln!(type_code, "{}", GenerationUtils::format_typescript_field(fld, optional_style)) {
         panic!("Failed to write TypeScript type: {err}");
     }
 
     // Conditionally write Zod schema
     #[cfg(feature = "zod")]
     {
-        if let Err(err) = writeln!(schema_code, "  {}: {},", fld.name, fld.zod_type()) {
+        let zod_field_type = zod_property_schema(fld, optional_style, zod);
+        if let Err(err) = writeln!(schema_code, "  {}: {},", fld.property_key(), zod_field_type) {
             panic!("Failed to write Zod schema: {err}");
         }
     }
@@ -1306,17 +2336,51 @@ fn write_field_type_and_schema(type_code: &mut String, schema_code: &mut String,
     }
 }
 
+/// Whether serde never writes the field (`#[serde(skip)]` or `skip_serializing`), which then
+/// has no place in the generated output. With `deserialize_shape`, the output describes what
+/// serde reads instead, so `skip_deserializing` fields are dropped and `skip_serializing` ones
+/// kept. Its type isn't looked at, since skipped fields often hold values that can't be
+/// serialized, but its model_schema_prop attributes are removed all the same.
+fn is_skipped_field(field: &mut Field, deserialize_shape: bool) -> bool {
+    #[cfg(feature = "serde")]
+    {
+        let serde_meta = parse_serde_field_attributes(&field.attrs);
+        let skipped = if deserialize_shape { serde_meta.skip_deserializing } else { serde_meta.skip_serializing };
+        if skipped {
+            field.attrs.retain(|attr| !attr.path().is_ident("model_schema_prop"));
+            return true;
+        }
+    }
+
+    #[cfg(not(feature = "serde"))]
+    let _ = (field, deserialize_shape);
+
+    false
+}
+
 /// Processes a field and returns its definition.
 fn process_field(rename_all: &Option<String>, field: &mut Field) -> FieldDef {
+    let mut field_def = typed_field_def(rename_all, field);
+    apply_model_schema_props(&mut field_def);
+    field_def
+}
+
+/// Returns the definition of a field's type, with its `as` override applied but before the
+/// other model_schema_prop options reshape it. Strips the model_schema_prop attributes.
+fn typed_field_def(rename_all: &Option<String>, field: &mut Field) -> FieldDef {
     let mut new_attrs = Vec::new();
 
     #[cfg(feature = "serde")]
-    let field_rename = parse_serde_field_attributes(&field.attrs).rename;
+    let (field_rename, has_default) = {
+        let serde_meta = parse_serde_field_attributes(&field.attrs);
+        (serde_meta.rename, serde_meta.default)
+    };
     #[cfg(not(feature = "serde"))]
-    let field_rename = None;
+    let (field_rename, has_default) = (None, false);
 
     // Parse model_schema_prop attributes before filtering them out
-    let model_schema_prop_meta = crate::features::model_schema_prop::parse_model_schema_prop_attributes(&field.attrs);
+    let model_schema_prop_meta = crate::features::model_schema_prop::parse_model_schema_prop_attributes(&field.attrs)
+        .expect("model_schema_prop attributes are checked by check_field_types");
 
     // Filter out model_schema_prop attributes
     for attr in &field.attrs {
@@ -1336,39 +2400,91 @@ fn process_field(rename_all: &Option<String>, field: &mut Field) -> FieldDef {
         .collect::<String>();
 
     let final_name = get_final_name(name, &field_rename, rename_all);
-    let field_docs = match get_field_docs(field) {
-        Some(doc_lines) => doc_lines
-            .into_iter()
-            .flat_map(|v| v.lines().map(|l| l.to_owned()).collect::<Vec<_>>())
-            .chain(vec!["".to_string()])
-            .map(|l| format!(" * {l}"))
-            .collect::<Vec<_>>()
-            .join("\n"),
-        None => [final_name.to_string(), "".to_string()]
-            .into_iter()
-            .map(|l| format!(" * {l}"))
-            .collect::<Vec<_>>()
-            .join("\n"),
-    };
+    let field_docs = GenerationUtils::format_docs(get_field_docs(field), &final_name);
     
     // Create the field definition and apply any model_schema_prop overrides
     let mut field_def = get_field_def(&final_name, field_type, &field_docs);
-    field_def.model_schema_prop_meta = if model_schema_prop_meta.as_type.is_some() || 
-                                            model_schema_prop_meta.literal.is_some() || 
-                                            model_schema_prop_meta.min_length.is_some() {
-        Some(model_schema_prop_meta.clone())
-    } else {
+    field_def.model_schema_prop_meta = if model_schema_prop_meta.is_empty() {
         None
+    } else {
+        Some(model_schema_prop_meta.clone())
     };
+    field_def.description = get_field_docs(field).map(|lines| lines.join("\n"));
     
-    // Apply type overrides based on model_schema_prop attributes
+    // Apply type overrides based on model_schema_prop attributes. `as` replaces the element
+    // type, so the field's own Vec / Option wrappers still apply on top of it.
     if let Some(ref meta) = field_def.model_schema_prop_meta
-        && let Some(ref literal) = meta.literal {
-            // If literal is specified, override the field type to StringLiteral
-            field_def.field_type = crate::field_type::FieldDefType::StringLiteral(literal.clone());
+        && let Some(ref as_type) = meta.as_type {
+            let target = get_field_def(&final_name, as_type, &field_docs);
+            field_def.field_type = target.field_type;
+            field_def.is_non_zero = target.is_non_zero;
+            field_def.is_array |= target.is_array;
+            field_def.is_set |= target.is_set;
+            field_def.is_optional |= target.is_optional;
         }
-        // TODO: Handle `as` parameter for type overrides in future implementation
+
+    // serde fills in a missing `#[serde(default)]` field, so it may be left out of the input
+    field_def.is_optional |= has_default;
+
+    field_def
+}
+
+/// Applies the model_schema_prop options of a field to its definition. Options on a field type
+/// they don't apply to are rejected by `check_field_types`, and left out here.
+fn apply_model_schema_props(field_def: &mut FieldDef) {
+    let final_name = field_def.name.clone();
+
+    // The string holds JSON, so the schemas describe any JSON value (or the string, with parse)
+    if field_def.model_schema_prop_meta.as_ref().is_some_and(|meta| meta.raw_json)
+        && matches!(field_def.field_type, FieldDefType::String)
+    {
+        field_def.field_type = FieldDefType::Unknown;
+        field_def.docs = if field_def.parses_raw_json() {
+            format!("{}\n * Serialized JSON, parsed by the Zod schema", field_def.docs)
+        } else {
+            format!("{}\n * Raw JSON", field_def.docs)
+        };
+    }
+
+    // If literal is specified, override the field type to StringLiteral. Other field types are
+    // rejected by `check_field_types`.
+    if let Some(ref meta) = field_def.model_schema_prop_meta
+        && let Some(ref literal) = meta.literal
+        && matches!(field_def.field_type, FieldDefType::String)
+    {
+        field_def.field_type = crate::field_type::FieldDefType::StringLiteral(literal.clone());
+    }
+
+    if let Some(format) = field_def.string_format_name() {
+        field_def.docs = if field_def.string_format().is_some() {
+            format!("{}\n * Format: {format}", field_def.docs)
+        } else {
+            format!("{}\n * Format: {format} (not checked by the Zod schema)", field_def.docs)
+        };
+    }
+
+    if field_def.is_coerced() {
+        field_def.docs = format!("{}\n * Coerced by the Zod schema", field_def.docs);
+    }
     
+    #[cfg(feature = "object_id")]
+    if field_def.has_object_id_keys() {
+        field_def.docs = format!("{}\n * Keys are ObjectId hex strings", field_def.docs);
+    }
+
+    // The key pattern becomes the `pattern` of the map's key, which the Zod record and the JSON
+    // schema's `propertyNames` then check like any string pattern
+    if let Some(key_pattern) = field_def.model_schema_prop_meta.as_ref().and_then(|meta| meta.key_pattern.clone())
+        && has_string_keys(field_def)
+        && let FieldDefType::Map(key, _) = &mut field_def.field_type
+    {
+        key.model_schema_prop_meta = Some(crate::features::model_schema_prop::ModelSchemaPropMeta {
+            pattern: Some(key_pattern.clone()),
+            ..Default::default()
+        });
+        field_def.docs = format!("{}\n * Key pattern: {key_pattern}", field_def.docs);
+    }
+
     // Update field docs to include minimum length information
     if let Some(ref meta) = field_def.model_schema_prop_meta
         && let Some(min_len) = meta.min_length {
@@ -1380,57 +2496,249 @@ fn process_field(rename_all: &Option<String>, field: &mut Field) -> FieldDef {
                 format!("{}\n{}", field_def.docs, min_len_doc)
             };
         }
-    
-    field_def
+
+    // Only strings have a length; a char is always exactly one character long
+    if let Some(ref meta) = field_def.model_schema_prop_meta
+        && let Some(max_len) = meta.max_length
+        && matches!(field_def.field_type, FieldDefType::String) {
+            let max_len_doc = format!(" * Maximum length: {max_len}");
+            field_def.docs = if field_def.docs.is_empty() {
+                format!(" * {final_name}\n * \n{max_len_doc}")
+            } else {
+                format!("{}\n{}", field_def.docs, max_len_doc)
+            };
+        }
+
+    if let Some(ref meta) = field_def.model_schema_prop_meta
+        && field_def.field_type.is_numeric() {
+            if let Some(ref min) = meta.min {
+                field_def.docs = format!("{}\n * Minimum: {min}", field_def.docs);
+            }
+            if let Some(ref max) = meta.max {
+                field_def.docs = format!("{}\n * Maximum: {max}", field_def.docs);
+            }
+        }
+
+    // Zod-only string transforms have no TypeScript equivalent, so note them in the docs
+    if let Some(ref meta) = field_def.model_schema_prop_meta {
+        let transforms = meta.string_transforms();
+        if !transforms.is_empty() {
+            field_def.docs = format!("{}\n * Normalized by the Zod schema: {}", field_def.docs, transforms.join(", "));
+        }
+    }
+
+    // Note request/response-only fields in the TypeScript docs
+    if let Some(ref meta) = field_def.model_schema_prop_meta {
+        if meta.read_only {
+            field_def.docs = format!("{}\n * Read-only: set by the server and ignored in requests", field_def.docs);
+        }
+        if meta.write_only {
+            field_def.docs = format!("{}\n * Write-only: accepted in requests but never returned", field_def.docs);
+        }
+    }
+
+    // Mark the field as deprecated in the TypeScript docs
+    if let Some(ref meta) = field_def.model_schema_prop_meta
+        && let Some(ref reason) = meta.deprecated {
+            field_def.docs = format!("{}\n * @deprecated {reason}", field_def.docs);
+        }
+}
+
+/// Resolves `#[model_schema_prop(required_with = "...")]` into `(trigger, dependent)` pairs of
+/// serialized names, meaning `dependent` is required whenever `trigger` is present.
+///
+/// The referenced field may be given by its Rust or serialized name. A note is added to the
+/// dependent field's TypeScript docs.
+fn collect_dependent_required(
+    owner: &syn::Ident,
+    field_defs: &mut [FieldDef],
+    serialized_names: &HashMap<String, String>,
+) -> syn::Result<Vec<(String, String)>> {
+    let mut dependent_required = Vec::new();
+
+    for fld in field_defs.iter_mut() {
+        let Some(lit) = fld
+            .model_schema_prop_meta
+            .as_ref()
+            .and_then(|meta| meta.required_with.clone())
+        else {
+            continue;
+        };
+        let other = lit.value();
+
+        let trigger = match serialized_names.get(&other) {
+            Some(serialized) => serialized.clone(),
+            None if serialized_names.values().any(|v| v == &other) => other,
+            None => {
+                return Err(syn::Error::new_spanned(
+                    lit,
+                    format!("`{owner}` field `{}` uses required_with = \"{other}\", but there is no such field", fld.name),
+                ));
+            }
+        };
+
+        fld.docs = format!("{}\n * Required when `{trigger}` is present", fld.docs);
+        dependent_required.push((trigger, fld.name.clone()));
+    }
+
+    Ok(dependent_required)
+}
+
+/// Returns the `strict` warnings for nested types whose casing can't be checked, emitted with
+/// `expansion_warning` so `#[allow(deprecated)]` among `attrs` silences them.
+fn warn_nested_casing(
+    container: &str,
+    rename_all: &Option<String>,
+    field_defs: &[FieldDef],
+    flattened: &[FieldDef],
+    attrs: &[syn::Attribute],
+) -> proc_macro2::TokenStream {
+    crate::features::strict::nested_casing_warnings(container, rename_all, field_defs)
+        .into_iter()
+        .chain(crate::features::strict::flattened_casing_warnings(container, rename_all, flattened))
+        .map(|warning| expansion_warning("model_schema_strict_casing", &warning, attrs))
+        .collect()
+}
+
+/// Generates the `debug` constant listing the type each field was resolved to, so it can be
+/// printed from code or read in the rendered docs instead of going to the build output.
+fn generate_field_tree_const(notes: &[String]) -> proc_macro2::TokenStream {
+    let tree = notes.join("\n");
+    let docs = notes.iter().map(|note| format!(" - {note}"));
+    quote! {
+        /// The type each field was resolved to, from `#[model_schema(debug)]`:
+        ///
+        #(#[doc = #docs])*
+        pub const FIELD_TREE: &'static str = #tree;
+    }
+}
+
+/// Rejects two fields serialized under the same key, e.g. after a `#[serde(rename)]`, since
+/// the generated object type and schemas could only describe one of them.
+fn check_duplicate_names(owner: &str, field_defs: &[FieldDef], spans: &[proc_macro2::Span]) -> syn::Result<()> {
+    let mut seen = BTreeSet::new();
+    for (fld, span) in field_defs.iter().zip(spans) {
+        if !seen.insert(fld.name.as_str()) {
+            return Err(syn::Error::new(
+                *span,
+                format!("`{owner}` has more than one field serialized as `{}`", fld.name),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// The span a field's errors point at: its name, or its type for a tuple field.
+fn field_span(field: &Field) -> proc_macro2::Span {
+    match &field.ident {
+        Some(ident) => ident.span(),
+        None => syn::spanned::Spanned::span(&field.ty),
+    }
+}
+
+/// Rejects a variant field serialized under the tag's name, which serde would write next to
+/// the tag as a second key with the same name.
+fn check_tag_collision(
+    owner: &str,
+    tag_name: &str,
+    field_defs: &[FieldDef],
+    spans: &[proc_macro2::Span],
+) -> syn::Result<()> {
+    match field_defs.iter().zip(spans).find(|(fld, _)| fld.name == tag_name) {
+        Some((_, span)) => Err(syn::Error::new(
+            *span,
+            format!("`{owner}` has a field serialized as `{tag_name}`, which collides with the enum's tag"),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Rejects `camel_schema_keys` when a serialized key isn't camelCase.
+///
+/// Schema keys always follow the serialized (serde) name, since a schema whose keys differ
+/// from the payload could never validate it. The flag only asserts that the casing the team
+/// expects is the one serde produces.
+fn check_camel_schema_keys(owner: &syn::Ident, field_defs: &[FieldDef], spans: &[proc_macro2::Span]) -> syn::Result<()> {
+    let mut errors: Option<syn::Error> = None;
+    for (fld, span) in field_defs.iter().zip(spans) {
+        if fld.name.contains('_') || fld.name.starts_with(char::is_uppercase) {
+            let error = syn::Error::new(
+                *span,
+                format!(
+                    "`{owner}` serializes the key `{}`, which is not camelCase and conflicts with `camel_schema_keys`: schema keys always match the serialized name, so use `#[serde(rename_all = \"camelCase\")]` or `#[serde(rename = \"...\")]` instead",
+                    fld.name
+                ),
+            );
+            match &mut errors {
+                Some(errors) => errors.combine(error),
+                None => errors = Some(error),
+            }
+        }
+    }
+    errors.map_or(Ok(()), Err)
 }
 
-/// Gets the final name for a field or enum variant, considering serde attributes.
+/// Gets the final name for a field, considering serde attributes.
 fn get_final_name(
     name: String,
     field_rename: &Option<String>,
     rename_all: &Option<String>,
 ) -> String {
-    if let Some(rename) = &field_rename {
-        rename.clone()
-    } else if rename_all == &Some("camelCase".to_string()) {
-        snake_to_camel(&name)
-    } else if rename_all == &Some("lowercase".to_string()) {
-        name.to_lowercase()
-    } else {
-        name
+    match field_rename {
+        Some(rename) => rename.clone(),
+        None => crate::features::casing::apply_rename_all(&name, rename_all),
     }
 }
 
-/// Converts a snake_case string to camelCase.
-fn snake_to_camel(s: &str) -> String {
-    let mut result = String::new();
-    let mut capitalize_next = false;
-
-    for (i, c) in s.chars().enumerate() {
-        if c == '_' {
-            capitalize_next = true;
-        } else if i == 0 {
-            // Force the first character to lowercase
-            result.push(c.to_lowercase().next().unwrap());
-        } else if capitalize_next {
-            // Capitalize after an underscore
-            result.push(c.to_uppercase().next().unwrap());
-            capitalize_next = false;
-        } else {
-            // Keep other characters as is
-            result.push(c);
-        }
+/// Gets the final name for an enum variant, considering serde attributes.
+fn get_final_variant_name(
+    name: String,
+    variant_rename: &Option<String>,
+    rename_all: &Option<String>,
+) -> String {
+    match variant_rename {
+        Some(rename) => rename.clone(),
+        None => crate::features::casing::apply_variant_rename_all(&name, rename_all),
     }
-
-    result
 }
 
 #[cfg(feature = "jsonschema")]
 /// Generates the JSON schema method conditionally based on the jsonschema feature
 fn generate_json_schema_method(
     json_schema_fields: &[proc_macro2::TokenStream],
+    flattened_schemas: &[proc_macro2::TokenStream],
+    catchall_schema: Option<&proc_macro2::TokenStream>,
+    dependent_required: &[(String, String)],
+    method_names: &MethodNames,
+    item_name: &str,
 ) -> proc_macro2::TokenStream {
-    crate::features::jsonschema::generate_struct_json_schema_method(json_schema_fields)
+    crate::features::jsonschema::generate_struct_json_schema_method(
+        json_schema_fields,
+        flattened_schemas,
+        catchall_schema,
+        dependent_required,
+        &method_names.json_schema,
+        item_name,
+    )
+}
+
+#[cfg(feature = "jsonschema")]
+/// Builds an expression evaluating to the JSON schema of a flattened map's values, reusing
+/// `build_field_schema` through a scratch `properties` map.
+fn catchall_json_schema(value: &FieldDef) -> proc_macro2::TokenStream {
+    let mut value = value.clone();
+    value.name = "additionalProperties".to_string();
+    let value_schema = build_field_schema(&value);
+
+    quote! {
+        {
+            let mut properties = serde_json::Map::new();
+            let mut required: Vec<serde_json::Value> = Vec::new();
+            #value_schema
+            let _ = required;
+            properties.remove("additionalProperties").unwrap()
+        }
+    }
 }
 
 #[cfg(feature = "typescript")]
@@ -1438,33 +2746,43 @@ fn generate_json_schema_method(
 fn generate_ts_definition_method(
     docs: &str,
     item_name: &str,
+    intersected: &str,
     type_code: &str,
     fields_empty: bool,
+    method_names: &MethodNames,
+    options: &CodegenOptions,
 ) -> proc_macro2::TokenStream {
+    let ts_definition = &method_names.ts_definition;
+
     // TypeScript type generation (only available when typescript feature is enabled)
+    let declaration = TypeScriptGenerator::generate_struct_type(item_name, intersected, type_code, fields_empty);
     let typescript_type_gen = if fields_empty {
         quote::quote! {
-            format!(r#"/**\n{}\n**/\nexport type {} = Record<string, never>;"#, docs, #item_name)
+            format!(r#"/**\n{}\n**/\n{}"#, docs, #declaration)
         }
     } else {
         quote::quote! {
-            format!("{}\n\nexport type {} = {{\n{}\n}};", docs, #item_name, #type_code)
+            format!("{}\n\n{}", docs, #declaration)
         }
     };
 
     #[cfg(all(feature = "jsonschema", feature = "typescript"))]
-    let json_docs_gen = generate_json_docs_part();
+    let json_docs_gen = generate_json_docs_part(&method_names.json_schema);
 
     #[cfg(not(feature = "jsonschema"))]
     let json_docs_gen = quote::quote! {
         let docs = format!("/**\n{docs}\n **/\n");
     };
 
+    let default_export = typescript_default_export(item_name, options);
+
     quote::quote! {
-        pub fn ts_definition() -> String {
+        pub fn #ts_definition() -> String {
             let docs = #docs;
             #json_docs_gen
-            #typescript_type_gen
+            let definition = #typescript_type_gen;
+            #default_export
+            definition
         }
     }
 }
@@ -1475,17 +2793,31 @@ fn generate_zod_schema_method(
     item_name: &str,
     schema_code: &str,
     show_opts: &str,
+    method_names: &MethodNames,
+    options: &CodegenOptions,
 ) -> proc_macro2::TokenStream {
+    let zod_schema = &method_names.zod_schema;
+    let default_export = zod_default_export(item_name, options);
+    let zod = &options.zod_import;
+
     #[cfg(feature = "zod")]
     {
         // When typescript feature is enabled, generate TypeScript-style Zod schema
         #[cfg(feature = "typescript")]
         {
+            let loose_schema = loose_schema_declaration(quote::quote! {
+                format!(r#"export const {}$LooseSchema: ZodType<{}> = {}.looseObject({{
+{}
+}}){};"#, #item_name, #item_name, #zod, #schema_code, #show_opts)
+            });
             quote::quote! {
-                pub fn zod_schema() -> String {
-                    format!(r#"export const {}$Schema: ZodType<{}> = z.strictObject({{
+                pub fn #zod_schema() -> String {
+                    let schema = format!(r#"export const {}$Schema: ZodType<{}> = {}.strictObject({{
 {}
-}}){};"#, #item_name, #item_name, #schema_code, #show_opts)
+}}){};"#, #item_name, #item_name, #zod, #schema_code, #show_opts);
+                    #loose_schema
+                    #default_export
+                    schema
                 }
             }
         }
@@ -1493,11 +2825,19 @@ fn generate_zod_schema_method(
         // When typescript feature is disabled, generate JavaScript-style Zod schema
         #[cfg(not(feature = "typescript"))]
         {
+            let loose_schema = loose_schema_declaration(quote::quote! {
+                format!(r#"export const {}$LooseSchema = {}.looseObject({{
+{}
+}}){};"#, #item_name, #zod, #schema_code, #show_opts)
+            });
             quote::quote! {
-                pub fn zod_schema() -> String {
-                    format!(r#"export const {}$Schema = z.strictObject({{
+                pub fn #zod_schema() -> String {
+                    let schema = format!(r#"export const {}$Schema = {}.strictObject({{
 {}
-}}){};"#, #item_name, #schema_code, #show_opts)
+}}){};"#, #item_name, #zod, #schema_code, #show_opts);
+                    #loose_schema
+                    #default_export
+                    schema
                 }
             }
         }
@@ -1513,10 +2853,145 @@ fn generate_zod_schema_method(
     }
 }
 
+/// Appends `export default User$Schema;` to the Zod output under `default_export`. A type
+/// alias can't be a default export value, so the schema is.
+#[cfg(feature = "zod")]
+fn zod_default_export(item_name: &str, options: &CodegenOptions) -> proc_macro2::TokenStream {
+    if options.default_export {
+        quote! {
+            let schema = format!("{schema}\nexport default {}$Schema;", #item_name);
+        }
+    } else {
+        quote! {}
+    }
+}
+
+/// Appends `export type { User as default };` to the TypeScript output under `default_export`
+/// when there's no Zod schema to export instead.
+#[cfg(feature = "typescript")]
+fn typescript_default_export(item_name: &str, options: &CodegenOptions) -> proc_macro2::TokenStream {
+    if options.default_export && !cfg!(feature = "zod") {
+        quote! {
+            let definition = format!("{definition}\nexport type {{ {} as default }};", #item_name);
+        }
+    } else {
+        quote! {}
+    }
+}
+
+/// Returns the statement appending the `$LooseSchema` declaration, built by `declaration`, to
+/// the struct's `schema` when the `dual_schemas` feature is enabled. The loose variant keeps
+/// unknown keys instead of rejecting them, for call sites that receive data from newer peers.
+#[cfg(feature = "zod")]
+fn loose_schema_declaration(declaration: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    #[cfg(feature = "dual_schemas")]
+    {
+        quote! {
+            let schema = format!("{schema}\n{}", #declaration);
+        }
+    }
+
+    #[cfg(not(feature = "dual_schemas"))]
+    {
+        let _ = declaration;
+        quote! {}
+    }
+}
+
+#[cfg(any(feature = "typescript", feature = "zod"))]
+/// Generates the schema_dependencies method, listing the generated type names referenced by
+/// this type so `model_schema_bundle!` can declare them first
+fn generate_schema_dependencies_method(dependencies: &BTreeSet<String>) -> proc_macro2::TokenStream {
+    let dependencies = dependencies.iter();
+    quote::quote! {
+        pub fn schema_dependencies() -> Vec<&'static str> {
+            vec![#(#dependencies),*]
+        }
+    }
+}
+
+#[cfg(any(feature = "typescript", feature = "zod", feature = "jsonschema"))]
+/// Generates the `emit(target)` method, returning one output format picked by name so generic
+/// tooling doesn't need to know the method names. A proc-macro crate can't export an enum for
+/// the targets, hence the string. Unknown targets and targets of disabled features return
+/// `None`, so tooling can skip formats a build doesn't generate.
+fn generate_emit_method(method_names: &MethodNames) -> proc_macro2::TokenStream {
+    #[allow(unused_variables)]
+    let MethodNames { ts_definition, zod_schema, json_schema, emit, .. } = method_names;
+
+    let arms = [
+        #[cfg(feature = "typescript")]
+        quote! { "typescript" => Some(Self::#ts_definition()), },
+        #[cfg(feature = "zod")]
+        quote! { "zod" => Some(Self::#zod_schema()), },
+        #[cfg(feature = "jsonschema")]
+        quote! { "json_schema" => serde_json::to_string_pretty(&Self::#json_schema()).ok(), },
+    ];
+
+    quote! {
+        /// Returns the output for `target`: "typescript", "zod" or "json_schema", or `None` for
+        /// an unknown target or one whose tixschema feature is disabled.
+        pub fn #emit(target: &str) -> Option<String> {
+            match target {
+                #(#arms)*
+                _ => None,
+            }
+        }
+    }
+}
+
+#[cfg(any(feature = "typescript", feature = "zod", feature = "jsonschema"))]
+/// Generates the hidden `__tixschema_*` forwarders to the generated methods. Generated code for
+/// other types (nested fields, flattened structs, and the union, alias, bundle and components
+/// macros) calls these instead of the methods, so it still compiles when they are renamed.
+pub(crate) fn generate_method_aliases(method_names: &MethodNames) -> proc_macro2::TokenStream {
+    #[allow(unused_variables)]
+    let MethodNames { ts_definition, zod_schema, json_schema, .. } = method_names;
+
+    let aliases = [
+        #[cfg(feature = "typescript")]
+        quote! {
+            #[doc(hidden)]
+            pub fn __tixschema_ts_definition() -> String {
+                Self::#ts_definition()
+            }
+        },
+        #[cfg(feature = "zod")]
+        quote! {
+            #[doc(hidden)]
+            pub fn __tixschema_zod_schema() -> String {
+                Self::#zod_schema()
+            }
+        },
+        #[cfg(feature = "jsonschema")]
+        quote! {
+            #[doc(hidden)]
+            pub fn __tixschema_json_schema() -> serde_json::Value {
+                Self::#json_schema()
+            }
+        },
+    ];
+
+    quote! { #(#aliases)* }
+}
+
+/// Generates the hidden `__tixschema_zod_import()`, the identifier the Zod schema calls Zod
+/// through, so `model_schema_union!` and `model_schema_bundle!` call Zod the same way in the
+/// code they add around it.
+#[cfg(feature = "zod")]
+pub(crate) fn generate_zod_import_method(zod_import: &str) -> proc_macro2::TokenStream {
+    quote! {
+        #[doc(hidden)]
+        pub fn __tixschema_zod_import() -> &'static str {
+            #zod_import
+        }
+    }
+}
+
 #[cfg(all(feature = "jsonschema", feature = "typescript"))]
-fn generate_json_docs_part() -> proc_macro2::TokenStream {
+fn generate_json_docs_part(json_schema: &syn::Ident) -> proc_macro2::TokenStream {
     quote::quote! {
-        let prettified = serde_json::to_string_pretty(&Self::json_schema()).unwrap().lines().map(|l| format!(" * {l}")).collect::<Vec<_>>().join("\n");
+        let prettified = serde_json::to_string_pretty(&Self::#json_schema()).unwrap().lines().map(|l| format!(" * {l}")).collect::<Vec<_>>().join("\n");
         let docs = format!("/**\n{docs}\n * JSON Schema:\n{prettified}\n **/\n");
     }
 }
@@ -1524,16 +2999,24 @@ fn generate_json_docs_part() -> proc_macro2::TokenStream {
 #[cfg(feature = "jsonschema")]
 /// Generates the JSON schema method for plain enums conditionally
 fn generate_plain_enum_json_schema_method(
-    _enumerated: &[proc_macro2::TokenStream],
+    _enum_options: &[String],
+    has_catch_all: bool,
+    method_names: &MethodNames,
+    item_name: &str,
 ) -> proc_macro2::TokenStream {
     #[cfg(feature = "jsonschema")]
     {
-        crate::features::jsonschema::generate_plain_enum_json_schema_method()
+        crate::features::jsonschema::generate_plain_enum_json_schema_method(
+            &method_names.json_schema,
+            item_name,
+            _enum_options,
+            has_catch_all,
+        )
     }
 
     #[cfg(not(feature = "jsonschema"))]
     {
-        let _ = _enumerated; // Suppress unused variable warning
+        let _ = (_enum_options, has_catch_all, item_name); // Suppress unused variable warning
         quote::quote! {
             // JSON schema method not available - jsonschema feature disabled
             // To enable: add "jsonschema" to your features
@@ -1547,31 +3030,60 @@ fn generate_plain_enum_json_schema_method(
 fn generate_plain_enum_ts_definition_method(
     docs: &str,
     item_name: &str,
-    type_code: &str,
+    declaration: &str,
+    enum_options: &[String],
+    method_names: &MethodNames,
+    options: &CodegenOptions,
 ) -> proc_macro2::TokenStream {
+    let MethodNames { ts_definition, json_schema, .. } = method_names;
+
+    // With `enum_consts`, a runtime array of the values follows the union type
+    #[cfg(feature = "enum_consts")]
+    let values_declaration = format!(
+        "\nexport const {item_name}Values = [{}] as const;",
+        enum_options
+            .iter()
+            .map(|v| format!("\"{v}\""))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    #[cfg(not(feature = "enum_consts"))]
+    let values_declaration = {
+        let _ = enum_options;
+        String::new()
+    };
+
     #[cfg(feature = "typescript")]
     {
+        let default_export = typescript_default_export(item_name, options);
+
         // TypeScript type generation (only available when typescript feature is enabled)
         let typescript_type_gen = quote::quote! {
-            format!(r#"/**\n{}\n**/\nexport type {} = {};"#, docs, #item_name, #type_code)
+            format!(r#"/**\n{}\n**/\n{}{}"#, docs, #declaration, #values_declaration)
         };
 
-        // Conditional JSON schema docs
+        // JSON schema docs, decided here rather than by a `cfg` in the generated code, which
+        // would test the features of the crate using the macro
+        #[cfg(all(feature = "jsonschema", feature = "zod"))]
         let json_docs_gen = quote::quote! {
-            #[cfg(all(feature = "jsonschema", feature = "zod"))]
-            let prettified = serde_json::to_string_pretty(&Self::json_schema()).unwrap().lines().map(|l| format!(" * {l}")).collect::<Vec<_>>().join("\n");
-
-            #[cfg(all(feature = "jsonschema", feature = "zod"))]
+            let prettified = serde_json::to_string_pretty(&Self::#json_schema()).unwrap().lines().map(|l| format!(" * {l}")).collect::<Vec<_>>().join("\n");
             let docs = format!("/**\n{}\n * JSON Schema:\n{}\n **/\n", #docs, prettified);
+        };
 
-            #[cfg(not(all(feature = "jsonschema", feature = "zod")))]
-            let docs = format!("/**\n{}\n**/\n", #docs);
+        #[cfg(not(all(feature = "jsonschema", feature = "zod")))]
+        let json_docs_gen = {
+            let _ = json_schema;
+            quote::quote! {
+                let docs = format!("/**\n{}\n**/\n", #docs);
+            }
         };
 
         quote::quote! {
-            pub fn ts_definition() -> String {
+            pub fn #ts_definition() -> String {
                 #json_docs_gen
-                #typescript_type_gen
+                let definition = #typescript_type_gen;
+                #default_export
+                definition
             }
         }
     }
@@ -1591,15 +3103,22 @@ fn generate_plain_enum_ts_definition_method(
 fn generate_plain_enum_zod_schema_method(
     item_name: &str,
     schema_code: &str,
+    method_names: &MethodNames,
+    options: &CodegenOptions,
 ) -> proc_macro2::TokenStream {
+    let zod_schema = &method_names.zod_schema;
+    let default_export = zod_default_export(item_name, options);
+
     #[cfg(feature = "zod")]
     {
         // When typescript feature is enabled, generate TypeScript-style Zod schema
         #[cfg(feature = "typescript")]
         {
             quote::quote! {
-                pub fn zod_schema() -> String {
-                    format!(r#"export const {}$Schema: ZodType<{}> = z.enum([{}]);"#, #item_name, #item_name, #schema_code)
+                pub fn #zod_schema() -> String {
+                    let schema = format!(r#"export const {}$Schema: ZodType<{}> = {};"#, #item_name, #item_name, #schema_code);
+                    #default_export
+                    schema
                 }
             }
         }
@@ -1608,8 +3127,10 @@ fn generate_plain_enum_zod_schema_method(
         #[cfg(not(feature = "typescript"))]
         {
             quote::quote! {
-                pub fn zod_schema() -> String {
-                    format!(r#"export const {}$Schema = z.enum([{}]);"#, #item_name, #schema_code)
+                pub fn #zod_schema() -> String {
+                    let schema = format!(r#"export const {}$Schema = {};"#, #item_name, #schema_code);
+                    #default_export
+                    schema
                 }
             }
         }
@@ -1629,40 +3150,72 @@ fn generate_plain_enum_zod_schema_method(
 /// Generates the JSON schema method for discriminated enums conditionally
 fn generate_discriminated_enum_json_schema_method(
     main_schema_code: &proc_macro2::TokenStream,
+    method_names: &MethodNames,
+    item_name: &str,
 ) -> proc_macro2::TokenStream {
-    quote::quote! {
-        pub fn json_schema() -> serde_json::Value {
-            #main_schema_code
-        }
-    }
+    crate::features::jsonschema::json_schema_methods(&method_names.json_schema, item_name, main_schema_code.clone())
 }
 
 #[cfg(feature = "typescript")]
 /// Generates the TypeScript definition method for discriminated enums (TypeScript types only)
+///
+/// With the `union_helpers` feature, an `{Name}Map` type mapping each tag value to its
+/// variant shape is appended after the union. Untagged enums pass no variant types, as there is
+/// no tag to key it by.
 fn generate_discriminated_enum_ts_definition_method(
     docs: &str,
     item_name: &str,
     type_code: &str,
+    variant_types: Option<&[(String, String)]>,
+    method_names: &MethodNames,
+    options: &CodegenOptions,
 ) -> proc_macro2::TokenStream {
+    let MethodNames { ts_definition, json_schema, .. } = method_names;
+
     #[cfg(feature = "typescript")]
     {
-        // Conditional JSON schema docs
-        let json_docs_gen = quote::quote! {
-            #[cfg(all(feature = "jsonschema", feature = "zod"))]
-            let prettified = serde_json::to_string_pretty(&Self::json_schema()).unwrap().lines().map(|l| format!(" * {l}")).collect::<Vec<_>>().join("\n");
+        let default_export = typescript_default_export(item_name, options);
 
-            #[cfg(all(feature = "jsonschema", feature = "zod"))]
+        // JSON schema docs, chosen at expansion time as for plain enums
+        #[cfg(all(feature = "jsonschema", feature = "zod"))]
+        let json_docs_gen = quote::quote! {
+            let prettified = serde_json::to_string_pretty(&Self::#json_schema()).unwrap().lines().map(|l| format!(" * {l}")).collect::<Vec<_>>().join("\n");
             let docs = format!("/**\n{}\n * JSON Schema:\n{}\n **/\n", #docs, prettified);
+        };
+
+        #[cfg(not(all(feature = "jsonschema", feature = "zod")))]
+        let json_docs_gen = {
+            let _ = json_schema;
+            quote::quote! {
+                let docs = format!("/**\n{}\n**/\n", #docs);
+            }
+        };
+
+        #[cfg(feature = "union_helpers")]
+        let variant_map_code = variant_types.map_or_else(String::new, |variant_types| {
+            format!(
+                "\nexport type {item_name}Map = {{\n{}\n}};",
+                variant_types
+                    .iter()
+                    .map(|(tag_value, variant_type_code)| format!("  \"{tag_value}\": {variant_type_code};"))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            )
+        });
 
-            #[cfg(not(all(feature = "jsonschema", feature = "zod")))]
-            let docs = format!("/**\n{}\n**/\n", #docs);
+        #[cfg(not(feature = "union_helpers"))]
+        let variant_map_code = {
+            let _ = variant_types;
+            String::new()
         };
 
         quote::quote! {
-            pub fn ts_definition() -> String {
+            pub fn #ts_definition() -> String {
                 #json_docs_gen
                 let bundled_docs = docs;
-                format!(r#"{bundled_docs}export type {} = {};"#, #item_name, #type_code)
+                let definition = format!(r#"{bundled_docs}export type {} = {};{}"#, #item_name, #type_code, #variant_map_code);
+                #default_export
+                definition
             }
         }
     }
@@ -1682,15 +3235,22 @@ fn generate_discriminated_enum_ts_definition_method(
 fn generate_discriminated_enum_zod_schema_method(
     item_name: &str,
     schema_code: &str,
+    method_names: &MethodNames,
+    options: &CodegenOptions,
 ) -> proc_macro2::TokenStream {
+    let zod_schema = &method_names.zod_schema;
+    let default_export = zod_default_export(item_name, options);
+
     #[cfg(feature = "zod")]
     {
         // When typescript feature is enabled, generate TypeScript-style Zod schema
         #[cfg(feature = "typescript")]
         {
             quote::quote! {
-                pub fn zod_schema() -> String {
-                    format!(r#"export const {}$Schema: ZodType<{}> = {};"#, #item_name, #item_name, #schema_code)
+                pub fn #zod_schema() -> String {
+                    let schema = format!(r#"export const {}$Schema: ZodType<{}> = {};"#, #item_name, #item_name, #schema_code);
+                    #default_export
+                    schema
                 }
             }
         }
@@ -1699,8 +3259,10 @@ fn generate_discriminated_enum_zod_schema_method(
         #[cfg(not(feature = "typescript"))]
         {
             quote::quote! {
-                pub fn zod_schema() -> String {
-                    format!(r#"export const {}$Schema = {};"#, #item_name, #schema_code)
+                pub fn #zod_schema() -> String {
+                    let schema = format!(r#"export const {}$Schema = {};"#, #item_name, #schema_code);
+                    #default_export
+                    schema
                 }
             }
         }
@@ -0,0 +1,130 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{
+    Ident, Path, Token, Visibility,
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+};
+
+#[cfg(any(feature = "typescript", feature = "zod", feature = "jsonschema"))]
+use crate::safe_type_name;
+#[cfg(any(feature = "typescript", feature = "zod", feature = "jsonschema"))]
+use crate::features::model_schema_args::ModelSchemaArgs;
+
+/// Parsed input of `model_schema_alias!(AccountJson = UserJson)`.
+struct AliasInput {
+    vis: Visibility,
+    name: Ident,
+    target: Path,
+}
+
+impl Parse for AliasInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let vis = input.parse()?;
+        let name = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let target = input.parse()?;
+        Ok(AliasInput { vis, name, target })
+    }
+}
+
+/// Executes the model_schema_alias macro, exposing an already generated schema under another name.
+///
+/// Like `model_schema_union!`, the alias is emitted as an uninhabited enum, since inherent
+/// methods can't be added to a Rust `type` alias without clashing with the target's own.
+pub(crate) fn exec_model_schema_alias(input: TokenStream) -> TokenStream {
+    let AliasInput { vis, name, target } = parse_macro_input!(input as AliasInput);
+
+    #[cfg(any(feature = "typescript", feature = "zod", feature = "jsonschema"))]
+    let item_name = safe_type_name(&name.to_string());
+
+    #[cfg(any(feature = "typescript", feature = "zod"))]
+    let target_name = {
+        let ident = target.segments.last().map(|s| s.ident.to_string()).unwrap_or_default();
+        safe_type_name(&ident)
+    };
+
+    #[cfg(feature = "typescript")]
+    let ts_definition_method = {
+        let docs = format!(" * {name}\n * ");
+        quote! {
+            pub fn ts_definition() -> String {
+                format!("/**\n{}\n**/\nexport type {} = {};", #docs, #item_name, #target_name)
+            }
+        }
+    };
+
+    #[cfg(feature = "zod")]
+    let zod_schema_method = {
+        let schema_code = format!("{target_name}$Schema");
+
+        #[cfg(feature = "typescript")]
+        {
+            quote! {
+                pub fn zod_schema() -> String {
+                    format!(r#"export const {}$Schema: ZodType<{}> = {};"#, #item_name, #item_name, #schema_code)
+                }
+            }
+        }
+
+        #[cfg(not(feature = "typescript"))]
+        {
+            quote! {
+                pub fn zod_schema() -> String {
+                    format!(r#"export const {}$Schema = {};"#, #item_name, #schema_code)
+                }
+            }
+        }
+    };
+
+    #[cfg(any(feature = "typescript", feature = "zod"))]
+    let schema_dependencies_method = quote! {
+        pub fn schema_dependencies() -> Vec<&'static str> {
+            vec![#target_name]
+        }
+    };
+
+    #[cfg(feature = "zod")]
+    let zod_import_method = quote! {
+        #[doc(hidden)]
+        pub fn __tixschema_zod_import() -> &'static str {
+            #target::__tixschema_zod_import()
+        }
+    };
+
+    // The alias is the target's schema, or a `$ref` to it when the target is a shared definition
+    #[cfg(feature = "jsonschema")]
+    let json_schema_method = crate::features::jsonschema::json_schema_methods(
+        &ModelSchemaArgs::default().method_names().json_schema,
+        &item_name,
+        quote! { #target::__tixschema_json_schema_ref(stack, defs) },
+    );
+
+    #[cfg(not(feature = "jsonschema"))]
+    let _ = &target;
+
+    let impl_items: Vec<proc_macro2::TokenStream> = vec![
+        #[cfg(feature = "jsonschema")]
+        json_schema_method,
+        #[cfg(feature = "typescript")]
+        ts_definition_method,
+        #[cfg(feature = "zod")]
+        zod_schema_method,
+        #[cfg(any(feature = "typescript", feature = "zod"))]
+        schema_dependencies_method,
+        #[cfg(feature = "zod")]
+        zod_import_method,
+        #[cfg(any(feature = "typescript", feature = "zod", feature = "jsonschema"))]
+        crate::model_schema::generate_method_aliases(&ModelSchemaArgs::default().method_names()),
+    ];
+
+    let output = quote! {
+        #vis enum #name {}
+
+        impl #name {
+            #(#impl_items) *
+        }
+    };
+
+    TokenStream::from(output)
+}
@@ -0,0 +1,190 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{
+    Path, Token,
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+    punctuated::Punctuated,
+};
+
+#[cfg(any(feature = "typescript", feature = "zod"))]
+use crate::safe_type_name;
+
+/// Parsed input of `model_schema_bundle!(UserJson, AddressJson, CountryJson)`.
+struct BundleInput {
+    members: Punctuated<Path, Token![,]>,
+}
+
+impl Parse for BundleInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let members = Punctuated::parse_terminated(input)?;
+        Ok(BundleInput { members })
+    }
+}
+
+/// Executes the model_schema_bundle macro, expanding to a `String` with the declarations of
+/// all listed types, deduplicated and ordered so every schema is declared before its use.
+///
+/// The dependency graph is only known at runtime (each type reports its own through
+/// `schema_dependencies()`), so the sort is emitted into the expansion: a depth-first
+/// traversal in listing order that emits a type after all of its dependencies. A dependency
+/// reached again while still being visited closes a cycle; its schema is declared later, so
+/// references to it are wrapped in `z.lazy(() => ...)` instead, calling Zod as the referencing
+/// schema does.
+pub(crate) fn exec_model_schema_bundle(input: TokenStream) -> TokenStream {
+    let BundleInput { members } = parse_macro_input!(input as BundleInput);
+
+    #[cfg(not(any(feature = "typescript", feature = "zod")))]
+    let output = {
+        let _ = &members;
+        quote! { String::new() }
+    };
+
+    #[cfg(any(feature = "typescript", feature = "zod"))]
+    let output = {
+        let entries = members.iter().map(|path| {
+            let ident = path.segments.last().map(|s| s.ident.to_string()).unwrap_or_default();
+            let item_name = safe_type_name(&ident);
+
+            #[cfg(feature = "typescript")]
+            let ts_definition = quote! { #path::__tixschema_ts_definition() };
+            #[cfg(not(feature = "typescript"))]
+            let ts_definition = quote! { String::new() };
+
+            #[cfg(feature = "zod")]
+            let (zod_schema, zod_import) =
+                (quote! { #path::__tixschema_zod_schema() }, quote! { #path::__tixschema_zod_import() });
+            #[cfg(not(feature = "zod"))]
+            let (zod_schema, zod_import) = (quote! { String::new() }, quote! { "z" });
+
+            quote! { (#item_name, #path::schema_dependencies(), #ts_definition, #zod_schema, #zod_import) }
+        });
+
+        quote! {
+            {
+                let mut entries: Vec<(&'static str, Vec<&'static str>, String, String, &'static str)> = Vec::new();
+                for entry in [#(#entries),*] {
+                    if !entries.iter().any(|e| e.0 == entry.0) {
+                        entries.push(entry);
+                    }
+                }
+                let index_of = |name: &str| entries.iter().position(|e| e.0 == name);
+
+                // 0: not visited, 1: visiting, 2: emitted
+                let mut state = vec![0u8; entries.len()];
+                let mut order: Vec<usize> = Vec::new();
+                for root in 0..entries.len() {
+                    if state[root] != 0 {
+                        continue;
+                    }
+                    state[root] = 1;
+                    let mut stack = vec![(root, 0usize)];
+                    while let Some((node, next)) = stack.pop() {
+                        if let Some(dep) = entries[node].1.get(next) {
+                            stack.push((node, next + 1));
+                            if let Some(dep_index) = index_of(dep)
+                                && state[dep_index] == 0
+                            {
+                                state[dep_index] = 1;
+                                stack.push((dep_index, 0));
+                            }
+                        } else {
+                            state[node] = 2;
+                            order.push(node);
+                        }
+                    }
+                }
+
+                let mut declared: Vec<&str> = Vec::new();
+                let mut declarations: Vec<String> = Vec::new();
+                for index in order {
+                    let (item_name, dependencies, ts_definition, zod_schema, zod_import) = &entries[index];
+                    let mut zod_schema = zod_schema.clone();
+                    for dep in dependencies {
+                        if declared.contains(dep) || index_of(dep).is_none() {
+                            continue;
+                        }
+                        // Part of a cycle: only the initializer references the schema
+                        let Some(start) = zod_schema.find(" = ") else {
+                            continue;
+                        };
+                        let reference = format!("{dep}$Schema");
+                        let mut body = String::new();
+                        let mut rest = &zod_schema[start..];
+                        while let Some(pos) = rest.find(&reference) {
+                            let is_boundary = rest[..pos]
+                                .chars()
+                                .next_back()
+                                .is_none_or(|c| !(c.is_alphanumeric() || c == '_' || c == '$'));
+                            body.push_str(&rest[..pos]);
+                            if is_boundary {
+                                body.push_str(&format!("{zod_import}.lazy(() => {reference})"));
+                            } else {
+                                body.push_str(&reference);
+                            }
+                            rest = &rest[pos + reference.len()..];
+                        }
+                        body.push_str(rest);
+                        zod_schema = format!("{}{body}", &zod_schema[..start]);
+                    }
+                    declared.push(item_name);
+                    declarations.push(
+                        [ts_definition.as_str(), zod_schema.as_str()]
+                            .into_iter()
+                            .filter(|v| !v.is_empty())
+                            .collect::<Vec<_>>()
+                            .join("\n"),
+                    );
+                }
+
+                declarations.join("\n\n")
+            }
+        }
+    };
+
+    TokenStream::from(output)
+}
+
+/// Executes the model_schema_index macro, expanding to a `String` with the `index.ts` barrel of
+/// a one-file-per-type layout: an `export * from "./User";` line per listed type, in listing
+/// order and deduplicated.
+///
+/// The names are known at expansion time, so the barrel is a string literal. Each type's
+/// `schema_dependencies` is still referenced, so listing a type without `#[model_schema]`
+/// fails to build.
+pub(crate) fn exec_model_schema_index(input: TokenStream) -> TokenStream {
+    let BundleInput { members } = parse_macro_input!(input as BundleInput);
+
+    #[cfg(not(any(feature = "typescript", feature = "zod")))]
+    let output = {
+        let _ = &members;
+        quote! { String::new() }
+    };
+
+    #[cfg(any(feature = "typescript", feature = "zod"))]
+    let output = {
+        let mut item_names: Vec<String> = Vec::new();
+        for path in &members {
+            let ident = path.segments.last().map(|s| s.ident.to_string()).unwrap_or_default();
+            let item_name = safe_type_name(&ident);
+            if !item_names.contains(&item_name) {
+                item_names.push(item_name);
+            }
+        }
+        let barrel = item_names
+            .iter()
+            .map(|item_name| format!("export * from \"./{item_name}\";"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let paths = members.iter();
+
+        quote! {
+            {
+                #(let _ = #paths::schema_dependencies;)*
+                String::from(#barrel)
+            }
+        }
+    };
+
+    TokenStream::from(output)
+}
@@ -0,0 +1,109 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{
+    Path, Token,
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+    punctuated::Punctuated,
+};
+
+/// Parsed input of `model_schema_components!(UserJson, AddressJson)`.
+struct ComponentsInput {
+    members: Punctuated<Path, Token![,]>,
+}
+
+impl Parse for ComponentsInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let members = Punctuated::parse_terminated(input)?;
+        Ok(ComponentsInput { members })
+    }
+}
+
+/// Executes the model_schema_components macro, expanding to the `serde_json::Value` of an
+/// OpenAPI `components.schemas` object with one entry per listed type.
+///
+/// `json_schema()` inlines nested types. Here every entry is built with the listed types in
+/// its `defs`, so a field declared with one of them is a `$ref` to its entry, and shared
+/// definitions appear once. Types that aren't listed stay inlined, unless they're recursive:
+/// those get an entry too, in place of the `$defs` of `json_schema()`.
+pub(crate) fn exec_model_schema_components(input: TokenStream) -> TokenStream {
+    let ComponentsInput { members } = parse_macro_input!(input as ComponentsInput);
+
+    #[cfg(not(feature = "jsonschema"))]
+    let output = {
+        let _ = &members;
+        quote! { serde_json::Value::Object(serde_json::Map::new()) }
+    };
+
+    #[cfg(feature = "jsonschema")]
+    let output = {
+        let entries = members.iter().map(|path| {
+            quote! {
+                (
+                    #path::__tixschema_name(),
+                    #path::__tixschema_json_schema_in
+                        as fn(&mut Vec<&'static str>, &mut serde_json::Map<String, serde_json::Value>) -> serde_json::Value,
+                )
+            }
+        });
+
+        quote! {
+            {
+                // Sorts the keys like `json_schema()` does, and points the references at the
+                // components
+                fn to_component(value: serde_json::Value) -> serde_json::Value {
+                    match value {
+                        serde_json::Value::Object(map) => {
+                            let mut entries = map.into_iter().collect::<Vec<_>>();
+                            entries.sort_by(|a, b| a.0.cmp(&b.0));
+                            serde_json::Value::Object(
+                                entries
+                                    .into_iter()
+                                    .map(|(k, v)| match (k.as_str(), v) {
+                                        ("$ref", serde_json::Value::String(reference)) => {
+                                            let name = reference.trim_start_matches("#/$defs/");
+                                            (k, serde_json::Value::String(format!("#/components/schemas/{name}")))
+                                        }
+                                        (_, v) => (k, to_component(v)),
+                                    })
+                                    .collect(),
+                            )
+                        }
+                        serde_json::Value::Array(items) => {
+                            serde_json::Value::Array(items.into_iter().map(to_component).collect())
+                        }
+                        other => other,
+                    }
+                }
+
+                let mut entries = Vec::new();
+                for entry in [#(#entries),*] {
+                    if !entries.iter().any(|e: &(&str, _)| e.0 == entry.0) {
+                        entries.push(entry);
+                    }
+                }
+
+                let mut defs = serde_json::Map::new();
+                for (name, _) in &entries {
+                    defs.insert(name.to_string(), serde_json::Value::Null);
+                }
+
+                let mut schemas = serde_json::Map::new();
+                for (name, build) in &entries {
+                    // The entry itself is built in full, only its nested types become references
+                    let schema = build(&mut vec![*name], &mut defs);
+                    schemas.insert(name.to_string(), to_component(schema));
+                }
+                // Recursive types that aren't listed get an entry of their own to reference
+                for (name, def) in defs {
+                    if !def.is_null() {
+                        schemas.insert(name, to_component(def));
+                    }
+                }
+                serde_json::Value::Object(schemas)
+            }
+        }
+    };
+
+    TokenStream::from(output)
+}
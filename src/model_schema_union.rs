@@ -0,0 +1,153 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{
+    Ident, Path, Token, Visibility,
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+    punctuated::Punctuated,
+};
+
+#[cfg(any(feature = "typescript", feature = "zod", feature = "jsonschema"))]
+use crate::safe_type_name;
+#[cfg(any(feature = "typescript", feature = "zod", feature = "jsonschema"))]
+use crate::features::model_schema_args::ModelSchemaArgs;
+
+/// Parsed input of `model_schema_union!(AllEventsJson = UserEventJson | BillingEventJson)`.
+struct UnionInput {
+    vis: Visibility,
+    name: Ident,
+    members: Punctuated<Path, Token![|]>,
+}
+
+impl Parse for UnionInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let vis = input.parse()?;
+        let name = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let members = Punctuated::parse_separated_nonempty(input)?;
+        Ok(UnionInput { vis, name, members })
+    }
+}
+
+/// Executes the model_schema_union macro, composing already generated schemas into a union.
+///
+/// The union is emitted as an uninhabited enum so it can carry the same generated methods
+/// as types annotated with `#[model_schema]`.
+pub(crate) fn exec_model_schema_union(input: TokenStream) -> TokenStream {
+    let UnionInput { vis, name, members } = parse_macro_input!(input as UnionInput);
+
+    #[cfg(any(feature = "typescript", feature = "zod", feature = "jsonschema"))]
+    let item_name = safe_type_name(&name.to_string());
+
+    #[cfg(any(feature = "typescript", feature = "zod"))]
+    let member_names = members
+        .iter()
+        .map(|path| {
+            let ident = path.segments.last().map(|s| s.ident.to_string()).unwrap_or_default();
+            safe_type_name(&ident)
+        })
+        .collect::<Vec<_>>();
+
+    #[cfg(feature = "typescript")]
+    let ts_definition_method = {
+        let docs = format!(" * {name}\n * ");
+        let type_code = member_names.join(" | ");
+        quote! {
+            pub fn ts_definition() -> String {
+                format!("/**\n{}\n**/\nexport type {} = {};", #docs, #item_name, #type_code)
+            }
+        }
+    };
+
+    // Zod is called as the first member calls it
+    #[cfg(feature = "zod")]
+    let zod_schema_method = {
+        let options_code = member_names
+            .iter()
+            .map(|v| format!("{v}$Schema"))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        #[cfg(feature = "typescript")]
+        {
+            quote! {
+                pub fn zod_schema() -> String {
+                    format!(r#"export const {}$Schema: ZodType<{}> = {}.union([{}]);"#, #item_name, #item_name, Self::__tixschema_zod_import(), #options_code)
+                }
+            }
+        }
+
+        #[cfg(not(feature = "typescript"))]
+        {
+            quote! {
+                pub fn zod_schema() -> String {
+                    format!(r#"export const {}$Schema = {}.union([{}]);"#, #item_name, Self::__tixschema_zod_import(), #options_code)
+                }
+            }
+        }
+    };
+
+    #[cfg(feature = "zod")]
+    let zod_import_method = {
+        let first = &members[0];
+        quote! {
+            #[doc(hidden)]
+            pub fn __tixschema_zod_import() -> &'static str {
+                #first::__tixschema_zod_import()
+            }
+        }
+    };
+
+    #[cfg(any(feature = "typescript", feature = "zod"))]
+    let schema_dependencies_method = quote! {
+        pub fn schema_dependencies() -> Vec<&'static str> {
+            vec![#(#member_names),*]
+        }
+    };
+
+    #[cfg(feature = "jsonschema")]
+    let json_schema_method = {
+        let member_paths = members.iter();
+        crate::features::jsonschema::json_schema_methods(
+            &ModelSchemaArgs::default().method_names().json_schema,
+            &item_name,
+            quote! {
+                let mut schema_obj = serde_json::Map::new();
+                schema_obj.insert(
+                    "oneOf".to_string(),
+                    serde_json::Value::Array(vec![#(#member_paths::__tixschema_json_schema_ref(stack, defs)),*]),
+                );
+
+                serde_json::Value::Object(schema_obj)
+            },
+        )
+    };
+
+    #[cfg(not(feature = "jsonschema"))]
+    let _ = &members;
+
+    let impl_items: Vec<proc_macro2::TokenStream> = vec![
+        #[cfg(feature = "jsonschema")]
+        json_schema_method,
+        #[cfg(feature = "typescript")]
+        ts_definition_method,
+        #[cfg(feature = "zod")]
+        zod_schema_method,
+        #[cfg(any(feature = "typescript", feature = "zod"))]
+        schema_dependencies_method,
+        #[cfg(feature = "zod")]
+        zod_import_method,
+        #[cfg(any(feature = "typescript", feature = "zod", feature = "jsonschema"))]
+        crate::model_schema::generate_method_aliases(&ModelSchemaArgs::default().method_names()),
+    ];
+
+    let output = quote! {
+        #vis enum #name {}
+
+        impl #name {
+            #(#impl_items) *
+        }
+    };
+
+    TokenStream::from(output)
+}
@@ -13,6 +13,26 @@ pub fn safe_type_name(key: &str) -> String {
 }
 
 
+/// Quotes text as a JavaScript string literal, e.g. an `enum_values` value in the generated
+/// TypeScript and Zod code, escaping quotes, backslashes and control characters.
+pub(crate) fn js_string_literal(text: &str) -> String {
+    let mut literal = String::with_capacity(text.len() + 2);
+    literal.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => literal.push_str("\\\""),
+            '\\' => literal.push_str("\\\\"),
+            '\n' => literal.push_str("\\n"),
+            '\r' => literal.push_str("\\r"),
+            '\t' => literal.push_str("\\t"),
+            c if c.is_control() => literal.push_str(&format!("\\u{:04x}", c as u32)),
+            _ => literal.push(c),
+        }
+    }
+    literal.push('"');
+    literal
+}
+
 #[cfg(feature = "typescript")]
 /// Extracts and concatenates documentation comments from a syn::ItemStruct.
 ///
@@ -92,7 +92,7 @@ mod advanced_tests {
 
     #[model_schema()]
     #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
-    #[serde(tag = "type", rename_all = "camelCase")]
+    #[serde(tag = "type", rename_all = "camelCase", rename_all_fields = "camelCase")]
     enum RetirementPlanJson {
         Option401k {
             employer_match_percentage: f32,
@@ -320,7 +320,7 @@ mod advanced_tests {
     // Test discriminated union with complex fields
     #[model_schema()]
     #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
-    #[serde(tag = "eventType", rename_all = "camelCase")]
+    #[serde(tag = "eventType", rename_all = "camelCase", rename_all_fields = "camelCase")]
     enum ComplexEventJson {
         UserRegistered {
             user_id: String,
@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod tests {
+    #[cfg(all(test, feature = "serde"))]
+    use serde::{Deserialize, Serialize};
+    #[cfg(all(
+        test,
+        any(feature = "typescript", feature = "jsonschema", feature = "zod")
+    ))]
+    use tixschema::{model_schema, model_schema_alias};
+
+    #[cfg(all(
+        test,
+        any(feature = "typescript", feature = "jsonschema", feature = "zod")
+    ))]
+    #[model_schema()]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq)]
+    struct UserJson {
+        id: String,
+        name: Option<String>,
+    }
+
+    #[cfg(all(
+        test,
+        any(feature = "typescript", feature = "jsonschema", feature = "zod")
+    ))]
+    model_schema_alias!(AccountJson = UserJson);
+
+    #[test]
+    #[cfg(feature = "typescript")]
+    fn test_alias_ts_definition() {
+        let ts_definition = AccountJson::ts_definition();
+
+        assert!(ts_definition.starts_with("/**\n"));
+        assert!(ts_definition.contains(" * AccountJson\n"));
+        assert!(ts_definition.ends_with("export type Account = User;"));
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_alias_zod_schema() {
+        let zod_schema = AccountJson::zod_schema();
+
+        assert!(zod_schema.contains("Account$Schema"));
+        assert!(zod_schema.ends_with(" = User$Schema;"));
+        assert!(UserJson::zod_schema().contains("export const User$Schema"));
+    }
+
+    #[test]
+    #[cfg(all(feature = "typescript", feature = "zod"))]
+    fn test_alias_zod_schema_typed() {
+        assert_eq!(
+            AccountJson::zod_schema(),
+            "export const Account$Schema: ZodType<Account> = User$Schema;"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_alias_json_schema_delegates_to_target() {
+        assert_eq!(AccountJson::json_schema(), UserJson::json_schema());
+    }
+}
@@ -3,7 +3,7 @@ mod tests {
 
     #[cfg(all(test, feature = "serde"))]
     use serde::{Deserialize, Serialize};
-    #[cfg(all(test, any(feature = "jsonschema")))]
+    #[cfg(all(test, feature = "jsonschema"))]
     use serde_json::Value;
     #[cfg(all(
         test,
@@ -210,4 +210,426 @@ mod tests {
         // Should generate Record<string, never> for empty structs
         assert!(ts_definition.contains("export type EmptyStruct = Record<string, never>;"));
     }
+
+    // Casing via the macro argument, without any serde attributes
+    #[cfg(all(
+        test,
+        any(feature = "typescript", feature = "jsonschema", feature = "zod")
+    ))]
+    #[derive(Debug, Clone, PartialEq)]
+    #[model_schema(rename_all = "camelCase")]
+    struct MacroCasedUser {
+        first_name: String,
+        last_login_at: Option<String>,
+    }
+
+    #[cfg(all(
+        test,
+        any(feature = "typescript", feature = "jsonschema", feature = "zod")
+    ))]
+    #[allow(dead_code)]
+    #[derive(Debug, Clone, PartialEq)]
+    #[model_schema(rename_all = "lowercase")]
+    enum MacroCasedLevel {
+        LowPriority,
+        HighPriority,
+    }
+
+    // The macro argument wins over serde's rename_all
+    #[cfg(all(test, feature = "serde"))]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "lowercase")]
+    #[model_schema(rename_all = "camelCase")]
+    struct ConflictingCaseUser {
+        display_name: String,
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_macro_rename_all_json_schema() {
+        let schema = MacroCasedUser::json_schema();
+        let properties = schema["properties"].as_object().unwrap();
+        assert!(properties.contains_key("firstName"));
+        assert!(properties.contains_key("lastLoginAt"));
+        assert!(!properties.contains_key("first_name"));
+
+        let schema = MacroCasedLevel::json_schema();
+        let values: Vec<Value> = schema["enum"].as_array().unwrap().clone();
+        assert!(values.contains(&Value::from("lowpriority")));
+        assert!(values.contains(&Value::from("highpriority")));
+    }
+
+    #[test]
+    #[cfg(feature = "typescript")]
+    fn test_macro_rename_all_ts_definition() {
+        let ts_definition = MacroCasedUser::ts_definition();
+        assert!(ts_definition.contains("firstName: string;"));
+        assert!(ts_definition.contains("lastLoginAt"));
+        assert!(!ts_definition.contains("last_login_at"));
+
+        let ts_definition = MacroCasedLevel::ts_definition();
+        assert!(ts_definition.contains("\"lowpriority\""));
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_macro_rename_all_zod_schema() {
+        let zod_schema = MacroCasedUser::zod_schema();
+        assert!(zod_schema.contains("firstName: z.string()"));
+        assert!(!zod_schema.contains("first_name"));
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde", feature = "jsonschema"))]
+    fn test_macro_rename_all_overrides_serde() {
+        let schema = ConflictingCaseUser::json_schema();
+        let properties = schema["properties"].as_object().unwrap();
+        assert!(properties.contains_key("displayName"));
+        assert!(!properties.contains_key("displayname"));
+    }
+
+    // Generated methods renamed through the macro arguments
+    #[cfg(all(
+        test,
+        any(feature = "typescript", feature = "jsonschema", feature = "zod")
+    ))]
+    #[derive(Debug, Clone, PartialEq)]
+    #[model_schema(ts_definition = "to_ts", zod_schema = "zod", json_schema = "schema")]
+    struct RenamedMethodsUser {
+        id: String,
+        count: u32,
+    }
+
+    // Hand-written methods with the default names don't collide with the generated ones
+    #[cfg(all(
+        test,
+        any(feature = "typescript", feature = "jsonschema", feature = "zod")
+    ))]
+    impl RenamedMethodsUser {
+        fn ts_definition() -> &'static str {
+            "custom"
+        }
+    }
+
+    #[cfg(all(
+        test,
+        any(feature = "typescript", feature = "jsonschema", feature = "zod")
+    ))]
+    #[allow(dead_code)]
+    #[derive(Debug, Clone, PartialEq)]
+    #[model_schema(ts_definition = "to_ts", zod_schema = "zod", json_schema = "schema")]
+    enum RenamedMethodsLevel {
+        Low,
+        High,
+    }
+
+    #[cfg(all(
+        test,
+        any(feature = "typescript", feature = "jsonschema", feature = "zod")
+    ))]
+    #[allow(dead_code)]
+    #[derive(Debug, Clone, PartialEq)]
+    #[model_schema(ts_definition = "to_ts", zod_schema = "zod", json_schema = "schema")]
+    enum RenamedMethodsEvent {
+        Created { id: String },
+        Deleted { id: String },
+    }
+
+    #[test]
+    #[cfg(feature = "typescript")]
+    fn test_renamed_ts_definition_method() {
+        assert!(RenamedMethodsUser::to_ts().contains("export type RenamedMethodsUser = {"));
+        assert_eq!(RenamedMethodsUser::ts_definition(), "custom");
+        assert!(RenamedMethodsLevel::to_ts().contains("export type RenamedMethodsLevel = "));
+        assert!(RenamedMethodsEvent::to_ts().contains("export type RenamedMethodsEvent = "));
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_renamed_zod_schema_method() {
+        assert!(RenamedMethodsUser::zod().contains("export const RenamedMethodsUser$Schema"));
+        assert!(RenamedMethodsLevel::zod().contains("z.enum("));
+        assert!(RenamedMethodsEvent::zod().contains("z.discriminatedUnion(\"type\""));
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_renamed_json_schema_method() {
+        assert_eq!(RenamedMethodsUser::schema()["type"], "object");
+        assert_eq!(RenamedMethodsLevel::schema()["type"], "string");
+        assert!(RenamedMethodsEvent::schema()["oneOf"].is_array());
+    }
+
+    #[test]
+    #[cfg(all(feature = "typescript", feature = "jsonschema"))]
+    fn test_renamed_methods_embed_json_schema_docs() {
+        assert!(RenamedMethodsUser::to_ts().contains(" * JSON Schema:"));
+    }
+
+    // The generated methods live in their own inherent `impl`, next to the user's
+    #[cfg(all(
+        test,
+        any(feature = "typescript", feature = "jsonschema", feature = "zod")
+    ))]
+    #[derive(Debug, Clone, PartialEq)]
+    #[model_schema()]
+    struct InvoiceLine {
+        quantity: u32,
+        unit_price: f64,
+    }
+
+    #[cfg(all(
+        test,
+        any(feature = "typescript", feature = "jsonschema", feature = "zod")
+    ))]
+    impl InvoiceLine {
+        fn total(&self) -> f64 {
+            f64::from(self.quantity) * self.unit_price
+        }
+
+        #[cfg(feature = "typescript")]
+        fn declaration() -> String {
+            Self::ts_definition()
+        }
+    }
+
+    #[cfg(all(
+        test,
+        any(feature = "typescript", feature = "jsonschema", feature = "zod")
+    ))]
+    #[allow(dead_code)]
+    #[derive(Debug, Clone, PartialEq)]
+    #[model_schema()]
+    enum InvoiceState {
+        Open,
+        Paid,
+    }
+
+    #[cfg(all(
+        test,
+        any(feature = "typescript", feature = "jsonschema", feature = "zod")
+    ))]
+    impl InvoiceState {
+        fn is_final(&self) -> bool {
+            matches!(self, InvoiceState::Paid)
+        }
+    }
+
+    #[test]
+    #[cfg(any(feature = "typescript", feature = "jsonschema", feature = "zod"))]
+    fn test_user_impl_blocks_coexist() {
+        let line = InvoiceLine { quantity: 3, unit_price: 2.5 };
+        assert_eq!(line.total(), 7.5);
+        assert!(InvoiceState::Paid.is_final());
+        assert!(!InvoiceState::Open.is_final());
+
+        #[cfg(feature = "typescript")]
+        {
+            assert_eq!(InvoiceLine::declaration(), InvoiceLine::ts_definition());
+            assert!(InvoiceState::ts_definition().contains("export type InvoiceState ="));
+        }
+    }
+
+    // Optional fields rendered as absent keys, as with `serde_with::skip_serializing_none`
+    #[cfg(all(
+        test,
+        any(feature = "typescript", feature = "jsonschema", feature = "zod")
+    ))]
+    #[derive(Debug, Clone, PartialEq)]
+    #[model_schema(optionals_absent)]
+    struct AbsentOptionalsProfile {
+        name: String,
+        nickname: Option<String>,
+        tags: Option<Vec<String>>,
+    }
+
+    #[cfg(all(
+        test,
+        any(feature = "typescript", feature = "jsonschema", feature = "zod")
+    ))]
+    #[derive(Debug, Clone, PartialEq)]
+    #[model_schema()]
+    struct UndefinedOptionalsProfile {
+        name: String,
+        nickname: Option<String>,
+        tags: Option<Vec<String>>,
+    }
+
+    #[cfg(all(
+        test,
+        any(feature = "typescript", feature = "jsonschema", feature = "zod")
+    ))]
+    #[allow(dead_code)]
+    #[derive(Debug, Clone, PartialEq)]
+    #[model_schema(optionals_absent)]
+    enum AbsentOptionalsEvent {
+        Renamed { from: String, note: Option<String> },
+    }
+
+    #[test]
+    #[cfg(feature = "typescript")]
+    fn test_optionals_absent_ts_definition() {
+        let absent = AbsentOptionalsProfile::ts_definition();
+        assert!(absent.contains("name: string;"));
+        assert!(absent.contains("nickname?: string;"));
+        assert!(absent.contains("tags?: Array<string>;"));
+        assert!(!absent.contains("undefined"));
+
+        let undefined = UndefinedOptionalsProfile::ts_definition();
+        assert!(undefined.contains("nickname: string | undefined;"));
+        assert!(undefined.contains("tags: Array<string> | undefined;"));
+        assert!(!undefined.contains("nickname?:"));
+
+        assert!(AbsentOptionalsEvent::ts_definition().contains("note?: string;"));
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_optionals_absent_zod_schema() {
+        let absent = AbsentOptionalsProfile::zod_schema();
+        assert!(absent.contains("name: z.string(),"));
+        assert!(absent.contains("nickname: z.string().optional(),"));
+        assert!(absent.contains("tags: z.array(z.string()).optional(),"));
+        assert!(!absent.contains("z.undefined()"));
+
+        let undefined = UndefinedOptionalsProfile::zod_schema();
+        assert!(undefined.contains("nickname: z.string().or(z.undefined()),"));
+        assert!(!undefined.contains(".optional()"));
+
+        assert!(AbsentOptionalsEvent::zod_schema().contains("note: z.string().optional(),"));
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_optionals_absent_json_schema_matches_default() {
+        // Optional fields are left out of `required` either way
+        assert_eq!(
+            AbsentOptionalsProfile::json_schema()["required"],
+            serde_json::json!(["name"])
+        );
+        assert_eq!(
+            AbsentOptionalsProfile::json_schema()["properties"],
+            UndefinedOptionalsProfile::json_schema()["properties"]
+        );
+    }
+
+    // The same choice spelled out with `optional_style`, for `exactOptionalPropertyTypes` codebases
+    #[cfg(all(
+        test,
+        any(feature = "typescript", feature = "jsonschema", feature = "zod")
+    ))]
+    #[derive(Debug, Clone, PartialEq)]
+    #[model_schema(optional_style = "question_mark")]
+    struct QuestionMarkAttendee {
+        name: String,
+        age: Option<u32>,
+        seat: Option<String>,
+    }
+
+    #[cfg(all(
+        test,
+        any(feature = "typescript", feature = "jsonschema", feature = "zod")
+    ))]
+    #[derive(Debug, Clone, PartialEq)]
+    #[model_schema(optional_style = "undefined")]
+    struct UndefinedStyleAttendee {
+        name: String,
+        age: Option<u32>,
+        seat: Option<String>,
+    }
+
+    #[test]
+    #[cfg(feature = "typescript")]
+    fn test_optional_style_ts_definition() {
+        let question_mark = QuestionMarkAttendee::ts_definition();
+        assert!(question_mark.contains("name: string;"));
+        assert!(question_mark.contains("age?: number;"));
+        assert!(question_mark.contains("seat?: string;"));
+        assert!(!question_mark.contains("undefined"));
+
+        let undefined = UndefinedStyleAttendee::ts_definition();
+        assert!(undefined.contains("age: number | undefined;"));
+        assert!(undefined.contains("seat: string | undefined;"));
+        assert!(!undefined.contains("?:"));
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_optional_style_zod_schema() {
+        let question_mark = QuestionMarkAttendee::zod_schema();
+        assert!(question_mark.contains("age: z.number().int().optional(),"));
+        assert!(question_mark.contains("seat: z.string().optional(),"));
+
+        let undefined = UndefinedStyleAttendee::zod_schema();
+        assert!(undefined.contains("age: z.number().int().or(z.undefined()),"));
+        assert!(!undefined.contains(".optional()"));
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_optional_style_json_schema() {
+        assert_eq!(QuestionMarkAttendee::json_schema()["required"], serde_json::json!(["name"]));
+        assert_eq!(UndefinedStyleAttendee::json_schema()["required"], serde_json::json!(["name"]));
+    }
+
+    // Exported names overridden with `rename`, instead of the Rust name without `Json`
+    #[cfg(all(
+        test,
+        any(feature = "typescript", feature = "jsonschema", feature = "zod")
+    ))]
+    #[derive(Debug, Clone, PartialEq)]
+    #[model_schema(rename = "Customer")]
+    struct CustomerRecordJson {
+        id: String,
+    }
+
+    #[cfg(all(
+        test,
+        any(feature = "typescript", feature = "jsonschema", feature = "zod")
+    ))]
+    #[allow(dead_code)]
+    #[derive(Debug, Clone, PartialEq)]
+    #[model_schema(rename = "Tier")]
+    enum CustomerTierJson {
+        Basic,
+        Gold,
+    }
+
+    // `tag` sets the discriminator without serde's attributes
+    #[cfg(all(
+        test,
+        any(feature = "typescript", feature = "jsonschema", feature = "zod")
+    ))]
+    #[allow(dead_code)]
+    #[derive(Debug, Clone, PartialEq)]
+    #[model_schema(rename = "CustomerEvent", tag = "kind")]
+    enum CustomerEventRecord {
+        Joined { id: String },
+        Left { id: String },
+    }
+
+    #[test]
+    #[cfg(feature = "typescript")]
+    fn test_rename_ts_definition() {
+        let customer = CustomerRecordJson::ts_definition();
+        assert!(customer.contains("export type Customer = {"));
+        assert!(!customer.contains("export type CustomerRecord"));
+
+        assert!(CustomerTierJson::ts_definition().contains("export type Tier = \"Basic\" | \"Gold\";"));
+
+        let event = CustomerEventRecord::ts_definition();
+        assert!(event.contains("export type CustomerEvent = "));
+        assert!(event.contains("kind: \"Joined\";"));
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_rename_zod_schema() {
+        assert!(CustomerRecordJson::zod_schema().contains("export const Customer$Schema"));
+        assert!(CustomerTierJson::zod_schema().contains("export const Tier$Schema"));
+
+        let event = CustomerEventRecord::zod_schema();
+        assert!(event.contains("export const CustomerEvent$Schema"));
+        assert!(event.contains("z.discriminatedUnion(\"kind\""));
+    }
 }
@@ -0,0 +1,204 @@
+#[cfg(test)]
+mod tests {
+    #[cfg(all(test, feature = "serde"))]
+    use serde::{Deserialize, Serialize};
+    #[cfg(all(test, any(feature = "typescript", feature = "zod")))]
+    use tixschema::{model_schema, model_schema_alias, model_schema_bundle, model_schema_index};
+
+    // Dependency chain: A -> B -> C
+    #[cfg(all(test, any(feature = "typescript", feature = "zod")))]
+    #[model_schema()]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq)]
+    struct AJson {
+        b: BJson,
+    }
+
+    #[cfg(all(test, any(feature = "typescript", feature = "zod")))]
+    #[model_schema()]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq)]
+    struct BJson {
+        items: Vec<CJson>,
+    }
+
+    #[cfg(all(test, any(feature = "typescript", feature = "zod")))]
+    #[model_schema()]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq)]
+    struct CJson {
+        value: String,
+    }
+
+    // Mutual recursion: Parent -> Child -> Parent
+    #[cfg(all(test, any(feature = "typescript", feature = "zod")))]
+    #[model_schema()]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq)]
+    struct ParentJson {
+        child: Option<Box<ChildJson>>,
+    }
+
+    #[cfg(all(test, any(feature = "typescript", feature = "zod")))]
+    #[model_schema()]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq)]
+    struct ChildJson {
+        parent: Option<Box<ParentJson>>,
+    }
+
+    // Self recursion
+    #[cfg(all(test, any(feature = "typescript", feature = "zod")))]
+    #[model_schema()]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq)]
+    struct TreeNodeJson {
+        children: Vec<TreeNodeJson>,
+    }
+
+    // A recursive type nested in another one
+    #[cfg(all(test, any(feature = "typescript", feature = "zod")))]
+    #[model_schema()]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq)]
+    struct ForestJson {
+        trees: Vec<TreeNodeJson>,
+        parent: ParentJson,
+    }
+
+    #[cfg(all(test, any(feature = "typescript", feature = "zod")))]
+    model_schema_alias!(RootJson = AJson);
+
+    #[cfg(any(feature = "typescript", feature = "zod"))]
+    fn position(bundle: &str, needle: &str) -> usize {
+        bundle
+            .find(needle)
+            .unwrap_or_else(|| panic!("`{needle}` not found in bundle:\n{bundle}"))
+    }
+
+    #[test]
+    #[cfg(any(feature = "typescript", feature = "zod"))]
+    fn test_schema_dependencies() {
+        assert_eq!(AJson::schema_dependencies(), vec!["B"]);
+        assert_eq!(BJson::schema_dependencies(), vec!["C"]);
+        assert!(CJson::schema_dependencies().is_empty());
+        assert_eq!(RootJson::schema_dependencies(), vec!["A"]);
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_bundle_sorts_dependency_chain() {
+        let bundle = model_schema_bundle!(AJson, BJson, CJson);
+
+        let c = position(&bundle, "export const C$Schema");
+        let b = position(&bundle, "export const B$Schema");
+        let a = position(&bundle, "export const A$Schema");
+        assert!(c < b && b < a, "expected C, B, A order:\n{bundle}");
+        assert!(!bundle.contains("z.lazy"));
+    }
+
+    #[test]
+    #[cfg(feature = "typescript")]
+    fn test_bundle_sorts_type_definitions() {
+        let bundle = model_schema_bundle!(AJson, BJson, CJson);
+
+        let c = position(&bundle, "export type C =");
+        let b = position(&bundle, "export type B =");
+        let a = position(&bundle, "export type A =");
+        assert!(c < b && b < a, "expected C, B, A order:\n{bundle}");
+    }
+
+    #[test]
+    #[cfg(any(feature = "typescript", feature = "zod"))]
+    fn test_bundle_deduplicates() {
+        assert_eq!(
+            model_schema_bundle!(CJson, AJson, CJson, BJson, AJson),
+            model_schema_bundle!(CJson, BJson, AJson)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_bundle_wraps_cycles_in_lazy() {
+        let bundle = model_schema_bundle!(ParentJson, ChildJson);
+
+        // Child is declared first, so its reference back to Parent has to be lazy
+        let child = position(&bundle, "export const Child$Schema");
+        let parent = position(&bundle, "export const Parent$Schema");
+        assert!(child < parent);
+        assert!(bundle.contains("z.lazy(() => Parent$Schema)"));
+        assert!(!bundle.contains("z.lazy(() => Child$Schema)"));
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_bundle_wraps_self_reference_in_lazy() {
+        assert_eq!(TreeNodeJson::schema_dependencies(), vec!["TreeNode"]);
+
+        let bundle = model_schema_bundle!(TreeNodeJson);
+
+        assert!(bundle.contains("export const TreeNode$Schema"));
+        assert!(bundle.contains("z.lazy(() => TreeNode$Schema)"));
+        assert!(!bundle.contains("z.lazy(() => TreeNode$Schema):"));
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_bundle_leaves_external_references_eager() {
+        let bundle = model_schema_bundle!(AJson);
+
+        assert!(bundle.contains("B$Schema"));
+        assert!(!bundle.contains("z.lazy"));
+    }
+
+    #[test]
+    #[cfg(any(feature = "typescript", feature = "zod"))]
+    fn test_index_reexports_each_type() {
+        let index = model_schema_index!(AJson, BJson, CJson);
+
+        assert_eq!(
+            index,
+            "export * from \"./A\";\nexport * from \"./B\";\nexport * from \"./C\";"
+        );
+    }
+
+    #[test]
+    #[cfg(any(feature = "typescript", feature = "zod"))]
+    fn test_index_deduplicates() {
+        assert_eq!(model_schema_index!(CJson, AJson, CJson, AJson), model_schema_index!(CJson, AJson));
+        assert_eq!(model_schema_index!(RootJson).lines().count(), 1);
+    }
+
+    #[test]
+    #[cfg(all(feature = "typescript", feature = "jsonschema"))]
+    fn test_recursive_types_embed_their_json_schema() {
+        assert!(TreeNodeJson::ts_definition().contains("export type TreeNode ="));
+        assert!(ParentJson::ts_definition().contains(" * JSON Schema:\n"));
+        assert!(model_schema_bundle!(ParentJson, ChildJson).contains("export type Child ="));
+    }
+
+    #[test]
+    #[cfg(all(feature = "jsonschema", any(feature = "typescript", feature = "zod")))]
+    fn test_recursive_json_schema_references_the_root() {
+        let schema = TreeNodeJson::json_schema();
+        assert_eq!(schema["properties"]["children"]["items"], serde_json::json!({ "$ref": "#" }));
+        assert!(schema.get("$defs").is_none());
+
+        let schema = ParentJson::json_schema();
+        let child = &schema["properties"]["child"];
+        assert_eq!(child["properties"]["parent"], serde_json::json!({ "$ref": "#" }));
+    }
+
+    #[test]
+    #[cfg(all(feature = "jsonschema", any(feature = "typescript", feature = "zod")))]
+    fn test_nested_recursive_json_schema_uses_defs() {
+        let schema = ForestJson::json_schema();
+        let reference = |name: &str| serde_json::json!({ "$ref": format!("#/$defs/{name}") });
+
+        assert_eq!(schema["properties"]["trees"]["items"], reference("TreeNode"));
+        assert_eq!(schema["$defs"]["TreeNode"]["properties"]["children"]["items"], reference("TreeNode"));
+        assert_eq!(schema["properties"]["parent"], reference("Parent"));
+        assert_eq!(schema["$defs"]["Parent"]["properties"]["child"]["properties"]["parent"], reference("Parent"));
+        assert_eq!(schema["$defs"].as_object().unwrap().keys().collect::<Vec<_>>(), vec!["Parent", "TreeNode"]);
+    }
+}
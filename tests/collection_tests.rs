@@ -1,11 +1,17 @@
 use tixschema::model_schema;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, LinkedList, VecDeque};
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    // The Zod schema of u64 / i64 values, limited to the JS safe-integer range by `safe_ints`
+    #[cfg(all(feature = "zod", feature = "safe_ints"))]
+    const WIDE_INT_ZOD: &str = "z.number().int().safe()";
+    #[cfg(all(feature = "zod", not(feature = "safe_ints")))]
+    const WIDE_INT_ZOD: &str = "z.number().int()";
+
     // Test struct with collections
     #[model_schema()]
     #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
@@ -143,15 +149,15 @@ mod tests {
         // Test Zod schema generation for simple values - now in separate method
         let zod_schema = ComprehensiveHashMapTestJson::zod_schema();
         assert!(zod_schema.contains("string_to_string: z.record(z.string(), z.string())"));
-        assert!(zod_schema.contains("string_to_u64: z.record(z.string(), z.number().int())"));
-        assert!(zod_schema.contains("string_to_i64: z.record(z.string(), z.number().int())"));
+        assert!(zod_schema.contains(&format!("string_to_u64: z.record(z.string(), {WIDE_INT_ZOD})")));
+        assert!(zod_schema.contains(&format!("string_to_i64: z.record(z.string(), {WIDE_INT_ZOD})")));
         assert!(zod_schema.contains("string_to_f64: z.record(z.string(), z.number())"));
         assert!(zod_schema.contains("string_to_bool: z.record(z.string(), z.boolean())"));
         
         // Test Zod schema generation for array values  
         assert!(zod_schema.contains("string_to_string_array: z.record(z.string(), z.array(z.string()))"));
-        assert!(zod_schema.contains("string_to_u64_array: z.record(z.string(), z.array(z.number().int()))"));
-        assert!(zod_schema.contains("string_to_i64_array: z.record(z.string(), z.array(z.number().int()))"));
+        assert!(zod_schema.contains(&format!("string_to_u64_array: z.record(z.string(), z.array({WIDE_INT_ZOD}))")));
+        assert!(zod_schema.contains(&format!("string_to_i64_array: z.record(z.string(), z.array({WIDE_INT_ZOD}))")));
         assert!(zod_schema.contains("string_to_f64_array: z.record(z.string(), z.array(z.number()))"));
         assert!(zod_schema.contains("string_to_bool_array: z.record(z.string(), z.array(z.boolean()))"));
     }
@@ -199,8 +205,533 @@ mod tests {
         
         // Check Zod schema - now in separate method
         let zod_schema = HashMapWith64BitJson::zod_schema();
-        assert!(zod_schema.contains("u64_map: z.record(z.string(), z.number().int())"));
-        assert!(zod_schema.contains("i64_map: z.record(z.string(), z.number().int())"));
-        assert!(zod_schema.contains("mixed_map: z.record(z.string(), z.array(z.number().int()))"));
+        assert!(zod_schema.contains(&format!("u64_map: z.record(z.string(), {WIDE_INT_ZOD})")));
+        assert!(zod_schema.contains(&format!("i64_map: z.record(z.string(), {WIDE_INT_ZOD})")));
+        assert!(zod_schema.contains(&format!("mixed_map: z.record(z.string(), z.array({WIDE_INT_ZOD}))")));
+    }
+
+    // Test smart pointers wrapping slices, which serialize as plain arrays
+    #[model_schema()]
+    #[derive(Debug, Clone, PartialEq)]
+    struct SlicePointersJson {
+        ids: std::sync::Arc<[u32]>,
+        names: Box<[String]>,
+        flags: std::rc::Rc<[bool]>,
+        scores: Option<Box<[f64]>>,
+    }
+
+    #[test]
+    #[cfg(feature = "typescript")]
+    fn test_slice_pointers_ts_definition() {
+        let ts_definition = SlicePointersJson::ts_definition();
+
+        assert!(ts_definition.contains("ids: Array<number>;"));
+        assert!(ts_definition.contains("names: Array<string>;"));
+        assert!(ts_definition.contains("flags: Array<boolean>;"));
+        assert!(ts_definition.contains("scores: Array<number> | undefined;"));
+        assert!(!ts_definition.contains("Arc"));
+        assert!(!ts_definition.contains("Box"));
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_slice_pointers_zod_schema() {
+        let zod_schema = SlicePointersJson::zod_schema();
+
+        assert!(zod_schema.contains("ids: z.array(z.number().int()),"));
+        assert!(zod_schema.contains("names: z.array(z.string()),"));
+        assert!(zod_schema.contains("flags: z.array(z.boolean()),"));
+        assert!(zod_schema.contains("scores: z.array(z.number()).or(z.undefined()),"));
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_slice_pointers_json_schema() {
+        let schema = SlicePointersJson::json_schema();
+        let properties = schema["properties"].as_object().unwrap();
+
+        assert_eq!(properties["ids"]["type"], "array");
+        assert_eq!(properties["ids"]["items"]["type"], "integer");
+        assert_eq!(properties["names"]["type"], "array");
+        assert_eq!(properties["names"]["items"]["type"], "string");
+        assert_eq!(properties["flags"]["items"]["type"], "boolean");
+        assert_eq!(properties["scores"]["items"]["type"], "number");
+    }
+
+    #[cfg(feature = "bytes")]
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    struct PayloadJson {
+        body: bytes::Bytes,
+        buffer: bytes::BytesMut,
+        checksum: Option<bytes::Bytes>,
+    }
+
+    #[test]
+    #[cfg(all(feature = "bytes", feature = "typescript"))]
+    fn test_bytes_ts_definition() {
+        let ts_definition = PayloadJson::ts_definition();
+
+        assert!(ts_definition.contains("body: Array<number>;"));
+        assert!(ts_definition.contains("buffer: Array<number>;"));
+        assert!(ts_definition.contains("checksum: Array<number> | undefined;"));
+        assert!(!ts_definition.contains("Bytes"));
+    }
+
+    #[test]
+    #[cfg(all(feature = "bytes", feature = "zod"))]
+    fn test_bytes_zod_schema() {
+        let zod_schema = PayloadJson::zod_schema();
+
+        assert!(zod_schema.contains("body: z.array(z.number().int()),"));
+        assert!(zod_schema.contains("checksum: z.array(z.number().int()).or(z.undefined()),"));
+    }
+
+    #[test]
+    #[cfg(all(feature = "bytes", feature = "jsonschema"))]
+    fn test_bytes_json_schema_matches_serde_output() {
+        let schema = PayloadJson::json_schema();
+        let properties = schema["properties"].as_object().unwrap();
+
+        assert_eq!(properties["body"]["type"], "array");
+        assert_eq!(properties["body"]["items"]["type"], "integer");
+        assert_eq!(properties["buffer"]["type"], "array");
+
+        let payload = PayloadJson {
+            body: bytes::Bytes::from_static(&[1, 2, 3]),
+            buffer: bytes::BytesMut::from(&[4u8][..]),
+            checksum: None,
+        };
+        let value = serde_json::to_value(&payload).unwrap();
+        assert_eq!(value["body"], serde_json::json!([1, 2, 3]));
+        assert_eq!(value["buffer"], serde_json::json!([4]));
+    }
+
+    // Test other sequence collections, which serialize exactly like Vec
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    struct QueueJson {
+        pending: VecDeque<u32>,
+        history: LinkedList<String>,
+        retries: Option<VecDeque<u8>>,
+    }
+
+    #[test]
+    #[cfg(feature = "typescript")]
+    fn test_sequence_collections_ts_definition() {
+        let ts_definition = QueueJson::ts_definition();
+
+        assert!(ts_definition.contains("pending: Array<number>;"));
+        assert!(ts_definition.contains("history: Array<string>;"));
+        assert!(ts_definition.contains("retries: Array<number> | undefined;"));
+        assert!(!ts_definition.contains("VecDeque"));
+        assert!(!ts_definition.contains("LinkedList"));
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_sequence_collections_zod_schema() {
+        let zod_schema = QueueJson::zod_schema();
+
+        assert!(zod_schema.contains("pending: z.array(z.number().int()),"));
+        assert!(zod_schema.contains("history: z.array(z.string()),"));
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_sequence_collections_json_schema() {
+        let schema = QueueJson::json_schema();
+        let properties = schema["properties"].as_object().unwrap();
+
+        assert_eq!(properties["pending"]["type"], "array");
+        assert_eq!(properties["pending"]["items"]["type"], "integer");
+        assert_eq!(properties["history"]["type"], "array");
+        assert_eq!(properties["history"]["items"]["type"], "string");
+
+        let queue = QueueJson {
+            pending: VecDeque::from([1, 2]),
+            history: LinkedList::from(["a".to_string()]),
+            retries: None,
+        };
+        let value = serde_json::to_value(&queue).unwrap();
+        assert_eq!(value["pending"], serde_json::json!([1, 2]));
+        assert_eq!(value["history"], serde_json::json!(["a"]));
+    }
+
+    #[cfg(feature = "jsonschema")]
+    fn assert_keys_sorted(value: &serde_json::Value) {
+        match value {
+            serde_json::Value::Object(map) => {
+                let keys = map.keys().collect::<Vec<_>>();
+                let mut sorted = keys.clone();
+                sorted.sort();
+                assert_eq!(keys, sorted);
+                map.values().for_each(assert_keys_sorted);
+            }
+            serde_json::Value::Array(items) => items.iter().for_each(assert_keys_sorted),
+            _ => {}
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_hashmap_json_schema_output_is_stable() {
+        let first = serde_json::to_string_pretty(&ComprehensiveHashMapTestJson::json_schema()).unwrap();
+        for _ in 0..5 {
+            let again = serde_json::to_string_pretty(&ComprehensiveHashMapTestJson::json_schema()).unwrap();
+            assert_eq!(first, again);
+        }
+
+        // Keys come out sorted whether or not serde_json's `preserve_order` is enabled
+        assert_keys_sorted(&ComprehensiveHashMapTestJson::json_schema());
+        assert_keys_sorted(&SeatingChartJson::json_schema());
+    }
+
+    #[test]
+    #[cfg(all(feature = "jsonschema", feature = "typescript"))]
+    fn test_hashmap_ts_definition_docs_are_stable() {
+        assert_eq!(
+            ComprehensiveHashMapTestJson::ts_definition(),
+            ComprehensiveHashMapTestJson::ts_definition()
+        );
+        assert!(ComprehensiveHashMapTestJson::ts_definition().contains(
+            &serde_json::to_string_pretty(&ComprehensiveHashMapTestJson::json_schema())
+                .unwrap()
+                .lines()
+                .map(|l| format!(" * {l}"))
+                .collect::<Vec<_>>()
+                .join("\n")
+        ));
+    }
+
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    struct AddressJson {
+        street: String,
+        city: String,
+    }
+
+    // Maps whose values are nested model types
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    struct AddressBookJson {
+        by_name: HashMap<String, Option<AddressJson>>,
+        primary: HashMap<String, AddressJson>,
+        history: HashMap<String, Vec<AddressJson>>,
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_optional_sibling_map_value_json_schema() {
+        let schema = AddressBookJson::json_schema();
+        let properties = schema["properties"].as_object().unwrap();
+
+        assert_eq!(properties["by_name"]["type"], "object");
+        assert_eq!(
+            properties["by_name"]["additionalProperties"],
+            serde_json::json!({ "anyOf": [AddressJson::json_schema(), { "type": "null" }] })
+        );
+
+        // None values stay in the map as null
+        let book = AddressBookJson {
+            by_name: [("home".to_string(), None)].into_iter().collect(),
+            primary: HashMap::new(),
+            history: HashMap::new(),
+        };
+        assert_eq!(serde_json::to_value(&book).unwrap()["by_name"]["home"], serde_json::Value::Null);
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_sibling_map_value_json_schema() {
+        let schema = AddressBookJson::json_schema();
+        let properties = schema["properties"].as_object().unwrap();
+
+        assert_eq!(properties["primary"]["additionalProperties"], AddressJson::json_schema());
+        assert_eq!(properties["history"]["additionalProperties"]["type"], "array");
+        assert_eq!(properties["history"]["additionalProperties"]["items"], AddressJson::json_schema());
+    }
+
+    // Maps whose values are formatted strings
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    struct DeviceRegistryJson {
+        gateway: std::net::Ipv4Addr,
+        by_name: HashMap<String, std::net::Ipv4Addr>,
+        addresses: HashMap<String, std::net::IpAddr>,
+        aliases: HashMap<String, Vec<std::net::Ipv4Addr>>,
+        retired: HashMap<String, Option<std::net::Ipv4Addr>>,
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_formatted_map_value_json_schema() {
+        let schema = DeviceRegistryJson::json_schema();
+        let properties = schema["properties"].as_object().unwrap();
+        let ipv4_schema = serde_json::json!({ "type": "string", "format": "ipv4" });
+
+        assert_eq!(properties["gateway"], ipv4_schema);
+        assert_eq!(properties["by_name"]["type"], "object");
+        assert_eq!(properties["by_name"]["additionalProperties"], ipv4_schema);
+        assert_eq!(
+            properties["addresses"]["additionalProperties"],
+            serde_json::json!({
+                "type": "string",
+                "anyOf": [{ "format": "ipv4" }, { "format": "ipv6" }]
+            })
+        );
+        assert_eq!(
+            properties["aliases"]["additionalProperties"],
+            serde_json::json!({ "type": "array", "items": ipv4_schema })
+        );
+        assert_eq!(
+            properties["retired"]["additionalProperties"],
+            serde_json::json!({ "anyOf": [ipv4_schema, { "type": "null" }] })
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "typescript", feature = "zod"))]
+    fn test_formatted_map_value_ts_definition() {
+        let ts_definition = DeviceRegistryJson::ts_definition();
+        assert!(ts_definition.contains("gateway: string;"));
+        assert!(ts_definition.contains("by_name: Partial<Record<string, string>>;"));
+
+        let zod_schema = DeviceRegistryJson::zod_schema();
+        assert!(zod_schema.contains("gateway: z.ipv4(),"));
+        assert!(zod_schema.contains("by_name: z.record(z.string(), z.ipv4())"));
+        assert!(zod_schema.contains("addresses: z.record(z.string(), z.union([z.ipv4(), z.ipv6()]))"));
+        assert!(zod_schema.contains("aliases: z.record(z.string(), z.array(z.ipv4()))"));
+    }
+
+    // Ordered maps serialize like HashMap, so they get the same output
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    struct SeatingChartJson {
+        seats_by_row: std::collections::BTreeMap<String, Vec<u64>>,
+        prices: indexmap::IndexMap<String, f64>,
+        reserved: Option<std::collections::BTreeMap<String, bool>>,
+    }
+
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    struct SeatingChartHashJson {
+        seats_by_row: HashMap<String, Vec<u64>>,
+        prices: HashMap<String, f64>,
+        reserved: Option<HashMap<String, bool>>,
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_ordered_maps_json_schema() {
+        let schema = SeatingChartJson::json_schema();
+        let properties = &schema["properties"];
+
+        assert_eq!(properties["seats_by_row"]["type"], "object");
+        assert_eq!(properties["seats_by_row"]["additionalProperties"]["type"], "array");
+        assert_eq!(properties["seats_by_row"]["additionalProperties"]["items"]["type"], "integer");
+        assert_eq!(properties, &SeatingChartHashJson::json_schema()["properties"]);
+        assert_eq!(schema["required"], SeatingChartHashJson::json_schema()["required"]);
+    }
+
+    #[test]
+    #[cfg(all(feature = "typescript", feature = "zod"))]
+    fn test_ordered_maps_ts_definition() {
+        let ts_definition = SeatingChartJson::ts_definition();
+        assert!(ts_definition.contains("seats_by_row: Partial<Record<string, Array<number>>>;"));
+        assert!(ts_definition.contains("prices: Partial<Record<string, number>>;"));
+        assert!(ts_definition.contains("reserved: Partial<Record<string, boolean>> | undefined;"));
+        assert!(!ts_definition.contains("BTreeMap"));
+        assert!(!ts_definition.contains("IndexMap"));
+
+        let zod_schema = SeatingChartJson::zod_schema();
+        assert!(zod_schema.contains(&format!("seats_by_row: z.record(z.string(), z.array({WIDE_INT_ZOD}))")));
+        assert!(zod_schema.contains("prices: z.record(z.string(), z.number())"));
+        assert!(!zod_schema.contains("BTreeMap$Schema"));
+        assert_eq!(
+            zod_schema.replace("SeatingChart", "Chart"),
+            SeatingChartHashJson::zod_schema().replace("SeatingChartHash", "Chart")
+        );
+        assert!(SeatingChartJson::schema_dependencies().is_empty());
+    }
+
+    // Sequences of sequences keep every level, and sets are sequences too
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    struct SeatMapJson {
+        seats: Vec<Vec<u32>>,
+        prices: Option<Vec<Vec<f64>>>,
+        floors: Vec<Vec<Vec<bool>>>,
+        blocks: Vec<Vec<SeatingChartJson>>,
+        by_section: HashMap<String, Vec<Vec<u32>>>,
+        accessible: std::collections::HashSet<u32>,
+        zones: std::collections::BTreeSet<String>,
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_nested_sequences_json_schema() {
+        let schema = SeatMapJson::json_schema();
+        let properties = &schema["properties"];
+
+        assert_eq!(properties["seats"]["type"], "array");
+        assert_eq!(properties["seats"]["items"]["type"], "array");
+        assert_eq!(properties["seats"]["items"]["items"]["type"], "integer");
+        assert_eq!(properties["prices"]["items"]["items"]["type"], "number");
+        assert_eq!(properties["floors"]["items"]["items"]["items"]["type"], "boolean");
+        assert_eq!(properties["blocks"]["items"]["items"], SeatingChartJson::json_schema());
+        assert_eq!(properties["by_section"]["additionalProperties"], properties["seats"]);
+        assert_eq!(
+            properties["accessible"],
+            serde_json::json!({ "type": "array", "items": { "type": "integer" }, "uniqueItems": true })
+        );
+        assert_eq!(properties["zones"]["items"]["type"], "string");
+        assert!(!schema["required"].as_array().unwrap().contains(&serde_json::json!("prices")));
+    }
+
+    #[test]
+    #[cfg(all(feature = "typescript", feature = "zod"))]
+    fn test_nested_sequences_ts_definition() {
+        let ts_definition = SeatMapJson::ts_definition();
+        assert!(ts_definition.contains("seats: Array<Array<number>>;"));
+        assert!(ts_definition.contains("prices: Array<Array<number>> | undefined;"));
+        assert!(ts_definition.contains("floors: Array<Array<Array<boolean>>>;"));
+        assert!(ts_definition.contains("blocks: Array<Array<SeatingChart>>;"));
+        assert!(ts_definition.contains("accessible: Array<number>;"));
+        assert!(!ts_definition.contains("HashSet"));
+
+        let zod_schema = SeatMapJson::zod_schema();
+        assert!(zod_schema.contains("seats: z.array(z.array(z.number().int())),"));
+        assert!(zod_schema.contains("floors: z.array(z.array(z.array(z.boolean()))),"));
+        assert!(zod_schema.contains("by_section: z.record(z.string(), z.array(z.array(z.number().int()))),"));
+        assert!(zod_schema.contains("zones: z.array(z.string()),"));
+        assert_eq!(SeatMapJson::schema_dependencies(), vec!["SeatingChart"]);
+    }
+
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+    struct SeatRefJson {
+        row: u8,
+        seat: u16,
+    }
+
+    // Sets are typed as arrays, and their JSON schema requires unique items
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    struct SeatTagsJson {
+        labels: std::collections::BTreeSet<String>,
+        seats: std::collections::BTreeSet<SeatRefJson>,
+        priced: Option<std::collections::HashSet<u16>>,
+        rows: Vec<std::collections::BTreeSet<u8>>,
+        by_zone: HashMap<String, std::collections::BTreeSet<u32>>,
+        ordered: indexmap::IndexSet<String>,
+        history: Vec<String>,
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_sets_json_schema_unique_items() {
+        let schema = SeatTagsJson::json_schema();
+        let properties = &schema["properties"];
+
+        assert_eq!(
+            properties["labels"],
+            serde_json::json!({ "type": "array", "items": { "type": "string" }, "uniqueItems": true })
+        );
+        assert_eq!(properties["seats"]["items"], SeatRefJson::json_schema());
+        assert_eq!(properties["seats"]["uniqueItems"], true);
+        assert_eq!(properties["priced"]["uniqueItems"], true);
+        assert!(properties["rows"].get("uniqueItems").is_none());
+        assert_eq!(properties["rows"]["items"]["uniqueItems"], true);
+        assert_eq!(properties["by_zone"]["additionalProperties"]["uniqueItems"], true);
+        assert_eq!(properties["ordered"]["uniqueItems"], true);
+        assert!(properties["history"].get("uniqueItems").is_none());
+        assert!(!schema["required"].as_array().unwrap().contains(&serde_json::json!("priced")));
+    }
+
+    #[test]
+    #[cfg(all(feature = "typescript", feature = "zod"))]
+    fn test_sets_ts_definition() {
+        let ts_definition = SeatTagsJson::ts_definition();
+        assert!(ts_definition.contains("labels: Array<string>;"));
+        assert!(ts_definition.contains("seats: Array<SeatRef>;"));
+        assert!(ts_definition.contains("priced: Array<number> | undefined;"));
+        assert!(ts_definition.contains("rows: Array<Array<number>>;"));
+        assert!(ts_definition.contains("ordered: Array<string>;"));
+
+        let zod_schema = SeatTagsJson::zod_schema();
+        assert!(zod_schema.contains("labels: z.array(z.string()),"));
+        assert!(zod_schema.contains("seats: z.array(SeatRef$Schema),"));
+        assert!(zod_schema.contains("by_zone: z.record(z.string(), z.array(z.number().int())),"));
+    }
+
+    // A `None` inside a sequence or map is serialized as null, so only the field itself is optional
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    struct SeatHoldsJson {
+        holds: Vec<Option<u32>>,
+        checked_in: [Option<bool>; 2],
+        notes: HashMap<String, Option<String>>,
+        blocks: Vec<HashMap<String, u32>>,
+        waitlist: Option<Vec<Option<String>>>,
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_optional_elements_json_schema() {
+        let schema = SeatHoldsJson::json_schema();
+        let properties = &schema["properties"];
+
+        assert_eq!(
+            properties["holds"],
+            serde_json::json!({ "type": "array", "items": { "anyOf": [{ "type": "integer" }, { "type": "null" }] } })
+        );
+        assert_eq!(
+            properties["checked_in"],
+            serde_json::json!({ "type": "array", "items": { "anyOf": [{ "type": "boolean" }, { "type": "null" }] } })
+        );
+        assert_eq!(
+            properties["blocks"],
+            serde_json::json!({ "type": "array", "items": { "type": "object", "additionalProperties": { "type": "integer" } } })
+        );
+        assert_eq!(properties["waitlist"]["items"]["anyOf"][1], serde_json::json!({ "type": "null" }));
+        assert_eq!(
+            schema["required"],
+            serde_json::json!(["holds", "checked_in", "notes", "blocks"])
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "typescript", feature = "zod"))]
+    fn test_optional_elements_ts_definition() {
+        let ts_definition = SeatHoldsJson::ts_definition();
+        assert!(ts_definition.contains("holds: Array<number | null>;"));
+        assert!(ts_definition.contains("checked_in: Array<boolean | null>;"));
+        assert!(ts_definition.contains("notes: Partial<Record<string, string | null>>;"));
+        assert!(ts_definition.contains("blocks: Array<Partial<Record<string, number>>>;"));
+        assert!(ts_definition.contains("waitlist: Array<string | null> | undefined;"));
+
+        let zod_schema = SeatHoldsJson::zod_schema();
+        assert!(zod_schema.contains("holds: z.array(z.number().int().or(z.null())),"));
+        assert!(zod_schema.contains("checked_in: z.array(z.boolean().or(z.null())),"));
+        assert!(zod_schema.contains("notes: z.record(z.string(), z.string().or(z.null())),"));
+        assert!(zod_schema.contains("blocks: z.array(z.record(z.string(), z.number().int())),"));
+        assert!(zod_schema.contains("waitlist: z.array(z.string().or(z.null())).or(z.undefined()),"));
+    }
+
+    #[test]
+    fn test_optional_elements_serialize_as_null() {
+        let holds = SeatHoldsJson {
+            holds: vec![Some(4), None],
+            checked_in: [None, Some(true)],
+            notes: [("a1".to_string(), None)].into_iter().collect(),
+            blocks: vec![],
+            waitlist: None,
+        };
+        let value = serde_json::to_value(&holds).unwrap();
+
+        assert_eq!(value["holds"], serde_json::json!([4, null]));
+        assert_eq!(value["checked_in"], serde_json::json!([null, true]));
+        assert_eq!(value["notes"], serde_json::json!({ "a1": null }));
     }
-} 
\ No newline at end of file
+}
@@ -0,0 +1,127 @@
+#[cfg(all(test, feature = "jsonschema"))]
+mod tests {
+    #[cfg(feature = "serde")]
+    use serde::{Deserialize, Serialize};
+    use tixschema::{model_schema, model_schema_components};
+
+    #[model_schema()]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq)]
+    struct PostalAddressJson {
+        street: String,
+        city: String,
+    }
+
+    #[model_schema()]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq)]
+    #[allow(dead_code)]
+    enum CustomerTierJson {
+        Basic,
+        Premium,
+    }
+
+    #[model_schema()]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq)]
+    struct CustomerJson {
+        name: String,
+        billing: PostalAddressJson,
+        shipping: Vec<PostalAddressJson>,
+        tier: CustomerTierJson,
+    }
+
+    // Same fields as `PostalAddressJson`, so the same schema
+    #[model_schema()]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq)]
+    struct PickupPointJson {
+        street: String,
+        city: String,
+    }
+
+    #[model_schema()]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq)]
+    struct DeliveryJson {
+        address: PostalAddressJson,
+        pickup: Option<PickupPointJson>,
+    }
+
+    #[model_schema()]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq)]
+    struct CategoryJson {
+        name: String,
+        subcategories: Vec<CategoryJson>,
+    }
+
+    #[model_schema()]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq)]
+    struct CatalogJson {
+        root: CategoryJson,
+    }
+
+    fn reference(name: &str) -> serde_json::Value {
+        serde_json::json!({ "$ref": format!("#/components/schemas/{name}") })
+    }
+
+    #[test]
+    fn test_components_hold_each_type_once() {
+        let schemas = model_schema_components!(CustomerJson, PostalAddressJson, CustomerJson);
+        let schemas = schemas.as_object().unwrap();
+
+        assert_eq!(schemas.keys().collect::<Vec<_>>(), vec!["Customer", "PostalAddress"]);
+        assert_eq!(schemas["PostalAddress"], PostalAddressJson::json_schema());
+    }
+
+    #[test]
+    fn test_components_reference_shared_definitions() {
+        let schemas = model_schema_components!(CustomerJson, PostalAddressJson);
+        let properties = &schemas["Customer"]["properties"];
+
+        assert_eq!(properties["billing"], reference("PostalAddress"));
+        assert_eq!(properties["shipping"]["type"], "array");
+        assert_eq!(properties["shipping"]["items"], reference("PostalAddress"));
+        assert_eq!(properties["name"], serde_json::json!({ "type": "string" }));
+        assert_eq!(schemas["Customer"]["required"], CustomerJson::json_schema()["required"]);
+    }
+
+    #[test]
+    fn test_components_inline_unlisted_types() {
+        let schemas = model_schema_components!(CustomerJson, PostalAddressJson);
+
+        // `CustomerTierJson` isn't listed, so its enum stays inlined
+        assert_eq!(schemas["Customer"]["properties"]["tier"], CustomerTierJson::json_schema());
+
+        let schemas = model_schema_components!(CustomerJson, PostalAddressJson, CustomerTierJson);
+        assert_eq!(schemas["Customer"]["properties"]["tier"], reference("CustomerTier"));
+    }
+
+    #[test]
+    fn test_components_follow_declared_types() {
+        assert_eq!(PickupPointJson::json_schema(), PostalAddressJson::json_schema());
+
+        let schemas = model_schema_components!(DeliveryJson, PostalAddressJson);
+        let properties = &schemas["Delivery"]["properties"];
+
+        assert_eq!(properties["address"], reference("PostalAddress"));
+        // An equal schema of another type isn't a reference to it
+        assert_eq!(properties["pickup"], PickupPointJson::json_schema());
+    }
+
+    #[test]
+    fn test_components_reference_recursive_types() {
+        let schemas = model_schema_components!(CategoryJson);
+        assert_eq!(schemas["Category"]["properties"]["subcategories"]["items"], reference("Category"));
+
+        // An unlisted recursive type can't be inlined, so it's added to the components
+        let schemas = model_schema_components!(CatalogJson);
+        let schemas = schemas.as_object().unwrap();
+
+        assert_eq!(schemas.keys().collect::<Vec<_>>(), vec!["Catalog", "Category"]);
+        assert_eq!(schemas["Catalog"]["properties"]["root"], reference("Category"));
+        assert_eq!(schemas["Category"]["properties"]["subcategories"]["items"], reference("Category"));
+    }
+}
@@ -0,0 +1,58 @@
+#[cfg(all(test, feature = "csv"))]
+mod tests {
+    use tixschema::model_schema;
+
+    #[model_schema()]
+    #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize))]
+    struct SeatJson {
+        row: String,
+        number: u16,
+    }
+
+    #[cfg(feature = "serde")]
+    #[model_schema()]
+    #[derive(serde::Serialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    struct TicketSaleJson {
+        order_id: String,
+        #[serde(rename = "total")]
+        total_cents: u32,
+        buyer_email: Option<String>,
+        seat: SeatJson,
+        add_ons: Vec<String>,
+        #[serde(skip)]
+        internal_notes: String,
+    }
+
+    #[test]
+    fn test_csv_headers_in_declaration_order() {
+        assert_eq!(SeatJson::csv_headers(), vec!["row", "number"]);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_csv_headers_use_serialized_names() {
+        assert_eq!(TicketSaleJson::csv_headers(), vec!["orderId", "total", "buyerEmail", "seat", "addOns"]);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_csv_headers_match_serde_output() {
+        let sale = TicketSaleJson {
+            order_id: "ord_1".to_string(),
+            total_cents: 4500,
+            buyer_email: None,
+            seat: SeatJson { row: "C".to_string(), number: 7 },
+            add_ons: vec![],
+            internal_notes: String::new(),
+        };
+        let value = serde_json::to_value(&sale).unwrap();
+        let mut keys = value.as_object().unwrap().keys().cloned().collect::<Vec<_>>();
+        let mut headers = TicketSaleJson::csv_headers();
+        keys.sort();
+        headers.sort();
+
+        assert_eq!(headers, keys);
+    }
+}
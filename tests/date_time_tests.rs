@@ -0,0 +1,201 @@
+#[cfg(all(test, feature = "chrono"))]
+mod tests {
+    #[cfg(all(
+        test,
+        any(feature = "typescript", feature = "jsonschema", feature = "zod", feature = "serde")
+    ))]
+    use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+    #[cfg(all(test, feature = "serde"))]
+    use serde::{Deserialize, Serialize};
+    #[cfg(all(
+        test,
+        any(feature = "typescript", feature = "jsonschema", feature = "zod", feature = "serde")
+    ))]
+    use tixschema::model_schema;
+
+    #[cfg(all(
+        test,
+        any(
+            feature = "typescript",
+            feature = "jsonschema",
+            feature = "zod",
+            feature = "serde"
+        )
+    ))]
+    #[model_schema()]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq)]
+    struct ShowtimeJson {
+        starts_at: DateTime<Utc>,
+        doors_open_at: Option<chrono::DateTime<Utc>>,
+        reminders: Vec<DateTime<Utc>>,
+        premiere: NaiveDate,
+        blackout_dates: Vec<NaiveDate>,
+        last_edit: Option<NaiveDate>,
+        local_start: NaiveDateTime,
+        local_breaks: Vec<NaiveDateTime>,
+    }
+
+    #[test]
+    #[cfg(feature = "typescript")]
+    fn test_date_time_typescript_types() {
+        let ts_definition = ShowtimeJson::ts_definition();
+
+        assert!(ts_definition.contains("starts_at: string;"));
+        assert!(ts_definition.contains("doors_open_at: string | undefined;"));
+        assert!(ts_definition.contains("reminders: Array<string>;"));
+        assert!(ts_definition.contains("premiere: string;"));
+        assert!(ts_definition.contains("blackout_dates: Array<string>;"));
+        assert!(ts_definition.contains("last_edit: string | undefined;"));
+        assert!(ts_definition.contains("local_start: string;"));
+        assert!(!ts_definition.contains("DateTime"));
+        assert!(!ts_definition.contains("NaiveDate"));
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_date_time_zod_schema() {
+        let zod_schema = ShowtimeJson::zod_schema();
+
+        assert!(zod_schema.contains("starts_at: z.iso.datetime({ offset: true }),"));
+        assert!(zod_schema.contains("doors_open_at: z.iso.datetime({ offset: true }).or(z.undefined()),"));
+        assert!(zod_schema.contains("reminders: z.array(z.iso.datetime({ offset: true })),"));
+        assert!(zod_schema.contains("premiere: z.iso.date(),"));
+        assert!(zod_schema.contains("blackout_dates: z.array(z.iso.date()),"));
+        assert!(zod_schema.contains("last_edit: z.iso.date().or(z.undefined()),"));
+        assert!(zod_schema.contains("local_start: z.iso.datetime({ local: true }),"));
+        assert!(zod_schema.contains("local_breaks: z.array(z.iso.datetime({ local: true })),"));
+        assert!(!zod_schema.contains("$Schema,"));
+    }
+
+    #[test]
+    #[cfg(any(feature = "typescript", feature = "zod"))]
+    fn test_date_time_are_not_dependencies() {
+        assert!(ShowtimeJson::schema_dependencies().is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_date_time_json_schema() {
+        let schema = ShowtimeJson::json_schema();
+        let properties = &schema["properties"];
+
+        assert_eq!(properties["starts_at"], serde_json::json!({ "type": "string", "format": "date-time" }));
+        assert_eq!(properties["doors_open_at"], properties["starts_at"]);
+        assert_eq!(properties["reminders"]["items"], properties["starts_at"]);
+        assert_eq!(properties["premiere"], serde_json::json!({ "type": "string", "format": "date" }));
+        assert_eq!(properties["blackout_dates"]["items"], properties["premiere"]);
+        assert_eq!(properties["local_start"]["type"], "string");
+        assert!(properties["local_start"].get("format").is_none());
+        assert_eq!(properties["local_breaks"]["items"], properties["local_start"]);
+        assert_eq!(
+            schema["required"],
+            serde_json::json!(["starts_at", "reminders", "premiere", "blackout_dates", "local_start", "local_breaks"])
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde", feature = "jsonschema"))]
+    fn test_date_time_serialized_values_match_schema() {
+        let starts_at = DateTime::parse_from_rfc3339("2024-05-31T19:30:00Z").unwrap().with_timezone(&Utc);
+        let premiere = NaiveDate::from_ymd_opt(2024, 5, 31).unwrap();
+        let local_start = premiere.and_hms_milli_opt(19, 30, 0, 250).unwrap();
+        let value = serde_json::to_value(ShowtimeJson {
+            starts_at,
+            doors_open_at: None,
+            reminders: vec![starts_at],
+            premiere,
+            blackout_dates: vec![],
+            last_edit: Some(premiere),
+            local_start,
+            local_breaks: vec![premiere.and_hms_opt(21, 0, 0).unwrap()],
+        })
+        .unwrap();
+
+        assert_eq!(value["starts_at"], "2024-05-31T19:30:00Z");
+        assert_eq!(value["premiere"], "2024-05-31");
+
+        let schema = ShowtimeJson::json_schema();
+        let pattern = regex::Regex::new(schema["properties"]["local_start"]["pattern"].as_str().unwrap()).unwrap();
+        assert!(pattern.is_match(value["local_start"].as_str().unwrap()));
+        assert!(pattern.is_match(value["local_breaks"][0].as_str().unwrap()));
+        assert!(!pattern.is_match("2024-05-31T19:30:00Z"));
+    }
+
+    #[cfg(all(
+        test,
+        any(
+            feature = "typescript",
+            feature = "jsonschema",
+            feature = "zod",
+            feature = "serde"
+        )
+    ))]
+    #[model_schema()]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq)]
+    struct MatineeJson {
+        #[model_schema_prop(as_ts = "Date", as_zod = "z.coerce.date()")]
+        starts_at: DateTime<Utc>,
+        #[model_schema_prop(as_ts = "Date", as_zod = "z.date()")]
+        sold_out_at: Option<DateTime<Utc>>,
+        #[model_schema_prop(as_ts = "Date", as_zod = "z.coerce.date()")]
+        intermissions: Vec<DateTime<Utc>>,
+    }
+
+    #[test]
+    #[cfg(feature = "typescript")]
+    fn test_as_ts_date_typescript_types() {
+        let ts_definition = MatineeJson::ts_definition();
+
+        assert!(ts_definition.contains("starts_at: Date;"));
+        assert!(ts_definition.contains("sold_out_at: Date | undefined;"));
+        assert!(ts_definition.contains("intermissions: Array<Date>;"));
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_as_zod_date_schema() {
+        let zod_schema = MatineeJson::zod_schema();
+
+        assert!(zod_schema.contains("starts_at: z.coerce.date(),"));
+        assert!(zod_schema.contains("sold_out_at: z.date().or(z.undefined()),"));
+        assert!(zod_schema.contains("intermissions: z.array(z.coerce.date()),"));
+        assert!(!zod_schema.contains("z.iso.datetime"));
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_as_ts_date_keeps_json_schema() {
+        let schema = MatineeJson::json_schema();
+        let properties = &schema["properties"];
+
+        assert_eq!(properties["starts_at"], serde_json::json!({ "type": "string", "format": "date-time" }));
+        assert_eq!(properties["sold_out_at"], properties["starts_at"]);
+        assert_eq!(properties["intermissions"]["items"], properties["starts_at"]);
+    }
+}
+
+// Without the `chrono` feature, types named like chrono's are the user's own. The JSON schema of
+// a nested type is looked up through its `...Json` Rust type, hence no `jsonschema` here.
+#[cfg(all(test, not(feature = "chrono"), feature = "typescript", not(feature = "jsonschema")))]
+mod own_types {
+    use tixschema::model_schema;
+
+    #[allow(dead_code)]
+    struct NaiveDate {
+        day: u32,
+    }
+
+    #[model_schema()]
+    #[allow(dead_code)]
+    struct FestivalJson {
+        opening: NaiveDate,
+    }
+
+    #[test]
+    fn test_own_naive_date_is_a_sibling_type() {
+        assert!(FestivalJson::ts_definition().contains("opening: NaiveDate;"));
+        assert_eq!(FestivalJson::schema_dependencies(), vec!["NaiveDate"]);
+    }
+}
@@ -0,0 +1,52 @@
+#[cfg(all(test, any(feature = "typescript", feature = "zod", feature = "jsonschema")))]
+mod tests {
+    use tixschema::model_schema;
+
+    #[model_schema()]
+    #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[allow(dead_code)]
+    struct VenueJson {
+        name: String,
+    }
+
+    #[model_schema(debug)]
+    #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[allow(dead_code)]
+    struct EventJson {
+        name: String,
+        seats: Option<u32>,
+        venues: Vec<VenueJson>,
+    }
+
+    #[model_schema(tag = "type", debug)]
+    #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(tag = "type"))]
+    #[allow(dead_code)]
+    enum DoorEventJson {
+        Opened { at: String },
+        Closed { at: String, forced: bool },
+    }
+
+    #[test]
+    fn test_struct_field_tree() {
+        assert_eq!(
+            EventJson::FIELD_TREE,
+            "`EventJson` field `name`: String\n\
+             `EventJson` field `seats`: optional u32\n\
+             `EventJson` field `venues`: array of sibling `Venue`"
+        );
+    }
+
+    #[test]
+    fn test_enum_field_tree_lists_each_variant() {
+        assert_eq!(
+            DoorEventJson::FIELD_TREE,
+            "`DoorEventJson::Opened` field `at`: String\n\
+             `DoorEventJson::Closed` field `at`: String\n\
+             `DoorEventJson::Closed` field `forced`: bool"
+        );
+    }
+}
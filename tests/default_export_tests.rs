@@ -0,0 +1,76 @@
+#[cfg(all(test, any(feature = "typescript", feature = "zod")))]
+mod tests {
+    use tixschema::model_schema;
+
+    #[model_schema(default_export)]
+    #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[allow(dead_code)]
+    struct VenueJson {
+        name: String,
+        capacity: u32,
+    }
+
+    #[model_schema(default_export)]
+    #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[allow(dead_code)]
+    enum SeatingJson {
+        Reserved,
+        General,
+    }
+
+    #[model_schema(tag = "type", default_export)]
+    #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(tag = "type"))]
+    #[allow(dead_code)]
+    enum DoorEventJson {
+        Opened { at: String },
+        Closed { at: String },
+    }
+
+    #[model_schema()]
+    #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[allow(dead_code)]
+    struct StageJson {
+        name: String,
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_default_export_zod_schema() {
+        assert!(VenueJson::zod_schema().ends_with("\nexport default Venue$Schema;"));
+        assert!(SeatingJson::zod_schema().ends_with("\nexport default Seating$Schema;"));
+        assert!(DoorEventJson::zod_schema().ends_with("\nexport default DoorEvent$Schema;"));
+        assert_eq!(VenueJson::zod_schema().matches("export default").count(), 1);
+    }
+
+    #[test]
+    #[cfg(all(feature = "typescript", feature = "zod"))]
+    fn test_default_export_only_once_with_zod() {
+        // The schema is the default export, the type stays a named one
+        assert!(!VenueJson::ts_definition().contains("default"));
+        assert!(!SeatingJson::ts_definition().contains("default"));
+        assert!(!DoorEventJson::ts_definition().contains("default"));
+    }
+
+    #[test]
+    #[cfg(all(feature = "typescript", not(feature = "zod")))]
+    fn test_default_export_type_without_zod() {
+        assert!(VenueJson::ts_definition().ends_with("\nexport type { Venue as default };"));
+        assert!(SeatingJson::ts_definition().ends_with("\nexport type { Seating as default };"));
+        assert!(DoorEventJson::ts_definition().ends_with("\nexport type { DoorEvent as default };"));
+    }
+
+    #[test]
+    fn test_no_default_export_without_flag() {
+        #[cfg(feature = "typescript")]
+        assert!(!StageJson::ts_definition().contains("export default"));
+        #[cfg(feature = "typescript")]
+        assert!(!StageJson::ts_definition().contains("as default"));
+        #[cfg(feature = "zod")]
+        assert!(!StageJson::zod_schema().contains("export default"));
+    }
+}
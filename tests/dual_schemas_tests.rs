@@ -0,0 +1,66 @@
+#[cfg(all(test, feature = "zod"))]
+mod tests {
+    use tixschema::model_schema;
+
+    // The Zod schema of u64 values, limited to the JS safe-integer range by `safe_ints`
+    #[cfg(all(feature = "dual_schemas", feature = "safe_ints"))]
+    const WIDE_INT_ZOD: &str = "z.number().int().safe()";
+    #[cfg(all(feature = "dual_schemas", not(feature = "safe_ints")))]
+    const WIDE_INT_ZOD: &str = "z.number().int()";
+
+    #[model_schema()]
+    #[derive(Debug, Clone, PartialEq)]
+    struct AccountJson {
+        id: String,
+        balance: u64,
+        owner: OwnerJson,
+    }
+
+    #[model_schema()]
+    #[derive(Debug, Clone, PartialEq)]
+    struct OwnerJson {
+        name: String,
+    }
+
+    #[test]
+    #[cfg(feature = "dual_schemas")]
+    fn test_dual_schemas_emits_strict_and_loose_constants() {
+        let zod_schema = AccountJson::zod_schema();
+
+        let strict = zod_schema.find("export const Account$Schema").unwrap();
+        let loose = zod_schema.find("export const Account$LooseSchema").unwrap();
+        assert!(strict < loose);
+
+        let (strict_schema, loose_schema) = zod_schema.split_at(loose);
+        assert!(strict_schema.contains("z.strictObject({"));
+        assert!(!strict_schema.contains("z.looseObject"));
+        assert!(loose_schema.contains("z.looseObject({"));
+        assert!(!loose_schema.contains("z.strictObject"));
+
+        // Both constants validate the same fields
+        let balance = format!("balance: {WIDE_INT_ZOD},");
+        for field in ["id: z.string(),", &balance, "owner: Owner$Schema,"] {
+            assert!(strict_schema.contains(field));
+            assert!(loose_schema.contains(field));
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "dual_schemas", feature = "typescript"))]
+    fn test_dual_schemas_loose_constant_is_typed() {
+        assert!(
+            AccountJson::zod_schema()
+                .contains("export const Account$LooseSchema: ZodType<Account> = z.looseObject({")
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "dual_schemas"))]
+    fn test_loose_schema_is_opt_in() {
+        let zod_schema = AccountJson::zod_schema();
+
+        assert!(zod_schema.contains("export const Account$Schema"));
+        assert!(!zod_schema.contains("$LooseSchema"));
+        assert!(!zod_schema.contains("z.looseObject"));
+    }
+}
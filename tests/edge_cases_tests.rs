@@ -3,6 +3,12 @@ mod tests {
     #[cfg(all(test, feature = "serde"))]
     use serde::{Deserialize, Serialize};
 
+    // The Zod schema of u64 / i64 values, limited to the JS safe-integer range by `safe_ints`
+    #[cfg(all(feature = "zod", feature = "safe_ints"))]
+    const WIDE_INT_ZOD: &str = "z.number().int().safe()";
+    #[cfg(all(feature = "zod", not(feature = "safe_ints")))]
+    const WIDE_INT_ZOD: &str = "z.number().int()";
+
     #[cfg(all(
         test,
         any(feature = "typescript", feature = "jsonschema", feature = "zod", feature = "serde")
@@ -203,11 +209,11 @@ mod tests {
         // Zod schemas should use z.array(...) for the HashMap values - now in separate method
         let zod_schema = OriginalBugReproductionJson::zod_schema();
         assert!(
-            zod_schema.contains("problematic_map: z.record(z.string(), z.array(z.number().int()))")
+            zod_schema.contains(&format!("problematic_map: z.record(z.string(), z.array({WIDE_INT_ZOD}))"))
         );
         assert!(
             zod_schema
-                .contains("string_to_vec_i64: z.record(z.string(), z.array(z.number().int()))")
+                .contains(&format!("string_to_vec_i64: z.record(z.string(), z.array({WIDE_INT_ZOD}))"))
         );
         assert!(
             zod_schema.contains("string_to_vec_f64: z.record(z.string(), z.array(z.number()))")
@@ -242,6 +248,7 @@ mod tests {
         test,
         any(
             feature = "typescript",
+            feature = "jsonschema",
             feature = "zod",
             feature = "serde"
         )
@@ -250,6 +257,7 @@ mod tests {
     #[model_schema()]
     #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
     #[derive(Debug, Clone, PartialEq)]
+    #[allow(clippy::type_complexity)]
     struct ReallyComplexTestJson {
         // The quadruple nested case that was causing issues
         quadruple_nested: HashMap<String, Vec<HashMap<String, Vec<HashMap<String, u64>>>>>,
@@ -292,46 +300,286 @@ mod tests {
 
         // Zod schema should use the correct nested structure - now in separate method
         let zod_schema = SimpleComplexTestJson::zod_schema();
-        assert!(zod_schema.contains("nested_map_of_arrays: z.record(z.string(), z.array(z.record(z.string(), z.number().int()))),"));
+        assert!(zod_schema.contains(&format!("nested_map_of_arrays: z.record(z.string(), z.array(z.record(z.string(), {WIDE_INT_ZOD}))),")));
     }
 
     #[test]
-    #[cfg(all(feature = "jsonschema", feature = "typescript"))]
-    fn test_quadruple_nested_maps_compilation() {
-        // If this compiles without panic, it's a huge success!
-        let schema = ReallyComplexTestJson::json_schema();
+    #[cfg(feature = "typescript")]
+    fn test_really_complex_typescript() {
         let ts_definition = ReallyComplexTestJson::ts_definition();
 
-        // Check that the schema contains our fields
-        let properties = schema["properties"].as_object().unwrap();
-        assert!(properties.contains_key("quadruple_nested"));
-        assert!(properties.contains_key("optional_nested"));
-
-        // Basic structure checks
-        assert_eq!(properties["quadruple_nested"]["type"], "object");
+        assert!(ts_definition.contains(
+            "quadruple_nested: Partial<Record<string, Array<Partial<Record<string, Array<Partial<Record<string, number>>>>>>>>;"
+        ));
+        // Only the field itself may be absent; a `None` inside the map or sequence is null
+        assert!(ts_definition.contains(
+            "optional_nested: Partial<Record<string, Array<Partial<Record<string, Array<number> | null>> | null>>> | undefined;"
+        ));
+    }
 
-        // Check TypeScript contains our fields (exact types may be complex)
-        assert!(ts_definition.contains("quadruple_nested"));
-        assert!(ts_definition.contains("optional_nested"));
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_really_complex_zod_schema() {
+        let zod_schema = ReallyComplexTestJson::zod_schema();
+
+        assert!(zod_schema.contains(&format!(
+            "quadruple_nested: z.record(z.string(), z.array(z.record(z.string(), z.array(z.record(z.string(), {WIDE_INT_ZOD}))))),"
+        )));
+        assert!(zod_schema.contains(&format!(
+            "optional_nested: z.record(z.string(), z.array(z.record(z.string(), z.array({WIDE_INT_ZOD}).or(z.null())).or(z.null()))).or(z.undefined()),"
+        )));
     }
 
     #[test]
-    #[cfg(all(feature = "jsonschema", feature = "typescript", feature = "serde"))]
-    fn test_quadruple_nested_maps_compilation_serde() {
-        // If this compiles without panic, it's a huge success!
+    #[cfg(feature = "jsonschema")]
+    fn test_really_complex_json_schema() {
         let schema = ReallyComplexTestJson::json_schema();
-        let ts_definition = ReallyComplexTestJson::ts_definition();
+        let properties = &schema["properties"];
+        let u64_schema = &properties["quadruple_nested"]["additionalProperties"]["items"]["additionalProperties"]["items"]
+            ["additionalProperties"];
+        let i64_schema = &properties["optional_nested"]["additionalProperties"]["items"]["anyOf"][0]
+            ["additionalProperties"]["anyOf"][0]["items"];
+
+        assert_eq!(u64_schema["type"], "integer");
+        assert_eq!(i64_schema["type"], "integer");
+        assert_eq!(
+            properties["quadruple_nested"],
+            serde_json::json!({
+                "type": "object",
+                "additionalProperties": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "additionalProperties": {
+                            "type": "array",
+                            "items": { "type": "object", "additionalProperties": u64_schema }
+                        }
+                    }
+                }
+            })
+        );
+        assert_eq!(
+            properties["optional_nested"],
+            serde_json::json!({
+                "type": "object",
+                "additionalProperties": {
+                    "type": "array",
+                    "items": {
+                        "anyOf": [
+                            {
+                                "type": "object",
+                                "additionalProperties": {
+                                    "anyOf": [{ "type": "array", "items": i64_schema }, { "type": "null" }]
+                                }
+                            },
+                            { "type": "null" }
+                        ]
+                    }
+                }
+            })
+        );
+        assert_eq!(schema["required"], serde_json::json!(["quadruple_nested"]));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_really_complex_inner_none_serializes_as_null() {
+        let inner = HashMap::from([("missing".to_string(), None), ("present".to_string(), Some(vec![-1i64]))]);
+        let value = serde_json::to_value(ReallyComplexTestJson {
+            quadruple_nested: HashMap::new(),
+            optional_nested: Some(HashMap::from([("slots".to_string(), vec![None, Some(inner)])])),
+        })
+        .unwrap();
 
-        // Check that the schema contains our fields
+        assert_eq!(
+            value["optional_nested"],
+            serde_json::json!({ "slots": [null, { "missing": null, "present": [-1] }] })
+        );
+    }
+
+    // Transparent wrappers must resolve to the same schema as the wrapped type
+    #[cfg(all(
+        test,
+        any(feature = "typescript", feature = "jsonschema", feature = "zod", feature = "serde")
+    ))]
+    #[model_schema()]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq)]
+    struct WrappedAddressJson {
+        address: AddressJson,
+        boxed: Box<AddressJson>,
+        maybe_boxed: Option<Box<AddressJson>>,
+        boxed_optional: Box<Option<AddressJson>>,
+        label: std::borrow::Cow<'static, str>,
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_wrapped_field_json_schema_matches_bare_type() {
+        let schema = WrappedAddressJson::json_schema();
         let properties = schema["properties"].as_object().unwrap();
-        assert!(properties.contains_key("quadruple_nested"));
-        assert!(properties.contains_key("optional_nested"));
+        let required = schema["required"].as_array().unwrap();
+
+        assert_eq!(properties["boxed"], properties["address"]);
+        assert_eq!(properties["maybe_boxed"], properties["address"]);
+        assert_eq!(properties["boxed_optional"], properties["address"]);
+        assert_eq!(properties["label"]["type"], "string");
+
+        assert!(required.contains(&serde_json::json!("boxed")));
+        assert!(!required.contains(&serde_json::json!("maybe_boxed")));
+        assert!(!required.contains(&serde_json::json!("boxed_optional")));
+    }
+
+    #[test]
+    #[cfg(all(feature = "typescript", feature = "zod"))]
+    fn test_wrapped_field_ts_and_zod_match_bare_type() {
+        let ts_definition = WrappedAddressJson::ts_definition();
+        assert!(ts_definition.contains("address: Address;"));
+        assert!(ts_definition.contains("boxed: Address;"));
+        assert!(ts_definition.contains("maybe_boxed: Address | undefined;"));
+        assert!(ts_definition.contains("boxed_optional: Address | undefined;"));
+        assert!(ts_definition.contains("label: string;"));
+        assert!(!ts_definition.contains("Box"));
+        assert!(!ts_definition.contains("Cow"));
+
+        let zod_schema = WrappedAddressJson::zod_schema();
+        assert!(zod_schema.contains("boxed: Address$Schema,"));
+        assert!(!zod_schema.contains("Box"));
+    }
+
+    // Shared pointers serialize as their pointee, like Box
+    #[cfg(all(
+        test,
+        any(feature = "typescript", feature = "jsonschema", feature = "zod", feature = "serde")
+    ))]
+    #[model_schema()]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq)]
+    struct SharedAddressJson {
+        name: std::sync::Arc<String>,
+        home: std::rc::Rc<AddressJson>,
+        previous: Vec<std::sync::Arc<AddressJson>>,
+        nickname: Option<std::sync::Arc<str>>,
+    }
+
+    #[test]
+    #[cfg(all(feature = "typescript", feature = "zod"))]
+    fn test_shared_pointer_fields_ts_and_zod() {
+        let ts_definition = SharedAddressJson::ts_definition();
+        assert!(ts_definition.contains("name: string;"));
+        assert!(ts_definition.contains("home: Address;"));
+        assert!(ts_definition.contains("previous: Array<Address>;"));
+        assert!(ts_definition.contains("nickname: string | undefined;"));
+        assert!(!ts_definition.contains("Arc"));
+        assert!(!ts_definition.contains("Rc"));
+
+        let zod_schema = SharedAddressJson::zod_schema();
+        assert!(zod_schema.contains("name: z.string(),"));
+        assert!(zod_schema.contains("home: Address$Schema,"));
+        assert!(zod_schema.contains("previous: z.array(Address$Schema),"));
+        assert!(!zod_schema.contains("Arc$Schema"));
+        assert_eq!(SharedAddressJson::schema_dependencies(), vec!["Address"]);
+    }
+
+    #[test]
+    #[cfg(all(feature = "jsonschema", feature = "serde"))]
+    fn test_shared_pointer_fields_json_schema_matches_serde_output() {
+        let schema = SharedAddressJson::json_schema();
+        let properties = &schema["properties"];
+        assert_eq!(properties["name"], serde_json::json!({ "type": "string" }));
+        assert_eq!(properties["home"], AddressJson::json_schema());
+        assert_eq!(properties["previous"], serde_json::json!({ "type": "array", "items": AddressJson::json_schema() }));
+        assert_eq!(schema["required"], serde_json::json!(["name", "home", "previous"]));
+
+        let address = AddressJson { street: "Via Roma 1".to_string(), city: "Turin".to_string(), zip_code: "10121".to_string() };
+        let value = serde_json::to_value(SharedAddressJson {
+            name: std::sync::Arc::new("Main".to_string()),
+            home: std::rc::Rc::new(address.clone()),
+            previous: vec![std::sync::Arc::new(address)],
+            nickname: None,
+        })
+        .unwrap();
+        assert_eq!(value["name"], "Main");
+        assert_eq!(value["home"]["city"], "Turin");
+        assert_eq!(value["previous"][0]["street"], "Via Roma 1");
+    }
+
+    // Lifetimes and where clauses are carried onto the generated impl
+    #[cfg(all(
+        test,
+        any(feature = "typescript", feature = "jsonschema", feature = "zod", feature = "serde")
+    ))]
+    #[model_schema()]
+    #[cfg_attr(feature = "serde", derive(Serialize))]
+    #[derive(Debug, Clone, PartialEq)]
+    struct BorrowedAddressJson<'a, 'b>
+    where
+        'b: 'a,
+    {
+        street: &'a str,
+        city: &'b str,
+        nested: &'a AddressJson,
+        history: Vec<&'b str>,
+    }
+
+    #[cfg(all(
+        test,
+        any(feature = "typescript", feature = "jsonschema", feature = "zod", feature = "serde")
+    ))]
+    #[model_schema()]
+    #[cfg_attr(feature = "serde", derive(Serialize))]
+    #[cfg_attr(feature = "serde", serde(tag = "type"))]
+    #[derive(Debug, Clone, PartialEq)]
+    #[allow(dead_code)]
+    enum BorrowedEventJson<'a> {
+        Renamed { from: &'a str, to: &'a str },
+        Cleared,
+    }
 
-        // Basic structure checks
-        assert_eq!(properties["quadruple_nested"]["type"], "object");
+    #[test]
+    #[cfg(all(feature = "typescript", feature = "zod"))]
+    fn test_lifetime_struct_ts_and_zod() {
+        let ts_definition = BorrowedAddressJson::ts_definition();
+        assert!(ts_definition.contains("export type BorrowedAddress = {"));
+        assert!(ts_definition.contains("street: string;"));
+        assert!(ts_definition.contains("city: string;"));
+        assert!(ts_definition.contains("nested: Address;"));
+        assert!(ts_definition.contains("history: Array<string>;"));
+
+        let zod_schema = BorrowedAddressJson::zod_schema();
+        assert!(zod_schema.contains("street: z.string(),"));
+        assert!(zod_schema.contains("nested: Address$Schema,"));
+
+        let ts_definition = BorrowedEventJson::ts_definition();
+        assert!(ts_definition.contains("from: string;"));
+    }
 
-        // Check TypeScript contains our fields (exact types may be complex)
-        assert!(ts_definition.contains("quadruple_nested"));
-        assert!(ts_definition.contains("optional_nested"));
+    #[test]
+    #[cfg(all(feature = "jsonschema", feature = "serde"))]
+    fn test_lifetime_struct_json_schema_matches_serde_output() {
+        let schema = BorrowedAddressJson::json_schema();
+        let properties = schema["properties"].as_object().unwrap();
+        assert_eq!(properties["street"]["type"], "string");
+        assert_eq!(properties["history"]["items"]["type"], "string");
+
+        let nested = AddressJson {
+            street: "1 Main St".to_string(),
+            city: "Berlin".to_string(),
+            zip_code: "10115".to_string(),
+        };
+        let value = serde_json::to_value(BorrowedAddressJson {
+            street: "2 Side St",
+            city: "Paris",
+            nested: &nested,
+            history: vec!["Rome"],
+        })
+        .unwrap();
+        assert_eq!(value["street"], "2 Side St");
+        assert_eq!(value["history"][0], "Rome");
+
+        let schema = BorrowedEventJson::json_schema();
+        let value = serde_json::to_value(BorrowedEventJson::Renamed { from: "a", to: "b" }).unwrap();
+        assert_eq!(value["type"], "Renamed");
+        assert!(schema.to_string().contains("\"Renamed\""));
     }
 }
@@ -0,0 +1,116 @@
+#[cfg(all(test, any(feature = "typescript", feature = "zod", feature = "jsonschema")))]
+mod tests {
+    use tixschema::model_schema;
+
+    #[model_schema()]
+    #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[allow(dead_code)]
+    struct InvoiceJson {
+        number: String,
+        total: f64,
+    }
+
+    #[model_schema()]
+    #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[allow(dead_code)]
+    enum InvoiceStatusJson {
+        Draft,
+        Sent,
+    }
+
+    #[model_schema(tag = "type")]
+    #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(tag = "type"))]
+    #[allow(dead_code)]
+    enum InvoiceEventJson {
+        Issued { number: String },
+        Paid { amount: f64 },
+    }
+
+    #[model_schema(ts_definition = "to_ts", zod_schema = "zod", json_schema = "schema")]
+    #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[allow(dead_code)]
+    struct RenamedMethodsJson {
+        name: String,
+    }
+
+    // A type with its own `emit`, keeping the generated one under another name
+    #[model_schema(emit = "emit_schema")]
+    #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[allow(dead_code)]
+    struct RenamedEmitJson {
+        name: String,
+    }
+
+    impl RenamedEmitJson {
+        fn emit(_target: &str) -> &'static str {
+            "user-defined"
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "typescript")]
+    fn test_emit_typescript() {
+        assert_eq!(InvoiceJson::emit("typescript"), Some(InvoiceJson::ts_definition()));
+        assert_eq!(InvoiceStatusJson::emit("typescript"), Some(InvoiceStatusJson::ts_definition()));
+        assert_eq!(InvoiceEventJson::emit("typescript"), Some(InvoiceEventJson::ts_definition()));
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_emit_zod() {
+        assert_eq!(InvoiceJson::emit("zod"), Some(InvoiceJson::zod_schema()));
+        assert_eq!(InvoiceStatusJson::emit("zod"), Some(InvoiceStatusJson::zod_schema()));
+        assert_eq!(InvoiceEventJson::emit("zod"), Some(InvoiceEventJson::zod_schema()));
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_emit_json_schema_string() {
+        let emitted = InvoiceJson::emit("json_schema");
+        let parsed: serde_json::Value = serde_json::from_str(&emitted.unwrap()).unwrap();
+        assert_eq!(parsed, InvoiceJson::json_schema());
+
+        let parsed: serde_json::Value = serde_json::from_str(&InvoiceStatusJson::emit("json_schema").unwrap()).unwrap();
+        assert_eq!(parsed, InvoiceStatusJson::json_schema());
+
+        let parsed: serde_json::Value = serde_json::from_str(&InvoiceEventJson::emit("json_schema").unwrap()).unwrap();
+        assert_eq!(parsed, InvoiceEventJson::json_schema());
+    }
+
+    #[test]
+    #[cfg(all(feature = "typescript", feature = "zod", feature = "jsonschema"))]
+    fn test_emit_follows_renamed_methods() {
+        assert_eq!(RenamedMethodsJson::emit("typescript"), Some(RenamedMethodsJson::to_ts()));
+        assert_eq!(RenamedMethodsJson::emit("zod"), Some(RenamedMethodsJson::zod()));
+        assert_eq!(
+            RenamedMethodsJson::emit("json_schema"),
+            serde_json::to_string_pretty(&RenamedMethodsJson::schema()).ok()
+        );
+    }
+
+    #[test]
+    fn test_emit_unknown_target() {
+        assert_eq!(InvoiceJson::emit("graphql"), None);
+    }
+
+    #[test]
+    #[cfg(not(feature = "zod"))]
+    fn test_emit_disabled_target() {
+        assert_eq!(InvoiceJson::emit("zod"), None);
+    }
+
+    #[test]
+    fn test_emit_renamed() {
+        #[cfg(feature = "typescript")]
+        assert_eq!(RenamedEmitJson::emit_schema("typescript"), Some(RenamedEmitJson::ts_definition()));
+        #[cfg(feature = "zod")]
+        assert_eq!(RenamedEmitJson::emit_schema("zod"), Some(RenamedEmitJson::zod_schema()));
+        assert_eq!(RenamedEmitJson::emit("typescript"), "user-defined");
+    }
+}
@@ -0,0 +1,49 @@
+#[cfg(all(test, feature = "typescript"))]
+mod tests {
+    use tixschema::model_schema;
+
+    #[model_schema()]
+    #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+    #[allow(dead_code)]
+    enum UserStatusJson {
+        Active,
+        Inactive,
+        OnHold,
+    }
+
+    #[test]
+    #[cfg(feature = "enum_consts")]
+    fn test_enum_consts_emits_values_array() {
+        let ts_definition = UserStatusJson::ts_definition();
+
+        let values = UserStatusJson::enum_members()
+            .iter()
+            .map(|v| format!("\"{v}\""))
+            .collect::<Vec<_>>();
+        assert!(ts_definition.contains(&format!(
+            "export const UserStatusValues = [{}] as const;",
+            values.join(", ")
+        )));
+
+        // The array holds exactly the members of the union, in the same order
+        assert!(ts_definition.contains(&format!("export type UserStatus = {};", values.join(" | "))));
+        assert!(ts_definition.find("export type UserStatus").unwrap() < ts_definition.find("UserStatusValues").unwrap());
+    }
+
+    #[test]
+    #[cfg(all(feature = "enum_consts", feature = "serde"))]
+    fn test_enum_consts_uses_serialized_names() {
+        assert!(
+            UserStatusJson::ts_definition()
+                .contains("export const UserStatusValues = [\"active\", \"inactive\", \"onHold\"] as const;")
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "enum_consts"))]
+    fn test_enum_consts_is_opt_in() {
+        assert!(!UserStatusJson::ts_definition().contains("UserStatusValues"));
+    }
+}
@@ -7,8 +7,6 @@ mod tests {
     ))]
     use tixschema::model_schema;
 
-    #[cfg(all(test, feature = "serde"))]
-    use serde;
     #[cfg(all(test, feature = "serde"))]
     use serde::{Deserialize, Serialize};
     #[cfg(all(test, feature = "jsonschema", feature = "serde"))]
@@ -107,7 +105,7 @@ mod tests {
     #[model_schema()]
     #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
     #[derive(Debug, Clone, PartialEq)]
-    #[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "camelCase"))]
+    #[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "camelCase", rename_all_fields = "camelCase"))]
     enum PaymentMethod {
         CreditCard {
             card_number: String,
@@ -179,4 +177,876 @@ mod tests {
         let zod_schema = PaymentMethod::zod_schema();
         assert!(zod_schema.contains("z.discriminatedUnion(\"type\""));
     }
+
+    // Enum-level rename_all only renames tag values, exactly like serde
+    #[cfg(all(test, feature = "serde", any(feature = "jsonschema", feature = "typescript")))]
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[serde(tag = "kind", rename_all = "PascalCase")]
+    enum AccountEventJson {
+        AccountOpened {
+            account_id: String,
+            opened_at: String,
+        },
+        #[serde(rename_all = "camelCase")]
+        AccountClosed {
+            account_id: String,
+            closing_reason: Option<String>,
+        },
+    }
+
+    #[cfg(all(test, feature = "serde", any(feature = "jsonschema", feature = "typescript")))]
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[serde(tag = "type", rename_all = "camelCase")]
+    enum NoticeJson {
+        EmailSent { recipient_address: String },
+    }
+
+    /// Asserts that serde's output for `value` validates against the generated variant schema.
+    #[cfg(all(test, feature = "serde", feature = "jsonschema"))]
+    fn assert_matches_variant_schema(schema: &Value, tag: &str, value: &Value) {
+        let tag_value = value[tag].as_str().unwrap();
+        let variant = schema["oneOf"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|v| v["properties"][tag]["const"] == tag_value)
+            .unwrap_or_else(|| panic!("no variant schema for tag value {tag_value}"));
+
+        let properties = variant["properties"].as_object().unwrap();
+        for key in value.as_object().unwrap().keys() {
+            assert!(properties.contains_key(key), "schema is missing serialized key {key}");
+        }
+        for required in variant["required"].as_array().unwrap() {
+            assert!(value.get(required.as_str().unwrap()).is_some());
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde", feature = "jsonschema"))]
+    fn test_pascal_case_tagged_enum_matches_serde_output() {
+        let schema = AccountEventJson::json_schema();
+
+        let opened = serde_json::to_value(AccountEventJson::AccountOpened {
+            account_id: "acc_1".to_string(),
+            opened_at: "2024-01-01".to_string(),
+        })
+        .unwrap();
+        assert_eq!(opened["kind"], "AccountOpened");
+        assert!(opened.get("account_id").is_some());
+        assert_matches_variant_schema(&schema, "kind", &opened);
+
+        let closed = serde_json::to_value(AccountEventJson::AccountClosed {
+            account_id: "acc_1".to_string(),
+            closing_reason: Some("moved".to_string()),
+        })
+        .unwrap();
+        assert_eq!(closed["kind"], "AccountClosed");
+        assert!(closed.get("accountId").is_some());
+        assert!(closed.get("closingReason").is_some());
+        assert_matches_variant_schema(&schema, "kind", &closed);
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde", feature = "jsonschema"))]
+    fn test_enum_rename_all_does_not_rename_variant_fields() {
+        let notice = serde_json::to_value(NoticeJson::EmailSent {
+            recipient_address: "a@b.c".to_string(),
+        })
+        .unwrap();
+        assert_eq!(notice["type"], "emailSent");
+        assert!(notice.get("recipient_address").is_some());
+        assert_matches_variant_schema(&NoticeJson::json_schema(), "type", &notice);
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde", feature = "typescript"))]
+    fn test_tagged_enum_field_casing_ts_definition() {
+        let ts_definition = AccountEventJson::ts_definition();
+        assert!(ts_definition.contains("kind: \"AccountOpened\";"));
+        assert!(ts_definition.contains("account_id: string;"));
+        assert!(ts_definition.contains("opened_at: string;"));
+        assert!(ts_definition.contains("kind: \"AccountClosed\";"));
+        assert!(ts_definition.contains("accountId: string;"));
+        assert!(ts_definition.contains("closingReason: string | undefined;"));
+
+        let ts_definition = NoticeJson::ts_definition();
+        assert!(ts_definition.contains("type: \"emailSent\";"));
+        assert!(ts_definition.contains("recipient_address: string;"));
+    }
+
+    // Tagged enum that also accepts unknown tags on the frontend
+    #[cfg(all(test, any(feature = "typescript", feature = "zod", feature = "jsonschema")))]
+    #[model_schema(untagged_fallback)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(tag = "type"))]
+    #[derive(Debug, Clone, PartialEq)]
+    enum ForwardCompatEventJson {
+        Created { id: String },
+        Deleted { id: String },
+    }
+
+    #[cfg(all(test, any(feature = "typescript", feature = "zod")))]
+    #[model_schema(tag = "kind", untagged_fallback)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(tag = "kind"))]
+    #[derive(Debug, Clone, PartialEq)]
+    enum KindEventJson {
+        Started { at: String },
+    }
+
+    #[test]
+    #[cfg(feature = "typescript")]
+    fn test_untagged_fallback_ts_definition() {
+        let ts_definition = ForwardCompatEventJson::ts_definition();
+        assert!(ts_definition.contains("type: \"Created\";"));
+        assert!(ts_definition.contains("type: \"Deleted\";"));
+        assert!(ts_definition.contains("} | { type: string; [k: string]: unknown };"));
+
+        let ts_definition = KindEventJson::ts_definition();
+        assert!(ts_definition.contains("kind: \"Started\";"));
+        assert!(ts_definition.contains("} | { kind: string; [k: string]: unknown };"));
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_untagged_fallback_zod_schema() {
+        let zod_schema = ForwardCompatEventJson::zod_schema();
+        assert!(zod_schema.contains("= z.union([z.discriminatedUnion(\"type\", ["));
+        assert!(zod_schema.ends_with("]), z.looseObject({ type: z.string() })]);"));
+
+        let zod_schema = KindEventJson::zod_schema();
+        assert!(zod_schema.contains("z.discriminatedUnion(\"kind\", ["));
+        assert!(zod_schema.contains("z.looseObject({ kind: z.string() })"));
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_untagged_fallback_keeps_json_schema_strict() {
+        let schema = ForwardCompatEventJson::json_schema();
+        let one_of = schema["oneOf"].as_array().unwrap();
+        assert_eq!(one_of.len(), 2);
+        for variant in one_of {
+            assert_eq!(variant["additionalProperties"], false);
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "typescript", feature = "serde", feature = "union_helpers"))]
+    fn test_union_helpers_variant_map() {
+        let ts_definition = PaymentMethod::ts_definition();
+
+        let (union_part, map_part) = ts_definition
+            .split_once("\nexport type PaymentMethodMap = {\n")
+            .expect("variant map should follow the union type");
+        assert!(union_part.contains("export type PaymentMethod = "));
+        assert!(map_part.ends_with("\n};"));
+
+        for tag_value in ["creditCard", "bankTransfer", "payPal"] {
+            assert!(map_part.contains(&format!("  \"{tag_value}\": {{")));
+        }
+        assert!(map_part.contains("type: \"creditCard\";"));
+        assert!(map_part.contains("cardNumber: string;"));
+    }
+
+    #[test]
+    #[cfg(all(feature = "typescript", not(feature = "union_helpers")))]
+    fn test_variant_map_requires_union_helpers() {
+        assert!(!PaymentMethod::ts_definition().contains("PaymentMethodMap"));
+    }
+
+    // Plain enum serialized as tagged objects, e.g. { "kind": "active" }
+    #[cfg(all(test, any(feature = "typescript", feature = "zod", feature = "jsonschema")))]
+    #[model_schema(enum_as_object = "kind")]
+    #[cfg_attr(
+        feature = "serde",
+        derive(Serialize, Deserialize),
+        serde(tag = "kind", rename_all = "lowercase")
+    )]
+    #[derive(Debug, Clone, PartialEq)]
+    enum AccountStateJson {
+        Active,
+        Pending,
+    }
+
+    #[test]
+    #[cfg(all(feature = "typescript", feature = "serde"))]
+    fn test_enum_as_object_ts_definition() {
+        let ts_definition = AccountStateJson::ts_definition();
+
+        assert!(ts_definition.contains("export type AccountState = {"));
+        assert!(ts_definition.contains("kind: \"active\";"));
+        assert!(ts_definition.contains("kind: \"pending\";"));
+        assert!(!ts_definition.contains("= \"active\" | \"pending\""));
+    }
+
+    #[test]
+    #[cfg(all(feature = "zod", feature = "serde"))]
+    fn test_enum_as_object_zod_schema() {
+        let zod_schema = AccountStateJson::zod_schema();
+
+        assert!(zod_schema.contains("z.discriminatedUnion(\"kind\", ["));
+        assert!(zod_schema.contains("kind: z.literal(\"active\"),"));
+        assert!(zod_schema.contains("kind: z.literal(\"pending\"),"));
+        assert!(!zod_schema.contains("z.enum("));
+    }
+
+    #[test]
+    #[cfg(all(feature = "jsonschema", feature = "serde"))]
+    fn test_enum_as_object_json_schema_matches_serde_output() {
+        let schema = AccountStateJson::json_schema();
+        assert_eq!(schema["oneOf"].as_array().unwrap().len(), 2);
+
+        let value = serde_json::to_value(AccountStateJson::Active).unwrap();
+        assert_eq!(value, serde_json::json!({ "kind": "active" }));
+        assert_matches_variant_schema(&schema, "kind", &value);
+    }
+
+    // Unit variants of a serde-tagged enum are written as objects, e.g. { "type": "Queued" }
+    #[cfg(feature = "serde")]
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[serde(tag = "type")]
+    enum PrintJobStateJson {
+        Queued,
+        Printed,
+    }
+
+    #[test]
+    #[cfg(all(feature = "typescript", feature = "serde"))]
+    fn test_tagged_unit_enum_ts_definition() {
+        let ts_definition = PrintJobStateJson::ts_definition();
+
+        assert!(ts_definition.contains("type: \"Queued\";\n} | {"));
+        assert!(ts_definition.contains("type: \"Printed\";\n};"));
+        assert!(!ts_definition.contains("= \"Queued\" | \"Printed\""));
+    }
+
+    #[test]
+    #[cfg(all(feature = "zod", feature = "serde"))]
+    fn test_tagged_unit_enum_zod_schema() {
+        let zod_schema = PrintJobStateJson::zod_schema();
+
+        assert!(zod_schema.contains("z.discriminatedUnion(\"type\", ["));
+        assert!(zod_schema.contains("type: z.literal(\"Queued\"),"));
+        assert!(zod_schema.contains("type: z.literal(\"Printed\"),"));
+        assert!(!zod_schema.contains("z.enum("));
+    }
+
+    #[test]
+    #[cfg(all(feature = "jsonschema", feature = "serde"))]
+    fn test_tagged_unit_enum_json_schema_matches_serde_output() {
+        let schema = PrintJobStateJson::json_schema();
+        assert_eq!(schema["oneOf"].as_array().unwrap().len(), 2);
+
+        for state in [PrintJobStateJson::Queued, PrintJobStateJson::Printed] {
+            let value = serde_json::to_value(&state).unwrap();
+            assert!(value.is_object());
+            assert_matches_variant_schema(&schema, "type", &value);
+        }
+    }
+
+    // Plain enum with a `#[serde(other)]` catch-all variant
+    #[cfg(feature = "serde")]
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+    #[serde(rename_all = "lowercase")]
+    enum ChannelJson {
+        Email,
+        Sms,
+        #[serde(other)]
+        Unknown,
+    }
+
+    #[cfg(feature = "serde")]
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    struct QuotaJson {
+        limit: u32,
+    }
+
+    #[cfg(feature = "serde")]
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    struct ChannelQuotasJson {
+        quotas: std::collections::HashMap<ChannelJson, QuotaJson>,
+    }
+
+    #[cfg(feature = "serde")]
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    struct ChannelUsageJson {
+        sent: std::collections::HashMap<ChannelJson, u32>,
+        fallbacks: std::collections::HashMap<ChannelJson, Option<QuotaJson>>,
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_catch_all_variant_accepts_unknown_values() {
+        let channel: ChannelJson = serde_json::from_str("\"push\"").unwrap();
+        assert_eq!(channel, ChannelJson::Unknown);
+
+        // The catch-all isn't one of the known values
+        assert_eq!(ChannelJson::enum_members(), vec!["email", "sms"]);
+    }
+
+    #[test]
+    #[cfg(all(feature = "typescript", feature = "serde"))]
+    fn test_catch_all_variant_ts_definition() {
+        let ts_definition = ChannelJson::ts_definition();
+        assert!(ts_definition.contains("export type Channel = \"email\" | \"sms\" | (string & {});"));
+        assert!(!ts_definition.contains("unknown"));
+    }
+
+    #[test]
+    #[cfg(all(feature = "zod", feature = "serde"))]
+    fn test_catch_all_variant_zod_schema() {
+        let zod_schema = ChannelJson::zod_schema();
+        assert!(zod_schema.contains("z.union([z.enum([\"email\", \"sms\"]), z.string()])"));
+        assert!(!zod_schema.contains("unknown"));
+    }
+
+    #[test]
+    #[cfg(all(feature = "jsonschema", feature = "serde"))]
+    fn test_catch_all_variant_json_schema() {
+        assert_eq!(ChannelJson::json_schema(), serde_json::json!({ "type": "string" }));
+
+        // Known keys are listed, and unknown keys are accepted with the same value schema
+        let schema = ChannelQuotasJson::json_schema();
+        let quotas = &schema["properties"]["quotas"];
+        assert_eq!(quotas["properties"]["email"], QuotaJson::json_schema());
+        assert_eq!(quotas["additionalProperties"], QuotaJson::json_schema());
+    }
+
+    #[test]
+    #[cfg(all(feature = "jsonschema", feature = "serde"))]
+    fn test_enum_keyed_map_value_json_schema() {
+        let schema = ChannelUsageJson::json_schema();
+
+        let sent = &schema["properties"]["sent"];
+        assert_eq!(sent["properties"]["sms"], serde_json::json!({ "type": "integer" }));
+        assert_eq!(sent["additionalProperties"], sent["properties"]["email"]);
+
+        // A `None` value is kept in the map and serialized as null
+        let fallbacks = &schema["properties"]["fallbacks"];
+        assert_eq!(
+            fallbacks["properties"]["email"],
+            serde_json::json!({ "anyOf": [QuotaJson::json_schema(), { "type": "null" }] })
+        );
+    }
+
+    // Internally tagged enum mixing a newtype variant with struct and unit variants
+    #[cfg(feature = "serde")]
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    struct PinLocationJson {
+        lat: f64,
+        lng: f64,
+    }
+
+    #[cfg(feature = "serde")]
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[serde(tag = "kind", rename_all = "lowercase")]
+    #[allow(dead_code)]
+    enum DeliveryTargetJson {
+        Pin(PinLocationJson),
+        Locker { code: String },
+        Pickup,
+    }
+
+    #[test]
+    #[cfg(all(feature = "typescript", feature = "serde"))]
+    fn test_newtype_variant_ts_definition() {
+        let ts_definition = DeliveryTargetJson::ts_definition();
+        assert!(ts_definition.contains("kind: \"pin\";\n} & PinLocation)"));
+        assert!(!ts_definition.contains("  : PinLocation"));
+    }
+
+    #[test]
+    #[cfg(all(feature = "zod", feature = "serde"))]
+    fn test_newtype_variant_falls_back_to_zod_union() {
+        let zod_schema = DeliveryTargetJson::zod_schema();
+        assert!(zod_schema.contains("= z.union(["));
+        assert!(zod_schema.contains("z.looseObject({ kind: z.literal(\"pin\") }).and(z.preprocess("));
+        assert!(zod_schema.contains("PinLocation$Schema))"));
+        assert!(zod_schema.contains("z.strictObject({\n  kind: z.literal(\"locker\"),"));
+        assert!(!zod_schema.contains("z.discriminatedUnion"));
+
+        // Enums without newtype variants keep the discriminated union
+        assert!(PaymentMethod::zod_schema().contains("z.discriminatedUnion("));
+    }
+
+    #[test]
+    #[cfg(all(feature = "jsonschema", feature = "serde"))]
+    fn test_newtype_variant_json_schema_matches_serde_output() {
+        let schema = DeliveryTargetJson::json_schema();
+
+        let pin = serde_json::to_value(DeliveryTargetJson::Pin(PinLocationJson { lat: 1.5, lng: 2.5 })).unwrap();
+        assert_eq!(pin, serde_json::json!({ "kind": "pin", "lat": 1.5, "lng": 2.5 }));
+        assert_matches_variant_schema(&schema, "kind", &pin);
+
+        let variant = schema["oneOf"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|v| v["properties"]["kind"]["const"] == "pin")
+            .unwrap();
+        assert_eq!(variant["required"], serde_json::json!(["kind", "lat", "lng"]));
+        assert_eq!(variant["additionalProperties"], false);
+    }
+
+    #[test]
+    #[cfg(all(feature = "jsonschema", feature = "serde"))]
+    fn test_mixed_newtype_and_struct_variants_round_trip() {
+        let schema = DeliveryTargetJson::json_schema();
+
+        for target in [
+            DeliveryTargetJson::Pin(PinLocationJson { lat: -3.25, lng: 40.0 }),
+            DeliveryTargetJson::Locker { code: "L-12".to_string() },
+            DeliveryTargetJson::Pickup,
+        ] {
+            let value = serde_json::to_value(&target).unwrap();
+            assert_matches_variant_schema(&schema, "kind", &value);
+            assert_eq!(serde_json::from_value::<DeliveryTargetJson>(value).unwrap(), target);
+        }
+    }
+
+    // Newtype variant whose struct renames its fields and has an optional one
+    #[cfg(feature = "serde")]
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    struct CourierDropJson {
+        courier_name: String,
+        tracking_code: Option<String>,
+    }
+
+    #[cfg(feature = "serde")]
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[serde(tag = "type")]
+    #[allow(dead_code)]
+    enum HandoffJson {
+        Courier(CourierDropJson),
+        Counter { desk: u8 },
+    }
+
+    #[test]
+    #[cfg(all(feature = "typescript", feature = "zod", feature = "serde"))]
+    fn test_newtype_struct_variant_intersects_payload() {
+        let ts_definition = HandoffJson::ts_definition();
+        assert!(ts_definition.contains("type: \"Courier\";\n} & CourierDrop)"));
+
+        let zod_schema = HandoffJson::zod_schema();
+        assert!(zod_schema.contains("z.looseObject({ type: z.literal(\"Courier\") }).and(z.preprocess("));
+        assert!(zod_schema.contains("CourierDrop$Schema))"));
+    }
+
+    #[test]
+    #[cfg(all(feature = "jsonschema", feature = "serde"))]
+    fn test_newtype_struct_variant_keeps_payload_optionality() {
+        let schema = HandoffJson::json_schema();
+        let variant = schema["oneOf"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|v| v["properties"]["type"]["const"] == "Courier")
+            .unwrap();
+        assert_eq!(variant["required"], serde_json::json!(["type", "courierName"]));
+        assert!(variant["properties"]["trackingCode"].is_object());
+
+        for handoff in [
+            HandoffJson::Courier(CourierDropJson {
+                courier_name: "Ada".to_string(),
+                tracking_code: Some("TRK-1".to_string()),
+            }),
+            HandoffJson::Courier(CourierDropJson {
+                courier_name: "Bo".to_string(),
+                tracking_code: None,
+            }),
+            HandoffJson::Counter { desk: 3 },
+        ] {
+            let value = serde_json::to_value(&handoff).unwrap();
+            assert_matches_variant_schema(&schema, "type", &value);
+            assert_eq!(serde_json::from_value::<HandoffJson>(value).unwrap(), handoff);
+        }
+    }
+
+    // Plain enum whose JSON schema is checked in every feature combination with jsonschema
+    #[cfg(feature = "jsonschema")]
+    #[model_schema()]
+    #[cfg_attr(
+        feature = "serde",
+        derive(Serialize, Deserialize),
+        serde(rename_all = "lowercase")
+    )]
+    #[derive(Debug, Clone, PartialEq)]
+    #[allow(dead_code)]
+    enum ReviewStateJson {
+        Draft,
+        InReview,
+        Published,
+    }
+
+    #[test]
+    #[cfg(all(feature = "jsonschema", feature = "serde"))]
+    fn test_plain_enum_json_schema_uses_renamed_values() {
+        let schema = ReviewStateJson::json_schema();
+        assert_eq!(schema["enum"], serde_json::json!(["draft", "inreview", "published"]));
+
+        for state in [ReviewStateJson::Draft, ReviewStateJson::InReview, ReviewStateJson::Published] {
+            let value = serde_json::to_value(&state).unwrap();
+            assert!(schema["enum"].as_array().unwrap().contains(&value));
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "jsonschema", not(feature = "serde")))]
+    fn test_plain_enum_json_schema_without_serde_keeps_variant_names() {
+        assert_eq!(
+            ReviewStateJson::json_schema()["enum"],
+            serde_json::json!(["Draft", "InReview", "Published"])
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_plain_enum_json_schema_matches_enum_members() {
+        assert_eq!(
+            ReviewStateJson::json_schema()["enum"],
+            serde_json::json!(ReviewStateJson::enum_members())
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "jsonschema", feature = "typescript"))]
+    fn test_plain_enum_json_schema_matches_ts_union() {
+        let union = ReviewStateJson::json_schema()["enum"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>()
+            .join(" | ");
+        assert!(ReviewStateJson::ts_definition().contains(&format!("export type ReviewState = {union};")));
+    }
+
+    // Union branches documented in the JSON schema
+    #[cfg(all(feature = "jsonschema", feature = "serde"))]
+    #[model_schema(discriminator_description)]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[serde(tag = "type", rename_all = "lowercase")]
+    #[allow(dead_code)]
+    enum RefundEventJson {
+        /// A refund was requested by the customer.
+        /// It still needs approval.
+        Requested { amount: u64 },
+        /// The refund reached the customer's account.
+        Settled,
+        Cancelled,
+    }
+
+    #[test]
+    #[cfg(all(feature = "jsonschema", feature = "serde"))]
+    fn test_discriminator_description_annotates_branches() {
+        let schema = RefundEventJson::json_schema();
+        let branch = |tag: &str| {
+            schema["oneOf"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .find(|v| v["properties"]["type"]["const"] == tag)
+                .unwrap()
+                .clone()
+        };
+
+        assert_eq!(branch("requested")["title"], "Requested");
+        assert_eq!(
+            branch("requested")["description"],
+            "A refund was requested by the customer.\nIt still needs approval."
+        );
+        assert_eq!(branch("settled")["title"], "Settled");
+        assert_eq!(branch("settled")["description"], "The refund reached the customer's account.");
+
+        // Undocumented variants only get a title
+        assert_eq!(branch("cancelled")["title"], "Cancelled");
+        assert!(branch("cancelled").get("description").is_none());
+    }
+
+    #[test]
+    #[cfg(all(feature = "jsonschema", feature = "serde"))]
+    fn test_branches_are_not_annotated_by_default() {
+        for branch in PaymentMethod::json_schema()["oneOf"].as_array().unwrap() {
+            assert!(branch.get("title").is_none());
+            assert!(branch.get("description").is_none());
+        }
+    }
+
+    // Edge cases: tagged enums with a single variant and without variants
+    #[cfg(any(feature = "typescript", feature = "zod", feature = "jsonschema"))]
+    #[model_schema(tag = "type")]
+    #[derive(Debug, Clone, PartialEq)]
+    #[allow(dead_code)]
+    enum OnlyPingJson {
+        Ping { at: u64 },
+    }
+
+    #[cfg(any(feature = "typescript", feature = "zod", feature = "jsonschema"))]
+    #[model_schema(tag = "type")]
+    #[derive(Debug, Clone, PartialEq)]
+    #[allow(dead_code)]
+    enum NoSignalJson {}
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_single_variant_tagged_enum_is_one_option_union() {
+        let zod_schema = OnlyPingJson::zod_schema();
+        assert!(zod_schema.contains("z.discriminatedUnion(\"type\", [z.strictObject({"));
+        assert_eq!(zod_schema.matches("z.strictObject(").count(), 1);
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_single_variant_tagged_enum_json_schema() {
+        let schema = OnlyPingJson::json_schema();
+        let branches = schema["oneOf"].as_array().unwrap();
+        assert_eq!(branches.len(), 1);
+        assert_eq!(branches[0]["properties"]["type"]["const"], "Ping");
+    }
+
+    #[test]
+    #[cfg(feature = "typescript")]
+    fn test_empty_tagged_enum_is_never() {
+        assert!(NoSignalJson::ts_definition().contains("export type NoSignal = never;"));
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_empty_tagged_enum_zod_schema_is_never() {
+        let zod_schema = NoSignalJson::zod_schema();
+        assert!(zod_schema.contains("z.never()"));
+        assert!(!zod_schema.contains("discriminatedUnion"));
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_empty_tagged_enum_json_schema_is_false() {
+        assert_eq!(NoSignalJson::json_schema(), serde_json::Value::Bool(false));
+    }
+
+    // Variants declared out of alphabetical order keep their declaration order in every output
+    #[cfg(any(feature = "typescript", feature = "zod", feature = "jsonschema"))]
+    #[model_schema(tag = "status")]
+    #[derive(Debug, Clone, PartialEq)]
+    #[allow(dead_code)]
+    enum RefundStepJson {
+        Submitted { at: u64 },
+        Approved { by: String },
+        Paid { amount: u32 },
+    }
+
+    #[cfg(any(feature = "typescript", feature = "zod"))]
+    fn assert_in_order(haystack: &str, needles: &[&str]) {
+        let positions = needles
+            .iter()
+            .map(|needle| haystack.find(needle).unwrap_or_else(|| panic!("{needle} missing from {haystack}")))
+            .collect::<Vec<_>>();
+        assert!(positions.windows(2).all(|pair| pair[0] < pair[1]), "{needles:?} out of order in {haystack}");
+    }
+
+    #[test]
+    #[cfg(feature = "typescript")]
+    fn test_tagged_variants_keep_declaration_order_in_typescript() {
+        assert_in_order(
+            &RefundStepJson::ts_definition(),
+            &["status: \"Submitted\"", "status: \"Approved\"", "status: \"Paid\""],
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_tagged_variants_keep_declaration_order_in_zod() {
+        assert_in_order(
+            &RefundStepJson::zod_schema(),
+            &["z.literal(\"Submitted\")", "z.literal(\"Approved\")", "z.literal(\"Paid\")"],
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_tagged_variants_keep_declaration_order_in_json_schema() {
+        let schema = RefundStepJson::json_schema();
+        let tags = schema["oneOf"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|branch| branch["properties"]["status"]["const"].as_str().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(tags, ["Submitted", "Approved", "Paid"]);
+    }
+
+    // The tag comes before each variant's own fields, even those sorting before it
+    #[cfg(any(feature = "typescript", feature = "zod", feature = "jsonschema"))]
+    #[model_schema()]
+    #[derive(Debug, Clone, PartialEq)]
+    #[allow(dead_code)]
+    struct WireDetailsJson {
+        account: String,
+    }
+
+    #[cfg(any(feature = "typescript", feature = "zod", feature = "jsonschema"))]
+    #[model_schema(tag = "type")]
+    #[derive(Debug, Clone, PartialEq)]
+    #[allow(dead_code)]
+    enum SettlementJson {
+        Card { amount: u32, brand: String },
+        Wire(WireDetailsJson),
+        Cash,
+    }
+
+    #[test]
+    #[cfg(feature = "typescript")]
+    fn test_tag_is_first_in_typescript_variants() {
+        let ts_definition = SettlementJson::ts_definition();
+        let type_code = ts_definition.split("export type Settlement =").nth(1).unwrap();
+
+        assert_in_order(type_code, &["type: \"Card\"", "amount: number", "brand: string"]);
+        assert_in_order(type_code, &["type: \"Wire\"", "& WireDetails"]);
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_tag_is_first_in_zod_variants() {
+        let zod_schema = SettlementJson::zod_schema();
+
+        assert_in_order(&zod_schema, &["type: z.literal(\"Card\")", "amount: z.number()", "brand: z.string()"]);
+        assert_in_order(&zod_schema, &["type: z.literal(\"Wire\")", "WireDetails$Schema"]);
+    }
+
+    // JSON schema keys are written sorted even under serde_json's `preserve_order` (see "Stable
+    // Output" in the README), so `properties` has no order to check and it's checked on `required`
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_tag_is_first_in_json_schema_variants() {
+        let schema = SettlementJson::json_schema();
+        let branches = schema["oneOf"].as_array().unwrap();
+
+        assert_eq!(branches.len(), 3);
+        for branch in branches {
+            assert_eq!(branch["required"][0], "type", "{branch}");
+            assert!(branch["properties"]["type"]["const"].is_string(), "{branch}");
+        }
+        assert_eq!(branches[0]["required"], serde_json::json!(["type", "amount", "brand"]));
+        assert_eq!(branches[1]["required"], serde_json::json!(["type", "account"]));
+    }
+
+    // Untagged enums: each variant is written as its own value, without a tag
+    #[cfg(feature = "serde")]
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[serde(untagged)]
+    enum SeatRequestJson {
+        /// A specific seat
+        Assigned { section: String, seat: u32 },
+        /// Any seats, by count
+        Count(u32),
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_untagged_enum_serde_shape() {
+        let assigned = SeatRequestJson::Assigned { section: "B".to_string(), seat: 12 };
+        assert_eq!(serde_json::to_value(&assigned).unwrap(), serde_json::json!({ "section": "B", "seat": 12 }));
+        assert_eq!(serde_json::to_value(SeatRequestJson::Count(4)).unwrap(), serde_json::json!(4));
+    }
+
+    #[test]
+    #[cfg(all(feature = "typescript", feature = "serde"))]
+    fn test_untagged_enum_ts_definition() {
+        let ts_definition = SeatRequestJson::ts_definition();
+        let type_code = ts_definition.split("export type SeatRequest =").nth(1).unwrap();
+
+        assert!(type_code.contains("A specific seat"));
+        assert!(type_code.contains("  section: string;"));
+        assert!(type_code.contains("  seat: number;"));
+        assert!(type_code.ends_with("} | number;"));
+        assert!(!type_code.contains("type:"));
+    }
+
+    #[test]
+    #[cfg(all(feature = "zod", feature = "serde"))]
+    fn test_untagged_enum_zod_schema() {
+        let zod_schema = SeatRequestJson::zod_schema();
+
+        assert!(zod_schema.contains("z.union([z.strictObject({\n  section: z.string(),\n  seat: z.number().int()"));
+        assert!(zod_schema.contains("}), z.number().int()"));
+        assert!(!zod_schema.contains("discriminatedUnion"));
+        assert!(!zod_schema.contains("z.literal("));
+    }
+
+    #[test]
+    #[cfg(all(feature = "jsonschema", feature = "serde"))]
+    fn test_untagged_enum_json_schema() {
+        let schema = SeatRequestJson::json_schema();
+
+        assert!(schema.get("oneOf").is_none());
+        assert!(schema.get("type").is_none());
+        let branches = schema["anyOf"].as_array().unwrap();
+        assert_eq!(branches.len(), 2);
+        assert_eq!(branches[0]["type"], "object");
+        assert_eq!(branches[0]["required"], serde_json::json!(["section", "seat"]));
+        assert_eq!(branches[0]["properties"]["section"]["type"], "string");
+        assert_eq!(branches[0]["additionalProperties"], false);
+        assert_eq!(branches[1]["type"], "integer");
+    }
+
+    // Unit and struct variants mixed in a tagged enum
+    #[cfg(feature = "serde")]
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[serde(tag = "type", rename_all = "lowercase")]
+    enum GateScanJson {
+        Admitted { ticket_id: String, gate: u8 },
+        Rejected { reason: String },
+        Offline,
+    }
+
+    #[test]
+    #[cfg(all(feature = "typescript", feature = "serde"))]
+    fn test_unit_variant_of_mixed_enum_is_only_its_tag_in_typescript() {
+        let ts_definition = GateScanJson::ts_definition();
+
+        assert!(ts_definition.contains("type: \"admitted\";"));
+        assert!(ts_definition.contains("ticket_id: string;"));
+        assert!(ts_definition.contains("} | {  /**\n * offline\n * \n**/\n  type: \"offline\";\n};"));
+    }
+
+    #[test]
+    #[cfg(all(feature = "zod", feature = "serde"))]
+    fn test_unit_variant_of_mixed_enum_is_only_its_tag_in_zod() {
+        let zod_schema = GateScanJson::zod_schema();
+
+        assert!(zod_schema.contains("z.discriminatedUnion(\"type\", ["));
+        assert!(zod_schema.contains("z.strictObject({\n  type: z.literal(\"offline\"),\n})"));
+    }
+
+    #[test]
+    #[cfg(all(feature = "jsonschema", feature = "serde"))]
+    fn test_unit_variant_of_mixed_enum_matches_serde_output() {
+        let schema = GateScanJson::json_schema();
+        let offline = &schema["oneOf"][2];
+        assert_eq!(offline["required"], serde_json::json!(["type"]));
+        assert_eq!(offline["properties"].as_object().unwrap().len(), 1);
+
+        for scan in [
+            GateScanJson::Admitted { ticket_id: "t_1".to_string(), gate: 3 },
+            GateScanJson::Rejected { reason: "used".to_string() },
+            GateScanJson::Offline,
+        ] {
+            let value = serde_json::to_value(&scan).unwrap();
+            assert_matches_variant_schema(&schema, "type", &value);
+            assert_eq!(serde_json::from_value::<GateScanJson>(value).unwrap(), scan);
+        }
+        assert_eq!(serde_json::to_value(GateScanJson::Offline).unwrap(), serde_json::json!({ "type": "offline" }));
+    }
 }
@@ -0,0 +1,84 @@
+#[cfg(all(test, any(feature = "typescript", feature = "zod", feature = "jsonschema")))]
+mod tests {
+    use std::collections::HashMap;
+    use tixschema::model_schema;
+
+    // Stand-ins for third-party types the macro can't look into
+    #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    struct GeoJson(serde_json::Value);
+
+    #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    struct Money(u64);
+
+    #[model_schema(extern_map(GeoJson = "GeoJSON.Geometry", Money(ts = "Dinero", zod = "Dinero$Schema")))]
+    #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    struct VenueJson {
+        name: String,
+        area: GeoJson,
+        entrances: Vec<GeoJson>,
+        parking: Option<GeoJson>,
+        prices: HashMap<String, Money>,
+        deposit: Money,
+    }
+
+    #[model_schema(extern_map(GeoJson = "GeoJSON.Geometry"))]
+    #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(tag = "type"))]
+    #[allow(dead_code)]
+    enum ZoneChangeJson {
+        Redrawn { area: GeoJson },
+    }
+
+    #[test]
+    #[cfg(feature = "typescript")]
+    fn test_extern_map_typescript() {
+        let ts_definition = VenueJson::ts_definition();
+        assert!(ts_definition.contains("area: GeoJSON.Geometry;"));
+        assert!(ts_definition.contains("entrances: Array<GeoJSON.Geometry>;"));
+        assert!(ts_definition.contains("parking: GeoJSON.Geometry | undefined;"));
+        assert!(ts_definition.contains("prices: Partial<Record<string, Dinero>>;"));
+        assert!(ts_definition.contains("deposit: Dinero;"));
+        assert!(!ts_definition.contains("Geo$Schema"));
+
+        assert!(ZoneChangeJson::ts_definition().contains("area: GeoJSON.Geometry;"));
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_extern_map_zod_schema() {
+        let zod_schema = VenueJson::zod_schema();
+        #[cfg(feature = "typescript")]
+        {
+            assert!(zod_schema.contains("area: z.custom<GeoJSON.Geometry>(),"));
+            assert!(zod_schema.contains("entrances: z.array(z.custom<GeoJSON.Geometry>()),"));
+        }
+        #[cfg(not(feature = "typescript"))]
+        assert!(zod_schema.contains("area: z.custom(),"));
+        assert!(zod_schema.contains("deposit: Dinero$Schema,"));
+        assert!(zod_schema.contains("Dinero$Schema"));
+        assert!(!zod_schema.contains("Geo$Schema"));
+        assert!(!zod_schema.contains("Money$Schema"));
+    }
+
+    #[test]
+    #[cfg(any(feature = "typescript", feature = "zod"))]
+    fn test_extern_map_types_are_not_dependencies() {
+        assert!(VenueJson::schema_dependencies().is_empty());
+        assert!(ZoneChangeJson::schema_dependencies().is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_extern_map_json_schema_accepts_any_value() {
+        let schema = VenueJson::json_schema();
+        let properties = &schema["properties"];
+        assert_eq!(properties["area"], serde_json::json!({}));
+        assert_eq!(properties["entrances"], serde_json::json!({ "type": "array", "items": {} }));
+        assert_eq!(properties["deposit"], serde_json::json!({}));
+        assert_eq!(properties["prices"]["additionalProperties"], serde_json::json!({}));
+    }
+}
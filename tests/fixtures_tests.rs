@@ -0,0 +1,116 @@
+#[cfg(all(test, feature = "fixtures"))]
+mod tests {
+    use serde_json::json;
+    use std::collections::HashMap;
+    use tixschema::model_schema;
+
+    #[model_schema(derive_default_instance)]
+    #[derive(Debug, Clone, PartialEq)]
+    struct AddressJson {
+        street: String,
+        zip_code: u32,
+    }
+
+    #[model_schema(derive_default_instance)]
+    #[derive(Debug, Clone, PartialEq)]
+    #[allow(dead_code)]
+    enum StatusJson {
+        Active,
+        Inactive,
+    }
+
+    #[model_schema(derive_default_instance)]
+    #[derive(Debug, Clone, PartialEq)]
+    #[allow(dead_code)]
+    enum ContactJson {
+        Email { address: String, verified: bool },
+        Phone { number: String },
+    }
+
+    #[model_schema(derive_default_instance)]
+    #[derive(Debug, Clone, PartialEq)]
+    struct ProfileJson {
+        id: String,
+        age: u32,
+        score: f64,
+        active: bool,
+        tags: Vec<String>,
+        counters: HashMap<String, u32>,
+        nickname: Option<String>,
+        visits: std::num::NonZeroU32,
+        address: AddressJson,
+        status: StatusJson,
+        contact: ContactJson,
+    }
+
+    #[test]
+    fn test_default_json_struct_shape() {
+        assert_eq!(
+            ProfileJson::default_json(),
+            json!({
+                "id": "",
+                "age": 0,
+                "score": 0.0,
+                "active": false,
+                "tags": [],
+                "counters": {},
+                "visits": 1,
+                "address": { "street": "", "zip_code": 0 },
+                "status": "Active",
+                "contact": { "type": "Email", "address": "", "verified": false },
+            })
+        );
+    }
+
+    #[model_schema(derive_default_instance)]
+    #[derive(Debug, Clone, PartialEq)]
+    struct SeatRuleJson {
+        #[model_schema_prop(enum_values = ["aisle", "window"])]
+        preference: String,
+        #[model_schema_prop(ts_template = "row_${number}_${string}")]
+        row: String,
+        #[model_schema_prop(min = 2, max = 8)]
+        group_size: u8,
+        #[model_schema_prop(min = 0.5)]
+        price: f64,
+        #[model_schema_prop(max = -1)]
+        offset: i32,
+    }
+
+    #[test]
+    fn test_default_json_satisfies_constraints() {
+        assert_eq!(
+            SeatRuleJson::default_json(),
+            json!({
+                "preference": "aisle",
+                "row": "row_0_",
+                "group_size": 2,
+                "price": 0.5,
+                "offset": -1,
+            })
+        );
+    }
+
+    #[test]
+    fn test_default_json_skips_optional_fields() {
+        assert!(ProfileJson::default_json().get("nickname").is_none());
+    }
+
+    #[test]
+    fn test_default_json_enums_use_first_variant() {
+        assert_eq!(StatusJson::default_json(), json!("Active"));
+        assert_eq!(ContactJson::default_json()["type"], "Email");
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_default_json_matches_json_schema_shape() {
+        let schema = ProfileJson::json_schema();
+        let default = ProfileJson::default_json();
+        let required = schema["required"].as_array().unwrap();
+
+        for key in required {
+            assert!(default.get(key.as_str().unwrap()).is_some(), "missing {key}");
+        }
+    }
+}
@@ -0,0 +1,91 @@
+#[cfg(all(test, feature = "io_ts"))]
+mod tests {
+    use std::collections::HashMap;
+    use tixschema::model_schema;
+
+    #[model_schema()]
+    #[derive(Debug, Clone, PartialEq)]
+    struct AddressJson {
+        street: String,
+        city: String,
+    }
+
+    #[model_schema()]
+    #[derive(Debug, Clone, PartialEq)]
+    #[allow(dead_code)]
+    enum UserStatusJson {
+        Active,
+        Inactive,
+    }
+
+    #[model_schema()]
+    #[derive(Debug, Clone, PartialEq)]
+    struct UserJson {
+        id: String,
+        age: Option<u32>,
+        score: f64,
+        tags: Vec<String>,
+        address: AddressJson,
+        status: UserStatusJson,
+        notes: HashMap<String, bool>,
+    }
+
+    #[model_schema()]
+    #[derive(Debug, Clone, PartialEq)]
+    #[allow(dead_code)]
+    enum ContactJson {
+        Email { address: String },
+        Phone { number: String, extension: Option<u16> },
+    }
+
+    #[test]
+    fn test_struct_io_ts_codec() {
+        assert_eq!(
+            UserJson::io_ts_codec(),
+            "export const User = t.type({\n  \
+             id: t.string,\n  \
+             age: t.union([t.number, t.undefined]),\n  \
+             score: t.number,\n  \
+             tags: t.array(t.string),\n  \
+             address: Address,\n  \
+             status: UserStatus,\n  \
+             notes: t.record(t.string, t.boolean),\n\
+             });"
+        );
+    }
+
+    #[test]
+    fn test_plain_enum_io_ts_codec() {
+        assert_eq!(
+            UserStatusJson::io_ts_codec(),
+            "export const UserStatus = t.keyof({ \"Active\": null, \"Inactive\": null });"
+        );
+    }
+
+    #[test]
+    fn test_discriminated_enum_io_ts_codec() {
+        let codec = ContactJson::io_ts_codec();
+        assert!(codec.starts_with("export const Contact = t.union([t.type({\n  type: t.literal(\"Email\"),\n  address: t.string,\n}), "));
+        assert!(codec.contains("type: t.literal(\"Phone\"),\n  number: t.string,\n  extension: t.union([t.number, t.undefined]),\n"));
+        assert!(codec.ends_with("})]);"));
+    }
+
+    #[cfg(feature = "object_id")]
+    #[model_schema()]
+    #[derive(Debug, Clone, PartialEq)]
+    struct DocumentJson {
+        id: mongodb::bson::oid::ObjectId,
+        owners: HashMap<mongodb::bson::oid::ObjectId, String>,
+    }
+
+    #[test]
+    #[cfg(feature = "object_id")]
+    fn test_object_id_io_ts_codec() {
+        let codec = DocumentJson::io_ts_codec();
+        #[cfg(not(feature = "object_id_flexible"))]
+        assert!(codec.contains("id: t.type({ $oid: t.string }),"));
+        #[cfg(feature = "object_id_flexible")]
+        assert!(codec.contains("id: t.union([t.type({ $oid: t.string }), t.string]),"));
+        assert!(codec.contains("owners: t.record(t.string, t.string),"));
+    }
+}
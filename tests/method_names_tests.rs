@@ -0,0 +1,102 @@
+#[cfg(all(test, any(feature = "typescript", feature = "jsonschema", feature = "zod")))]
+mod tests {
+    use std::collections::HashMap;
+
+    use tixschema::{model_schema, model_schema_alias, model_schema_union};
+
+    // A type with renamed methods, referenced from other generated code
+    #[model_schema(ts_definition = "to_ts", zod_schema = "zod", json_schema = "schema")]
+    #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[allow(dead_code)]
+    struct SeatLockJson {
+        seat: String,
+        until: u64,
+    }
+
+    #[model_schema(json_schema = "schema")]
+    #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[allow(dead_code)]
+    enum LockStateJson {
+        Held,
+        Released,
+    }
+
+    #[model_schema()]
+    #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[allow(dead_code)]
+    struct CheckoutJson {
+        lock: SeatLockJson,
+        locks: Vec<SeatLockJson>,
+        by_seat: HashMap<String, SeatLockJson>,
+        state: LockStateJson,
+    }
+
+    #[cfg(feature = "serde")]
+    #[model_schema()]
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+    #[allow(dead_code)]
+    struct FlatCheckoutJson {
+        id: String,
+        #[serde(flatten)]
+        lock: SeatLockJson,
+    }
+
+    model_schema_union!(LockOrStateJson = SeatLockJson | LockStateJson);
+    model_schema_alias!(HoldJson = SeatLockJson);
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_nested_renamed_json_schema() {
+        let schema = CheckoutJson::json_schema();
+        let properties = &schema["properties"];
+
+        assert_eq!(properties["lock"], SeatLockJson::schema());
+        assert_eq!(properties["locks"]["items"], SeatLockJson::schema());
+        assert_eq!(properties["by_seat"]["additionalProperties"], SeatLockJson::schema());
+        assert_eq!(properties["state"], LockStateJson::schema());
+    }
+
+    #[test]
+    #[cfg(all(feature = "jsonschema", feature = "serde"))]
+    fn test_flattened_renamed_json_schema() {
+        let schema = FlatCheckoutJson::json_schema();
+        assert_eq!(schema["allOf"][0]["properties"], SeatLockJson::schema()["properties"]);
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_union_and_alias_of_renamed_json_schema() {
+        assert_eq!(
+            LockOrStateJson::json_schema()["oneOf"],
+            serde_json::json!([SeatLockJson::schema(), LockStateJson::schema()])
+        );
+        assert_eq!(HoldJson::json_schema(), SeatLockJson::schema());
+
+        let components = tixschema::model_schema_components!(SeatLockJson, CheckoutJson);
+        assert_eq!(components["SeatLock"], SeatLockJson::schema());
+        assert_eq!(components["Checkout"]["properties"]["lock"]["$ref"], "#/components/schemas/SeatLock");
+    }
+
+    #[test]
+    #[cfg(all(feature = "typescript", feature = "zod"))]
+    fn test_bundle_of_renamed_methods() {
+        let bundle = tixschema::model_schema_bundle!(CheckoutJson, SeatLockJson, LockStateJson);
+
+        assert!(bundle.contains(&SeatLockJson::to_ts()));
+        assert!(bundle.contains(&SeatLockJson::zod()));
+        assert!(bundle.find("export const SeatLock$Schema").unwrap() < bundle.find("export const Checkout$Schema").unwrap());
+    }
+
+    #[test]
+    fn test_hidden_aliases_forward_to_renamed_methods() {
+        #[cfg(feature = "typescript")]
+        assert_eq!(SeatLockJson::__tixschema_ts_definition(), SeatLockJson::to_ts());
+        #[cfg(feature = "zod")]
+        assert_eq!(SeatLockJson::__tixschema_zod_schema(), SeatLockJson::zod());
+        #[cfg(feature = "jsonschema")]
+        assert_eq!(SeatLockJson::__tixschema_json_schema(), SeatLockJson::schema());
+    }
+}
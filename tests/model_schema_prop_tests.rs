@@ -412,4 +412,1148 @@ mod tests {
         assert_eq!(normal_prop["minLength"], 1);
         assert!(normal_prop.get("const").is_none());
     }
-} 
\ No newline at end of file
+
+    // Test wire-level deprecation independent of Rust's #[deprecated]
+    #[cfg(all(
+        test,
+        any(
+            feature = "typescript",
+            feature = "jsonschema", 
+            feature = "zod",
+            feature = "serde"
+        )
+    ))]
+    #[model_schema()]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq)]
+    struct DeprecatedFieldJson {
+        pub id: String,
+        /// The old display name
+        #[model_schema_prop(deprecated = "use display_name")]
+        pub name: Option<String>,
+        pub display_name: String,
+    }
+
+    #[test]
+    #[cfg(feature = "typescript")]
+    fn test_deprecated_typescript() {
+        let ts_definition = DeprecatedFieldJson::ts_definition();
+
+        assert!(ts_definition.contains(" * The old display name\n * \n * @deprecated use display_name\n**/\n  name: string | undefined;"));
+        assert_eq!(ts_definition.matches("@deprecated").count(), 1);
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_deprecated_zod() {
+        let zod_schema = DeprecatedFieldJson::zod_schema();
+
+        // Deprecation does not change validation
+        assert!(zod_schema.contains("name: z.string().or(z.undefined())"));
+        assert!(!zod_schema.contains("deprecated"));
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_deprecated_json_schema() {
+        let schema = DeprecatedFieldJson::json_schema();
+        let properties = schema["properties"].as_object().unwrap();
+
+        let name_prop = &properties["name"];
+        assert_eq!(name_prop["type"], "string");
+        assert_eq!(name_prop["deprecated"], true);
+        assert_eq!(name_prop["$comment"], "use display_name");
+
+        let display_name_prop = &properties["display_name"];
+        assert!(display_name_prop.get("deprecated").is_none());
+        assert!(display_name_prop.get("$comment").is_none());
+    }
+
+    // Test request/response-only fields (OpenAPI readOnly / writeOnly)
+    #[cfg(all(
+        test,
+        any(
+            feature = "typescript",
+            feature = "jsonschema", 
+            feature = "zod",
+            feature = "serde"
+        )
+    ))]
+    #[model_schema()]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq)]
+    struct AccessModeJson {
+        #[model_schema_prop(read_only)]
+        pub id: String,
+        pub email: String,
+        #[model_schema_prop(write_only, minLength = 8)]
+        pub password: Option<String>,
+    }
+
+    #[test]
+    #[cfg(feature = "typescript")]
+    fn test_access_mode_typescript() {
+        let ts_definition = AccessModeJson::ts_definition();
+
+        assert!(ts_definition.contains("readonly id: string;"));
+        assert!(ts_definition.contains("  email: string;"));
+        assert!(!ts_definition.contains("readonly email"));
+        assert!(ts_definition.contains("  password: string | undefined;"));
+        assert!(!ts_definition.contains("readonly password"));
+        assert!(ts_definition.contains("Read-only: set by the server and ignored in requests"));
+        assert!(ts_definition.contains("Write-only: accepted in requests but never returned"));
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_access_mode_zod() {
+        let zod_schema = AccessModeJson::zod_schema();
+
+        assert!(zod_schema.contains("id: z.string(),"));
+        assert!(zod_schema.contains("password: z.string().min(8).or(z.undefined()),"));
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_access_mode_json_schema() {
+        let schema = AccessModeJson::json_schema();
+        let properties = schema["properties"].as_object().unwrap();
+
+        assert_eq!(properties["id"]["readOnly"], true);
+        assert!(properties["id"].get("writeOnly").is_none());
+        assert_eq!(properties["password"]["writeOnly"], true);
+        assert_eq!(properties["password"]["minLength"], 8);
+        assert!(properties["email"].get("readOnly").is_none());
+
+        // readOnly fields stay required: per OpenAPI, `required` only applies to
+        // responses for readOnly properties
+        let required = schema["required"].as_array().unwrap();
+        assert!(required.contains(&serde_json::Value::String("id".to_string())));
+        assert!(!required.contains(&serde_json::Value::String("password".to_string())));
+    }
+
+    #[cfg(all(
+        test,
+        any(
+            feature = "typescript",
+            feature = "jsonschema",
+            feature = "zod",
+            feature = "serde"
+        )
+    ))]
+    #[model_schema()]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq)]
+    struct NormalizedInputJson {
+        #[model_schema_prop(trim, lowercase, minLength = 3)]
+        pub email: String,
+        #[model_schema_prop(minLength = 2, uppercase)]
+        pub country_code: Option<String>,
+        #[model_schema_prop(trim)]
+        pub nickname: String,
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_string_transforms_zod() {
+        let zod_schema = NormalizedInputJson::zod_schema();
+
+        // Transforms always come before .min(), regardless of attribute order
+        assert!(zod_schema.contains("email: z.string().trim().toLowerCase().min(3),"));
+        assert!(zod_schema.contains("country_code: z.string().toUpperCase().min(2).or(z.undefined()),"));
+        assert!(zod_schema.contains("nickname: z.string().trim(),"));
+    }
+
+    #[test]
+    #[cfg(feature = "typescript")]
+    fn test_string_transforms_typescript() {
+        let ts_definition = NormalizedInputJson::ts_definition();
+
+        assert!(ts_definition.contains("  email: string;"));
+        assert!(ts_definition.contains("Normalized by the Zod schema: trim, toLowerCase"));
+        assert!(ts_definition.contains("Normalized by the Zod schema: toUpperCase"));
+        assert!(!ts_definition.contains(".trim()"));
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_string_transforms_json_schema_unchanged() {
+        let schema = NormalizedInputJson::json_schema();
+        let properties = schema["properties"].as_object().unwrap();
+
+        assert_eq!(properties["email"]["type"], "string");
+        assert_eq!(properties["email"]["minLength"], 3);
+        assert_eq!(properties["nickname"], serde_json::json!({ "type": "string" }));
+    }
+
+    #[cfg(all(
+        test,
+        any(
+            feature = "typescript",
+            feature = "jsonschema",
+            feature = "zod",
+            feature = "serde"
+        )
+    ))]
+    #[model_schema()]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq)]
+    struct ShippingAddressJson {
+        pub street: String,
+        pub country: Option<String>,
+        #[model_schema_prop(required_with = "country")]
+        pub region: Option<String>,
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_required_with_json_schema() {
+        let schema = ShippingAddressJson::json_schema();
+
+        assert_eq!(
+            schema["dependentRequired"],
+            serde_json::json!({ "country": ["region"] })
+        );
+        let required = schema["required"].as_array().unwrap();
+        assert!(!required.contains(&serde_json::json!("region")));
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_required_with_zod_refine() {
+        let zod_schema = ShippingAddressJson::zod_schema();
+
+        assert!(zod_schema.contains("region: z.string().or(z.undefined()),"));
+        assert!(zod_schema.ends_with(
+            "}).refine(v => v[\"country\"] === undefined || v[\"region\"] !== undefined, { message: \"region is required when country is present\", path: [\"region\"] });"
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "typescript")]
+    fn test_required_with_typescript_docs() {
+        let ts_definition = ShippingAddressJson::ts_definition();
+
+        assert!(ts_definition.contains("Required when `country` is present"));
+        assert!(ts_definition.contains("region: string | undefined;"));
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_struct_without_required_with_has_no_dependent_required() {
+        assert!(AccessModeJson::json_schema().get("dependentRequired").is_none());
+    }
+
+    // String fields restricted to a closed set of values
+    #[cfg(all(
+        test,
+        any(
+            feature = "typescript",
+            feature = "jsonschema",
+            feature = "zod",
+            feature = "serde"
+        )
+    ))]
+    #[model_schema()]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq)]
+    struct PaletteJson {
+        #[model_schema_prop(enum_values = ["red", "green", "blue"])]
+        primary: String,
+        #[model_schema_prop(enum_values = ["light", "dark"])]
+        theme: Option<String>,
+        #[model_schema_prop(enum_values = ["red", "green", "blue"])]
+        accents: Vec<String>,
+        #[model_schema_prop(enum_values = ["say \"hi\"", "C:\\temp"])]
+        quoted: String,
+        name: String,
+    }
+
+    #[test]
+    #[cfg(feature = "typescript")]
+    fn test_enum_values_typescript() {
+        let ts_definition = PaletteJson::ts_definition();
+
+        assert!(ts_definition.contains("primary: \"red\" | \"green\" | \"blue\";"));
+        assert!(ts_definition.contains("theme: \"light\" | \"dark\" | undefined;"));
+        assert!(ts_definition.contains("accents: Array<\"red\" | \"green\" | \"blue\">;"));
+        assert!(ts_definition.contains(r#"quoted: "say \"hi\"" | "C:\\temp";"#));
+        assert!(ts_definition.contains("name: string;"));
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_enum_values_zod_schema() {
+        let zod_schema = PaletteJson::zod_schema();
+
+        assert!(zod_schema.contains("primary: z.enum([\"red\", \"green\", \"blue\"]),"));
+        assert!(zod_schema.contains("theme: z.enum([\"light\", \"dark\"]).or(z.undefined()),"));
+        assert!(zod_schema.contains("accents: z.array(z.enum([\"red\", \"green\", \"blue\"])),"));
+        assert!(zod_schema.contains(r#"quoted: z.enum(["say \"hi\"", "C:\\temp"]),"#));
+        assert!(zod_schema.contains("name: z.string(),"));
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_enum_values_json_schema() {
+        let schema = PaletteJson::json_schema();
+        let properties = schema["properties"].as_object().unwrap();
+
+        assert_eq!(
+            properties["primary"],
+            serde_json::json!({ "type": "string", "enum": ["red", "green", "blue"] })
+        );
+        assert_eq!(
+            properties["theme"],
+            serde_json::json!({ "type": "string", "enum": ["light", "dark"] })
+        );
+        assert_eq!(properties["accents"]["type"], "array");
+        assert_eq!(
+            properties["accents"]["items"],
+            serde_json::json!({ "type": "string", "enum": ["red", "green", "blue"] })
+        );
+        assert_eq!(
+            properties["quoted"],
+            serde_json::json!({ "type": "string", "enum": ["say \"hi\"", "C:\\temp"] })
+        );
+        assert_eq!(properties["name"], serde_json::json!({ "type": "string" }));
+    }
+
+    // `as` overrides the element type of Vec / Option fields
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq)]
+    struct InternalId(u64);
+
+    #[cfg(all(
+        test,
+        any(
+            feature = "typescript",
+            feature = "jsonschema",
+            feature = "zod",
+            feature = "serde"
+        )
+    ))]
+    #[model_schema()]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq)]
+    struct LinkedRecordsJson {
+        #[model_schema_prop(as = String)]
+        owner: InternalId,
+        #[model_schema_prop(as = String)]
+        members: Vec<InternalId>,
+        #[model_schema_prop(as = String)]
+        watchers: Option<Vec<InternalId>>,
+    }
+
+    #[test]
+    #[cfg(feature = "typescript")]
+    fn test_as_element_override_typescript() {
+        let ts_definition = LinkedRecordsJson::ts_definition();
+
+        assert!(ts_definition.contains("owner: string;"));
+        assert!(ts_definition.contains("members: Array<string>;"));
+        assert!(ts_definition.contains("watchers: Array<string> | undefined;"));
+        assert!(!ts_definition.contains("InternalId"));
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_as_element_override_zod_schema() {
+        let zod_schema = LinkedRecordsJson::zod_schema();
+
+        assert!(zod_schema.contains("owner: z.string(),"));
+        assert!(zod_schema.contains("members: z.array(z.string()),"));
+        assert!(zod_schema.contains("watchers: z.array(z.string()).or(z.undefined()),"));
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_as_element_override_json_schema() {
+        let schema = LinkedRecordsJson::json_schema();
+        let properties = schema["properties"].as_object().unwrap();
+        let string_array = serde_json::json!({ "type": "array", "items": { "type": "string" } });
+
+        assert_eq!(properties["owner"], serde_json::json!({ "type": "string" }));
+        assert_eq!(properties["members"], string_array);
+        assert_eq!(properties["watchers"], string_array);
+        assert_eq!(schema["required"], serde_json::json!(["owner", "members"]));
+    }
+
+    // `as = serde_json::Value` / `as = "any"` turn a concrete field into arbitrary JSON
+    #[cfg(all(
+        test,
+        any(
+            feature = "typescript",
+            feature = "jsonschema",
+            feature = "zod",
+            feature = "serde"
+        )
+    ))]
+    #[model_schema()]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq)]
+    struct WebhookDeliveryJson {
+        #[model_schema_prop(as = serde_json::Value)]
+        payload: InternalId,
+        #[model_schema_prop(as = "any")]
+        context: Option<InternalId>,
+        #[model_schema_prop(as = "any")]
+        attachments: Vec<InternalId>,
+        raw: serde_json::Value,
+    }
+
+    #[test]
+    #[cfg(feature = "typescript")]
+    fn test_as_any_typescript() {
+        let ts_definition = WebhookDeliveryJson::ts_definition();
+
+        assert!(ts_definition.contains("payload: unknown;"));
+        assert!(ts_definition.contains("context: unknown | undefined;"));
+        assert!(ts_definition.contains("attachments: Array<unknown>;"));
+        assert!(ts_definition.contains("raw: unknown;"));
+        assert!(!ts_definition.contains("InternalId"));
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_as_any_zod_schema() {
+        let zod_schema = WebhookDeliveryJson::zod_schema();
+
+        assert!(zod_schema.contains("payload: z.unknown(),"));
+        assert!(zod_schema.contains("context: z.unknown().or(z.undefined()),"));
+        assert!(zod_schema.contains("attachments: z.array(z.unknown()),"));
+        assert!(zod_schema.contains("raw: z.unknown(),"));
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_as_any_json_schema() {
+        let schema = WebhookDeliveryJson::json_schema();
+        let properties = schema["properties"].as_object().unwrap();
+
+        assert_eq!(properties["payload"], serde_json::json!({}));
+        assert_eq!(properties["context"], serde_json::json!({}));
+        assert_eq!(properties["attachments"], serde_json::json!({ "type": "array", "items": {} }));
+        assert_eq!(properties["raw"], serde_json::json!({}));
+        assert_eq!(schema["required"], serde_json::json!(["payload", "attachments", "raw"]));
+    }
+
+    // The other way around: `serde_json::Value` fields known to hold a specific type
+    #[cfg(all(
+        test,
+        any(
+            feature = "typescript",
+            feature = "jsonschema",
+            feature = "zod",
+            feature = "serde"
+        )
+    ))]
+    #[model_schema()]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq)]
+    struct LegacyOrderJson {
+        #[model_schema_prop(as = String)]
+        reference: serde_json::Value,
+        #[model_schema_prop(as = u32)]
+        quantity: serde_json::Value,
+        #[model_schema_prop(as = bool)]
+        gift: Option<serde_json::Value>,
+    }
+
+    #[test]
+    #[cfg(feature = "typescript")]
+    fn test_as_overrides_value_typescript() {
+        let ts_definition = LegacyOrderJson::ts_definition();
+
+        assert!(ts_definition.contains("reference: string;"));
+        assert!(ts_definition.contains("quantity: number;"));
+        assert!(ts_definition.contains("gift: boolean | undefined;"));
+        assert!(!ts_definition.contains("unknown"));
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_as_overrides_value_zod_schema() {
+        let zod_schema = LegacyOrderJson::zod_schema();
+
+        assert!(zod_schema.contains("reference: z.string(),"));
+        assert!(zod_schema.contains("quantity: z.number().int(),"));
+        assert!(zod_schema.contains("gift: z.boolean().or(z.undefined()),"));
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_as_overrides_value_json_schema() {
+        let schema = LegacyOrderJson::json_schema();
+        let properties = &schema["properties"];
+
+        assert_eq!(properties["reference"], serde_json::json!({ "type": "string" }));
+        assert_eq!(properties["quantity"], serde_json::json!({ "type": "integer" }));
+        assert_eq!(properties["gift"], serde_json::json!({ "type": "boolean" }));
+        assert_eq!(schema["required"], serde_json::json!(["reference", "quantity"]));
+    }
+
+    // Most fields come from a JSON body, `page` and `archived` from the query string
+    #[cfg(all(
+        test,
+        any(
+            feature = "typescript",
+            feature = "jsonschema",
+            feature = "zod",
+            feature = "serde"
+        )
+    ))]
+    #[model_schema()]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq)]
+    struct TicketSearchJson {
+        term: String,
+        #[model_schema_prop(coerce)]
+        page: u32,
+        limit: Option<u32>,
+        #[model_schema_prop(coerce)]
+        archived: Option<bool>,
+        #[model_schema_prop(coerce)]
+        min_score: Vec<f64>,
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_coerce_zod_schema() {
+        let zod_schema = TicketSearchJson::zod_schema();
+
+        assert!(zod_schema.contains("page: z.coerce.number().int(),"));
+        assert!(zod_schema.contains("archived: z.coerce.boolean().or(z.undefined()),"));
+        assert!(zod_schema.contains("min_score: z.array(z.coerce.number()),"));
+        // The other fields keep their strict schemas
+        assert!(zod_schema.contains("term: z.string(),"));
+        assert!(zod_schema.contains("limit: z.number().int().or(z.undefined()),"));
+        let strict_schema = zod_schema.split("$LooseSchema").next().unwrap();
+        assert_eq!(strict_schema.matches("z.coerce.").count(), 3);
+    }
+
+    #[test]
+    #[cfg(feature = "typescript")]
+    fn test_coerce_keeps_typescript_types() {
+        let ts_definition = TicketSearchJson::ts_definition();
+
+        assert!(ts_definition.contains("page: number;"));
+        assert!(ts_definition.contains("archived: boolean | undefined;"));
+        assert!(ts_definition.contains("Coerced by the Zod schema"));
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_coerce_keeps_json_schema() {
+        let schema = TicketSearchJson::json_schema();
+
+        assert_eq!(schema["properties"]["page"]["type"], "integer");
+        assert_eq!(schema["properties"]["archived"], serde_json::json!({ "type": "boolean" }));
+    }
+
+    // Prefixed IDs, typed as template literals in TypeScript
+    #[cfg(all(
+        test,
+        any(
+            feature = "typescript",
+            feature = "jsonschema",
+            feature = "zod",
+            feature = "serde"
+        )
+    ))]
+    #[model_schema()]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq)]
+    struct SeatAssignmentJson {
+        #[model_schema_prop(ts_template = "seat_${string}")]
+        seat_id: String,
+        #[model_schema_prop(ts_template = "row-${number}")]
+        row: Option<String>,
+        #[model_schema_prop(ts_template = "usr_${string}", minLength = 5)]
+        holders: Vec<String>,
+    }
+
+    #[test]
+    #[cfg(feature = "typescript")]
+    fn test_ts_template_typescript() {
+        let ts_definition = SeatAssignmentJson::ts_definition();
+
+        assert!(ts_definition.contains("seat_id: `seat_${string}`;"));
+        assert!(ts_definition.contains("row: `row-${number}` | undefined;"));
+        assert!(ts_definition.contains("holders: Array<`usr_${string}`>;"));
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_ts_template_zod_schema() {
+        let zod_schema = SeatAssignmentJson::zod_schema();
+
+        assert!(zod_schema.contains("seat_id: z.string().regex(/^seat_.*$/),"));
+        assert!(zod_schema.contains(r"row: z.string().regex(/^row--?\d+(\.\d+)?$/).or(z.undefined()),"));
+        assert!(zod_schema.contains("holders: z.array(z.string().regex(/^usr_.*$/).min(5)),"));
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_ts_template_json_schema() {
+        let schema = SeatAssignmentJson::json_schema();
+        let pattern = |value: &serde_json::Value| regex::Regex::new(value["pattern"].as_str().unwrap()).unwrap();
+
+        let seat_id = pattern(&schema["properties"]["seat_id"]);
+        assert!(seat_id.is_match("seat_A12"));
+        assert!(!seat_id.is_match("A12"));
+
+        let row = pattern(&schema["properties"]["row"]);
+        assert!(row.is_match("row-12"));
+        assert!(row.is_match("row--1.5"));
+        assert!(!row.is_match("row-twelve"));
+
+        assert_eq!(schema["properties"]["holders"]["items"]["minLength"], 5);
+        assert!(pattern(&schema["properties"]["holders"]["items"]).is_match("usr_42"));
+    }
+
+    // Single characters, alone and with the same constraints as strings
+    #[cfg(all(
+        test,
+        any(
+            feature = "typescript",
+            feature = "jsonschema",
+            feature = "zod",
+            feature = "serde"
+        )
+    ))]
+    #[model_schema()]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq)]
+    struct SeatLabelJson {
+        #[model_schema_prop(pattern = "[A-Z]")]
+        row: char,
+        section: Option<char>,
+        #[model_schema_prop(pattern = "^[a-z]+/[0-9]+$", minLength = 3)]
+        code: String,
+        by_letter: std::collections::HashMap<char, u32>,
+    }
+
+    #[test]
+    #[cfg(feature = "typescript")]
+    fn test_char_typescript() {
+        let ts_definition = SeatLabelJson::ts_definition();
+
+        assert!(ts_definition.contains("row: string;"));
+        assert!(ts_definition.contains("section: string | undefined;"));
+        assert!(ts_definition.contains("by_letter: Partial<Record<string, number>>;"));
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_char_constraints_zod_schema() {
+        let zod_schema = SeatLabelJson::zod_schema();
+
+        assert!(zod_schema.contains("row: z.string().length(1).regex(/[A-Z]/),"));
+        assert!(zod_schema.contains("section: z.string().length(1).or(z.undefined()),"));
+        assert!(zod_schema.contains(r"code: z.string().regex(/^[a-z]+\/[0-9]+$/).min(3),"));
+        assert!(zod_schema.contains("by_letter: z.record(z.string().length(1), z.number().int()),"));
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_char_constraints_json_schema() {
+        let schema = SeatLabelJson::json_schema();
+        let properties = &schema["properties"];
+
+        assert_eq!(
+            properties["row"],
+            serde_json::json!({ "type": "string", "minLength": 1, "maxLength": 1, "pattern": "[A-Z]" })
+        );
+        assert_eq!(properties["section"], serde_json::json!({ "type": "string", "minLength": 1, "maxLength": 1 }));
+        assert_eq!(
+            properties["code"],
+            serde_json::json!({ "type": "string", "minLength": 3, "pattern": "^[a-z]+/[0-9]+$" })
+        );
+        assert_eq!(
+            properties["by_letter"]["propertyNames"],
+            serde_json::json!({ "type": "string", "minLength": 1, "maxLength": 1 })
+        );
+        assert_eq!(schema["required"], serde_json::json!(["row", "code", "by_letter"]));
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde", feature = "jsonschema"))]
+    fn test_char_serializes_as_one_character_string() {
+        let value = serde_json::to_value(SeatLabelJson {
+            row: 'C',
+            section: Some('é'),
+            code: "ab/12".to_string(),
+            by_letter: [('x', 1)].into_iter().collect(),
+        })
+        .unwrap();
+
+        assert_eq!(value["row"], "C");
+        assert_eq!(value["section"].as_str().unwrap().chars().count(), 1);
+        assert_eq!(value["by_letter"]["x"], 1);
+    }
+
+    // maxLength alone, combined with minLength, and on a field it doesn't apply to
+    #[cfg(all(
+        test,
+        any(
+            feature = "typescript",
+            feature = "jsonschema",
+            feature = "zod",
+            feature = "serde"
+        )
+    ))]
+    #[model_schema()]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq)]
+    struct PromoCodeJson {
+        #[model_schema_prop(maxLength = 16)]
+        code: String,
+        #[model_schema_prop(minLength = 3, maxLength = 40)]
+        label: Option<String>,
+        #[model_schema_prop(maxLength = 8)]
+        tags: Vec<String>,
+        #[model_schema_prop(maxLength = 5)]
+        uses: u32,
+    }
+
+    #[test]
+    #[cfg(feature = "typescript")]
+    fn test_max_length_typescript_docs() {
+        let ts_definition = PromoCodeJson::ts_definition();
+
+        assert!(ts_definition.contains("Maximum length: 16"));
+        assert!(ts_definition.contains("Minimum length: 3\n * Maximum length: 40"));
+        assert!(ts_definition.contains("Maximum length: 8"));
+        assert!(!ts_definition.contains("Maximum length: 5"));
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_max_length_zod_schema() {
+        let zod_schema = PromoCodeJson::zod_schema();
+
+        assert!(zod_schema.contains("code: z.string().max(16),"));
+        assert!(zod_schema.contains("label: z.string().min(3).max(40).or(z.undefined()),"));
+        assert!(zod_schema.contains("tags: z.array(z.string().max(8)),"));
+        assert!(zod_schema.contains("uses: z.number().int(),"));
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_max_length_json_schema() {
+        let schema = PromoCodeJson::json_schema();
+        let properties = &schema["properties"];
+
+        assert_eq!(properties["code"], serde_json::json!({ "type": "string", "maxLength": 16 }));
+        assert_eq!(
+            properties["label"],
+            serde_json::json!({ "type": "string", "minLength": 3, "maxLength": 40 })
+        );
+        assert_eq!(properties["tags"]["items"], serde_json::json!({ "type": "string", "maxLength": 8 }));
+        assert!(properties["uses"].get("maxLength").is_none());
+    }
+
+    // Numeric bounds on integers, floats, sequences and NonZero integers
+    #[cfg(all(
+        test,
+        any(
+            feature = "typescript",
+            feature = "jsonschema",
+            feature = "zod",
+            feature = "serde"
+        )
+    ))]
+    #[model_schema()]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq)]
+    struct TicketTierJson {
+        #[model_schema_prop(min = 0, max = 100)]
+        discount_percent: u8,
+        #[model_schema_prop(min = 0.5, max = 999.99)]
+        price: f64,
+        #[model_schema_prop(min = -10)]
+        floor: Option<i32>,
+        #[model_schema_prop(min = 0)]
+        seat_counts: Vec<u32>,
+        #[model_schema_prop(max = 10)]
+        max_per_order: std::num::NonZeroU32,
+    }
+
+    #[test]
+    #[cfg(feature = "typescript")]
+    fn test_numeric_bounds_typescript_docs() {
+        let ts_definition = TicketTierJson::ts_definition();
+
+        assert!(ts_definition.contains("Minimum: 0\n * Maximum: 100"));
+        assert!(ts_definition.contains("Minimum: 0.5\n * Maximum: 999.99"));
+        assert!(ts_definition.contains("Minimum: -10"));
+        assert!(ts_definition.contains("discount_percent: number;"));
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_numeric_bounds_zod_schema() {
+        let zod_schema = TicketTierJson::zod_schema();
+
+        assert!(zod_schema.contains("discount_percent: z.number().int().min(0).max(100),"));
+        assert!(zod_schema.contains("price: z.number().min(0.5).max(999.99),"));
+        assert!(zod_schema.contains("floor: z.number().int().min(-10).or(z.undefined()),"));
+        assert!(zod_schema.contains("seat_counts: z.array(z.number().int().min(0)),"));
+        assert!(zod_schema.contains("max_per_order: z.number().int().min(1).max(10),"));
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_numeric_bounds_json_schema() {
+        let schema = TicketTierJson::json_schema();
+        let properties = &schema["properties"];
+
+        assert_eq!(
+            properties["discount_percent"],
+            serde_json::json!({ "type": "integer", "minimum": 0, "maximum": 100 })
+        );
+        assert_eq!(properties["price"]["minimum"], 0.5);
+        assert_eq!(properties["price"]["maximum"], 999.99);
+        assert_eq!(properties["floor"], serde_json::json!({ "type": "integer", "minimum": -10 }));
+        assert_eq!(
+            properties["seat_counts"],
+            serde_json::json!({ "type": "array", "items": { "type": "integer", "minimum": 0 } })
+        );
+        assert_eq!(
+            properties["max_per_order"],
+            serde_json::json!({ "type": "integer", "minimum": 1, "maximum": 10 })
+        );
+    }
+
+    // String constraints on optional strings, whichever way the optional is written
+    #[cfg(all(
+        test,
+        any(
+            feature = "typescript",
+            feature = "jsonschema",
+            feature = "zod",
+            feature = "serde"
+        )
+    ))]
+    #[model_schema(optionals_nullable)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq)]
+    struct GuestProfileJson {
+        #[model_schema_prop(minLength = 3, maxLength = 20, pattern = "^[a-z_]+$")]
+        handle: Option<String>,
+        #[model_schema_prop(minLength = 2)]
+        aliases: Option<Vec<String>>,
+    }
+
+    #[cfg(all(
+        test,
+        any(
+            feature = "typescript",
+            feature = "jsonschema",
+            feature = "zod",
+            feature = "serde"
+        )
+    ))]
+    #[model_schema(tag = "kind")]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(tag = "kind"))]
+    #[derive(Debug, Clone, PartialEq)]
+    enum GuestNoteJson {
+        Comment {
+            #[model_schema_prop(minLength = 1, maxLength = 280)]
+            text: Option<String>,
+        },
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_optional_string_constraints_json_schema() {
+        let properties = &MinLengthTestJson::json_schema()["properties"];
+        assert_eq!(properties["nickname"], serde_json::json!({ "type": "string", "minLength": 3 }));
+
+        let properties = &GuestProfileJson::json_schema()["properties"];
+        assert_eq!(
+            properties["handle"],
+            serde_json::json!({
+                "type": ["string", "null"],
+                "minLength": 3,
+                "maxLength": 20,
+                "pattern": "^[a-z_]+$"
+            })
+        );
+        assert_eq!(
+            properties["aliases"],
+            serde_json::json!({ "type": ["array", "null"], "items": { "type": "string", "minLength": 2 } })
+        );
+
+        let comment = &GuestNoteJson::json_schema()["oneOf"][0];
+        assert_eq!(
+            comment["properties"]["text"],
+            serde_json::json!({ "type": "string", "minLength": 1, "maxLength": 280 })
+        );
+        assert_eq!(comment["required"], serde_json::json!(["kind"]));
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_optional_string_constraints_zod_schema() {
+        let zod_schema = GuestProfileJson::zod_schema();
+        assert!(zod_schema.contains("handle: z.string().regex(/^[a-z_]+$/).min(3).max(20).or(z.null()),"));
+        assert!(zod_schema.contains("aliases: z.array(z.string().min(2)).or(z.null()),"));
+
+        assert!(GuestNoteJson::zod_schema().contains("text: z.string().min(1).max(280).or(z.undefined())"));
+    }
+
+    // Strings holding serialized JSON
+    #[cfg(all(
+        test,
+        any(
+            feature = "typescript",
+            feature = "jsonschema",
+            feature = "zod",
+            feature = "serde"
+        )
+    ))]
+    #[model_schema()]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq)]
+    struct WebhookPayloadJson {
+        #[model_schema_prop(raw_json)]
+        body: String,
+        #[model_schema_prop(raw_json(parse))]
+        headers: String,
+        #[model_schema_prop(raw_json(parse))]
+        replay: Option<String>,
+    }
+
+    #[test]
+    #[cfg(feature = "typescript")]
+    fn test_raw_json_typescript() {
+        let ts_definition = WebhookPayloadJson::ts_definition();
+
+        assert!(ts_definition.contains("body: unknown;"));
+        assert!(ts_definition.contains("headers: unknown;"));
+        assert!(ts_definition.contains("replay: unknown | undefined;"));
+        assert!(ts_definition.contains("Raw JSON"));
+        assert!(ts_definition.contains("Serialized JSON, parsed by the Zod schema"));
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_raw_json_zod_schema() {
+        let zod_schema = WebhookPayloadJson::zod_schema();
+
+        assert!(zod_schema.contains("body: z.unknown(),"));
+        assert!(zod_schema.contains("headers: z.string().transform((raw) => JSON.parse(raw)),"));
+        assert!(zod_schema.contains("replay: z.string().transform((raw) => JSON.parse(raw)).or(z.undefined()),"));
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_raw_json_json_schema() {
+        let schema = WebhookPayloadJson::json_schema();
+        let properties = &schema["properties"];
+
+        assert_eq!(properties["body"], serde_json::json!({}));
+        assert_eq!(
+            properties["headers"],
+            serde_json::json!({ "type": "string", "contentMediaType": "application/json" })
+        );
+        assert_eq!(schema["required"], serde_json::json!(["body", "headers"]));
+    }
+
+    // Patterns with backslashes, quotes and slashes, chained with the length checks
+    #[cfg(all(
+        test,
+        any(
+            feature = "typescript",
+            feature = "jsonschema",
+            feature = "zod",
+            feature = "serde"
+        )
+    ))]
+    #[model_schema()]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq)]
+    struct OrganizerContactJson {
+        #[model_schema_prop(pattern = r"^[^@\s]+@[^@\s]+\.[a-z]{2,}$", minLength = 6, maxLength = 254)]
+        email: String,
+        #[model_schema_prop(pattern = "^[a-z0-9]+(?:-[a-z0-9]+)*$", maxLength = 64)]
+        slug: Option<String>,
+        #[model_schema_prop(pattern = r#"^[^"]*$"#)]
+        display_name: String,
+        #[model_schema_prop(pattern = "^https?://")]
+        website: String,
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_pattern_chains_with_lengths_zod_schema() {
+        let zod_schema = OrganizerContactJson::zod_schema();
+
+        assert!(zod_schema.contains(r"email: z.string().regex(/^[^@\s]+@[^@\s]+\.[a-z]{2,}$/).min(6).max(254),"));
+        assert!(zod_schema.contains("slug: z.string().regex(/^[a-z0-9]+(?:-[a-z0-9]+)*$/).max(64).or(z.undefined()),"));
+        assert!(zod_schema.contains(r#"display_name: z.string().regex(/^[^"]*$/),"#));
+        // An unescaped `/` would end the regex literal
+        assert!(zod_schema.contains(r"website: z.string().regex(/^https?:\/\//),"));
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_pattern_chains_with_lengths_json_schema() {
+        let schema = OrganizerContactJson::json_schema();
+        let properties = &schema["properties"];
+
+        assert_eq!(
+            properties["email"],
+            serde_json::json!({
+                "type": "string",
+                "minLength": 6,
+                "maxLength": 254,
+                "pattern": r"^[^@\s]+@[^@\s]+\.[a-z]{2,}$"
+            })
+        );
+        assert_eq!(properties["slug"]["pattern"], "^[a-z0-9]+(?:-[a-z0-9]+)*$");
+        assert_eq!(properties["display_name"]["pattern"], r#"^[^"]*$"#);
+        assert_eq!(properties["website"]["pattern"], "^https?://");
+
+        // The JSON text escapes the pattern as a string
+        let json_text = serde_json::to_string(&properties["display_name"]).unwrap();
+        assert!(json_text.contains(r#""pattern":"^[^\"]*$""#));
+    }
+
+    // Known formats get their Zod validator; unknown ones only the JSON schema `format`
+    #[cfg(all(
+        test,
+        any(
+            feature = "typescript",
+            feature = "jsonschema",
+            feature = "zod",
+            feature = "serde"
+        )
+    ))]
+    #[model_schema()]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq)]
+    struct BoxOfficeContactJson {
+        #[model_schema_prop(format = "email", maxLength = 254)]
+        email: String,
+        #[model_schema_prop(format = "uri")]
+        website: Option<String>,
+        #[model_schema_prop(format = "date-time")]
+        opened_at: String,
+        #[model_schema_prop(format = "date")]
+        season_start: String,
+        #[model_schema_prop(format = "uuid")]
+        external_id: String,
+        #[model_schema_prop(format = "ipv4")]
+        kiosk_ip: String,
+        #[model_schema_prop(format = "ipv6")]
+        kiosk_ipv6: String,
+        #[model_schema_prop(format = "hostname")]
+        host: String,
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_string_format_zod_schema() {
+        let zod_schema = BoxOfficeContactJson::zod_schema();
+
+        assert!(zod_schema.contains("email: z.email().max(254),"));
+        assert!(zod_schema.contains("website: z.url().or(z.undefined()),"));
+        assert!(zod_schema.contains("opened_at: z.iso.datetime({ offset: true }),"));
+        assert!(zod_schema.contains("season_start: z.iso.date(),"));
+        assert!(zod_schema.contains("external_id: z.uuid(),"));
+        assert!(zod_schema.contains("kiosk_ip: z.ipv4(),"));
+        assert!(zod_schema.contains("kiosk_ipv6: z.ipv6(),"));
+        assert!(zod_schema.contains("host: z.string(),"));
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_string_format_json_schema() {
+        let schema = BoxOfficeContactJson::json_schema();
+        let properties = &schema["properties"];
+
+        assert_eq!(properties["email"], serde_json::json!({ "type": "string", "maxLength": 254, "format": "email" }));
+        for (field, format) in [
+            ("opened_at", "date-time"),
+            ("season_start", "date"),
+            ("external_id", "uuid"),
+            ("kiosk_ip", "ipv4"),
+            ("kiosk_ipv6", "ipv6"),
+            ("host", "hostname"),
+        ] {
+            assert_eq!(properties[field], serde_json::json!({ "type": "string", "format": format }), "{field}");
+        }
+        assert_eq!(properties["website"], serde_json::json!({ "type": "string", "format": "uri" }));
+    }
+
+    #[test]
+    #[cfg(feature = "typescript")]
+    fn test_string_format_typescript_docs() {
+        let ts_type = BoxOfficeContactJson::ts_definition();
+
+        assert!(ts_type.contains("email: string;"));
+        assert!(ts_type.contains(" * Format: email"));
+        assert!(ts_type.contains(" * Format: hostname (not checked by the Zod schema)"));
+    }
+
+    // Maps whose keys are constrained to lowercase slugs
+    #[cfg(all(
+        test,
+        any(
+            feature = "typescript",
+            feature = "jsonschema",
+            feature = "zod",
+            feature = "serde"
+        )
+    ))]
+    #[model_schema()]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq)]
+    struct VenueSectionsJson {
+        #[model_schema_prop(keyPattern = "^[a-z-]+$")]
+        capacity_by_section: std::collections::HashMap<String, u32>,
+        #[model_schema_prop(keyPattern = "^[a-z-]+$")]
+        labels: Option<std::collections::BTreeMap<String, Vec<String>>>,
+        prices: std::collections::HashMap<String, f64>,
+    }
+
+    #[test]
+    #[cfg(feature = "typescript")]
+    fn test_key_pattern_typescript() {
+        let ts_definition = VenueSectionsJson::ts_definition();
+
+        assert!(ts_definition.contains("capacity_by_section: Partial<Record<string, number>>;"));
+        assert!(ts_definition.contains(" * Key pattern: ^[a-z-]+$"));
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_key_pattern_zod_schema() {
+        let zod_schema = VenueSectionsJson::zod_schema();
+
+        assert!(zod_schema.contains("capacity_by_section: z.record(z.string().regex(/^[a-z-]+$/), z.number().int()),"));
+        assert!(zod_schema.contains("labels: z.record(z.string().regex(/^[a-z-]+$/), z.array(z.string())).or(z.undefined()),"));
+        assert!(zod_schema.contains("prices: z.record(z.string(), z.number()),"));
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_key_pattern_json_schema() {
+        let schema = VenueSectionsJson::json_schema();
+        let properties = &schema["properties"];
+
+        assert_eq!(
+            properties["capacity_by_section"],
+            serde_json::json!({
+                "type": "object",
+                "additionalProperties": { "type": "integer" },
+                "propertyNames": { "type": "string", "pattern": "^[a-z-]+$" }
+            })
+        );
+        assert_eq!(properties["labels"]["propertyNames"]["pattern"], "^[a-z-]+$");
+        assert!(properties["prices"].get("propertyNames").is_none());
+    }
+}
@@ -0,0 +1,89 @@
+// Nested types living in sibling modules, referenced by path rather than imported
+mod venues {
+    use tixschema::model_schema;
+
+    #[model_schema()]
+    #[derive(Debug, Clone, PartialEq)]
+    #[allow(dead_code)]
+    pub struct VenueJson {
+        pub name: String,
+        pub capacity: u32,
+    }
+}
+
+mod performers {
+    use tixschema::model_schema;
+
+    #[model_schema(tag = "kind")]
+    #[derive(Debug, Clone, PartialEq)]
+    #[allow(dead_code)]
+    pub enum PerformerJson {
+        Solo { name: String },
+        Band { members: Vec<String> },
+    }
+}
+
+mod events {
+    use std::collections::HashMap;
+    use tixschema::model_schema;
+
+    #[model_schema()]
+    #[derive(Debug, Clone, PartialEq)]
+    #[allow(dead_code)]
+    pub struct ConcertJson {
+        pub venue: super::venues::VenueJson,
+        pub support_venues: Vec<crate::venues::VenueJson>,
+        pub headliner: Option<super::performers::PerformerJson>,
+        pub stages: HashMap<String, super::venues::VenueJson>,
+    }
+}
+
+#[test]
+#[cfg(feature = "jsonschema")]
+fn test_nested_types_resolve_through_module_paths() {
+    let schema = events::ConcertJson::json_schema();
+    let venue = venues::VenueJson::json_schema();
+    assert_eq!(schema["properties"]["venue"], venue);
+    assert_eq!(schema["properties"]["support_venues"]["items"], venue);
+    assert_eq!(schema["properties"]["stages"]["additionalProperties"], venue);
+    assert_eq!(schema["properties"]["headliner"], performers::PerformerJson::json_schema());
+}
+
+#[test]
+#[cfg(feature = "typescript")]
+fn test_module_paths_are_dropped_from_typescript() {
+    let ts = events::ConcertJson::ts_definition();
+    assert!(ts.contains("venue: Venue;"));
+    assert!(ts.contains("support_venues: Array<Venue>;"));
+    assert_eq!(events::ConcertJson::schema_dependencies(), vec!["Performer", "Venue"]);
+}
+
+#[test]
+#[cfg(feature = "zod")]
+fn test_module_paths_are_dropped_from_zod() {
+    let zod_schema = events::ConcertJson::zod_schema();
+    assert!(zod_schema.contains("venue: Venue$Schema"));
+    assert!(!zod_schema.contains("::"));
+}
+
+#[test]
+#[cfg(feature = "jsonschema")]
+fn test_types_declared_inside_a_function() {
+    use tixschema::model_schema;
+
+    #[model_schema()]
+    #[derive(Debug, Clone, PartialEq)]
+    #[allow(dead_code)]
+    struct SeatJson {
+        row: String,
+    }
+
+    #[model_schema()]
+    #[derive(Debug, Clone, PartialEq)]
+    #[allow(dead_code)]
+    struct TicketJson {
+        seat: SeatJson,
+    }
+
+    assert_eq!(TicketJson::json_schema()["properties"]["seat"], SeatJson::json_schema());
+}
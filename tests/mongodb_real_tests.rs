@@ -2,7 +2,9 @@
 // These tests use the actual mongodb library to ensure our macro works
 // correctly with real MongoDB ObjectIds
 
-#[cfg(all(test, feature = "object_id"))]
+// These pin the extended JSON `{ $oid }` output, see object_id_flexible_tests.rs for the
+// `object_id_flexible` union
+#[cfg(all(test, feature = "object_id", not(feature = "object_id_flexible")))]
 mod tests {
     use tixschema::model_schema;
     use serde::{Deserialize, Serialize};
@@ -231,8 +233,7 @@ mod tests {
         
         let _user_ts = RealUserJson::ts_definition();
         let _document_ts = RealDocumentJson::ts_definition();
-        
+
         // If we get here without panics, real MongoDB ObjectId support is working
-        assert!(true);
     }
 } 
\ No newline at end of file
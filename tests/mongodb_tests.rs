@@ -1,4 +1,6 @@
-#[cfg(all(test, feature = "object_id"))]
+// These pin the extended JSON `{ $oid }` output, see object_id_flexible_tests.rs for the
+// `object_id_flexible` union
+#[cfg(all(test, feature = "object_id", not(feature = "object_id_flexible")))]
 mod tests {
 
     #[cfg(all(test, feature = "serde"))]
@@ -14,7 +16,7 @@ mod tests {
     // Mock ObjectId type for testing - compatible with mongodb::bson::oid::ObjectId
     // The real MongoDB ObjectId serializes to { "$oid": "hex_string" } in JSON
     // and to a plain string in other contexts
-    #[derive(Debug, Clone, PartialEq)]
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
     pub struct ObjectId(String);
 
     #[cfg(feature = "serde")]
@@ -117,6 +119,7 @@ mod tests {
     #[model_schema()]
     #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
     #[derive(Debug, Clone, PartialEq)]
+    #[allow(dead_code)]
     struct DocumentJson {
         id: ObjectId,
         title: String,
@@ -131,6 +134,7 @@ mod tests {
     #[model_schema()]
     #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
     #[derive(Debug, Clone, PartialEq)]
+    #[allow(dead_code)]
     struct UserWithHashMapObjectIdJson {
         id: ObjectId,
         name: String,
@@ -166,12 +170,23 @@ mod tests {
     #[model_schema()]
     #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
     #[derive(Debug, Clone, PartialEq)]
+    #[allow(dead_code)]
     struct UserWithOtherHashMapObjectIdJson {
         id: ObjectId,
         name: String,
         metadata: HashMap<String, ObjectId>,
     }
 
+    // Test struct with ObjectId map keys
+    #[model_schema()]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq)]
+    #[allow(dead_code)]
+    struct ObjectIdKeyedJson {
+        labels: HashMap<ObjectId, String>,
+        owners: Option<HashMap<ObjectId, ObjectId>>,
+    }
+
     impl ObjectId {
         fn new() -> Self {
             ObjectId("507f1f77bcf86cd799439011".to_string())
@@ -433,8 +448,145 @@ mod tests {
             name: "Test User".to_string(),
             email: Some("test@example.com".to_string()),
         };
-
         // If we get here without panics, ObjectId support is working at compile time
-        assert!(true);
+    }
+
+    #[test]
+    #[cfg(all(feature = "object_id", feature = "typescript"))]
+    fn test_object_id_map_keys_ts_definition() {
+        let ts_definition = ObjectIdKeyedJson::ts_definition();
+
+        // JSON object keys are strings: ObjectId keys serialize as their hex form
+        assert!(ts_definition.contains("labels: Partial<Record<string, string>>;"));
+        assert!(ts_definition.contains("Partial<Record<string, ObjectId>>"));
+        assert!(ts_definition.contains(" * Keys are ObjectId hex strings"));
+        assert!(!ts_definition.contains("Record<ObjectId"));
+    }
+
+    #[test]
+    #[cfg(all(feature = "object_id", feature = "zod"))]
+    fn test_object_id_map_keys_zod_schema() {
+        let zod_schema = ObjectIdKeyedJson::zod_schema();
+
+        assert!(zod_schema.contains("labels: z.record(z.string().regex(/^[a-f\\d]{24}$/i, { message: \"Invalid ObjectId\" }), z.string()),"));
+        assert!(zod_schema.contains("owners: z.record(z.string().regex(/^[a-f\\d]{24}$/i, { message: \"Invalid ObjectId\" }), z.object({ $oid:"));
+    }
+
+    #[test]
+    #[cfg(all(feature = "object_id", feature = "jsonschema"))]
+    fn test_object_id_map_keys_json_schema() {
+        let schema = ObjectIdKeyedJson::json_schema();
+        let properties = schema["properties"].as_object().unwrap();
+
+        let labels = &properties["labels"];
+        assert_eq!(labels["type"], "object");
+        assert_eq!(labels["additionalProperties"]["type"], "string");
+        assert_eq!(labels["propertyNames"]["type"], "string");
+        assert_eq!(labels["propertyNames"]["pattern"], "^[a-fA-F0-9]{24}$");
+
+        let owners = &properties["owners"];
+        assert_eq!(owners["additionalProperties"]["properties"]["$oid"]["type"], "string");
+        assert_eq!(owners["propertyNames"]["pattern"], "^[a-fA-F0-9]{24}$");
+
+        assert_eq!(schema["required"], serde_json::json!(["labels"]));
+    }
+
+    #[test]
+    #[cfg(all(feature = "object_id", feature = "jsonschema"))]
+    fn test_object_id_json_schema_key_order() {
+        // The `$oid` object and its siblings serialize with sorted keys, independently of
+        // serde_json's `preserve_order` (enabled here through bson)
+        let schema = serde_json::to_string(&ComplexDocumentJson::json_schema()).unwrap();
+        assert_eq!(schema, serde_json::to_string(&ComplexDocumentJson::json_schema()).unwrap());
+        assert!(schema.starts_with(r#"{"additionalProperties":false,"properties":{"author_id":{"#));
+        assert!(schema.contains(r#"{"additionalProperties":false,"properties":{"$oid":{"type":"string"}},"required":["$oid"],"type":"object"}"#));
+        assert!(schema.ends_with(r#""type":"object"}"#));
+    }
+
+    // Optional ObjectIds that the driver writes as `null` rather than leaving out
+    #[model_schema(optionals_nullable)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq)]
+    struct NullableRefsJson {
+        owner_id: ObjectId,
+        reviewer_id: Option<ObjectId>,
+        watcher_ids: Option<Vec<ObjectId>>,
+        note: Option<String>,
+    }
+
+    #[model_schema(optionals_nullable)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[cfg_attr(feature = "serde", serde(tag = "type"))]
+    #[derive(Debug, Clone, PartialEq)]
+    #[allow(dead_code)]
+    enum NullableRefEventJson {
+        Assigned { assignee_id: Option<ObjectId> },
+    }
+
+    #[test]
+    #[cfg(feature = "typescript")]
+    fn test_optionals_nullable_object_id_ts_definition() {
+        let ts_definition = NullableRefsJson::ts_definition();
+        assert!(ts_definition.contains("owner_id: ObjectId;"));
+        assert!(ts_definition.contains("reviewer_id: ObjectId | null;"));
+        assert!(ts_definition.contains("watcher_ids: Array<ObjectId> | null;"));
+        assert!(ts_definition.contains("note: string | null;"));
+        assert!(!ts_definition.contains("undefined"));
+
+        assert!(NullableRefEventJson::ts_definition().contains("assignee_id: ObjectId | null;"));
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_optionals_nullable_object_id_zod_schema() {
+        let zod_schema = NullableRefsJson::zod_schema();
+        assert!(zod_schema.contains(
+            "reviewer_id: z.object({ $oid: z.string().regex(/^[a-f\\d]{24}$/i, { message: \"Invalid ObjectId\" }) }).or(z.null()),"
+        ));
+        assert!(zod_schema.contains("watcher_ids: z.array(z.object({ $oid: "));
+        assert!(zod_schema.contains("note: z.string().or(z.null()),"));
+        assert!(!zod_schema.contains("z.undefined()"));
+
+        assert!(NullableRefEventJson::zod_schema().contains(".or(z.null())"));
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_optionals_nullable_object_id_json_schema() {
+        let schema = NullableRefsJson::json_schema();
+        let properties = &schema["properties"];
+
+        assert_eq!(properties["owner_id"]["type"], "object");
+        assert_eq!(properties["reviewer_id"]["type"], serde_json::json!(["object", "null"]));
+        assert_eq!(properties["reviewer_id"]["properties"]["$oid"]["type"], "string");
+        assert_eq!(properties["watcher_ids"]["type"], serde_json::json!(["array", "null"]));
+        assert_eq!(properties["note"]["type"], serde_json::json!(["string", "null"]));
+        // The key is written even when the value is `None`
+        assert_eq!(
+            schema["required"],
+            serde_json::json!(["owner_id", "reviewer_id", "watcher_ids", "note"])
+        );
+
+        let branch = &NullableRefEventJson::json_schema()["oneOf"][0];
+        assert_eq!(branch["properties"]["assignee_id"]["type"], serde_json::json!(["object", "null"]));
+        assert!(branch["required"].as_array().unwrap().contains(&serde_json::json!("assignee_id")));
+    }
+
+    #[test]
+    #[cfg(all(feature = "jsonschema", feature = "serde"))]
+    fn test_optionals_nullable_matches_serde_output() {
+        let value = serde_json::to_value(NullableRefsJson {
+            owner_id: ObjectId("507f1f77bcf86cd799439011".to_string()),
+            reviewer_id: None,
+            watcher_ids: None,
+            note: None,
+        })
+        .unwrap();
+        let object = value.as_object().unwrap();
+
+        for key in NullableRefsJson::json_schema()["required"].as_array().unwrap() {
+            assert!(object.contains_key(key.as_str().unwrap()));
+        }
+        assert_eq!(object["reviewer_id"], serde_json::Value::Null);
     }
 }
@@ -0,0 +1,37 @@
+// Build test for `--no-default-features`: with no output feature enabled the macro
+// must still expand to valid code, emitting only a deprecation warning that points
+// users at the missing features.
+#[cfg(all(
+    test,
+    not(any(feature = "typescript", feature = "zod", feature = "jsonschema"))
+))]
+mod tests {
+    use tixschema::model_schema;
+
+    #[model_schema()]
+    #[derive(Debug, Clone, PartialEq)]
+    struct PlainUserJson {
+        id: String,
+        age: u32,
+    }
+
+    #[model_schema()]
+    #[derive(Debug, Clone, PartialEq)]
+    enum PlainStatusJson {
+        Active,
+        Inactive,
+    }
+
+    #[test]
+    fn test_model_schema_compiles_without_output_features() {
+        let user = PlainUserJson {
+            id: "1".to_string(),
+            age: 30,
+        };
+        assert_eq!(user.clone(), user);
+        assert_ne!(PlainStatusJson::Active, PlainStatusJson::Inactive);
+
+        // Plain enums keep their reflection helper even without output features
+        assert_eq!(PlainStatusJson::enum_members(), vec!["Active", "Inactive"]);
+    }
+}
@@ -0,0 +1,79 @@
+// ObjectId accepted both as extended JSON `{ "$oid": "..." }` and as the bare hex string
+#[cfg(all(test, feature = "object_id_flexible"))]
+mod tests {
+    use mongodb::bson::oid::ObjectId;
+    use regex::Regex;
+    use std::collections::HashMap;
+    use tixschema::model_schema;
+
+    #[model_schema()]
+    #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    struct TicketJson {
+        id: ObjectId,
+        watchers: Vec<ObjectId>,
+        assignee: Option<ObjectId>,
+        links: HashMap<String, ObjectId>,
+    }
+
+    const KEY_ZOD: &str = "z.string().regex(/^[a-f\\d]{24}$/i, { message: \"Invalid ObjectId\" })";
+
+    #[test]
+    #[cfg(feature = "typescript")]
+    fn test_flexible_object_id_ts_definition() {
+        let ts_definition = TicketJson::ts_definition();
+        assert!(ts_definition.contains("id: ObjectId | string;"));
+        assert!(ts_definition.contains("watchers: Array<ObjectId | string>;"));
+        assert!(ts_definition.contains("assignee: ObjectId | string | undefined;"));
+        assert!(ts_definition.contains("links: Partial<Record<string, ObjectId | string>>;"));
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_flexible_object_id_zod_schema() {
+        let zod_schema = TicketJson::zod_schema();
+        let union = format!("z.union([z.object({{ $oid: {KEY_ZOD} }}), {KEY_ZOD}])");
+        assert!(zod_schema.contains(&format!("id: {union},")));
+        assert!(zod_schema.contains(&format!("watchers: z.array({union}),")));
+        assert!(zod_schema.contains(&format!("assignee: {union}.or(z.undefined()),")));
+        assert!(zod_schema.contains(&format!("links: z.record(z.string(), {union}),")));
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_flexible_object_id_json_schema() {
+        let schema = TicketJson::json_schema();
+        let id = &schema["properties"]["id"];
+        let branches = id["anyOf"].as_array().unwrap();
+        assert_eq!(branches.len(), 2);
+        assert_eq!(branches[0]["type"], "object");
+        assert_eq!(branches[0]["required"], serde_json::json!(["$oid"]));
+        assert_eq!(branches[1]["type"], "string");
+
+        assert_eq!(schema["properties"]["watchers"]["items"], *id);
+        assert_eq!(schema["properties"]["links"]["additionalProperties"], *id);
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde", feature = "jsonschema"))]
+    fn test_flexible_object_id_accepts_both_shapes() {
+        let schema = TicketJson::json_schema();
+        let branches = schema["properties"]["id"]["anyOf"].as_array().unwrap().clone();
+        let extended_pattern = Regex::new(branches[0]["properties"]["$oid"]["pattern"].as_str().unwrap()).unwrap();
+        let plain_pattern = Regex::new(branches[1]["pattern"].as_str().unwrap()).unwrap();
+
+        let oid = ObjectId::new();
+
+        // Extended JSON, as serde_json serializes a bson ObjectId
+        let extended = serde_json::to_value(oid).unwrap();
+        let extended = extended.as_object().unwrap();
+        assert_eq!(extended.keys().collect::<Vec<_>>(), vec!["$oid"]);
+        assert!(extended_pattern.is_match(extended["$oid"].as_str().unwrap()));
+
+        // Plain hex string, as most REST APIs send it
+        assert!(plain_pattern.is_match(&oid.to_hex()));
+        assert!(plain_pattern.is_match(&oid.to_hex().to_uppercase()));
+        assert!(!plain_pattern.is_match("not-an-object-id"));
+        assert!(!plain_pattern.is_match(&oid.to_hex()[1..]));
+    }
+}
@@ -3,7 +3,13 @@ mod tests {
 
     #[cfg(all(test, feature = "serde"))]
     use serde::{Deserialize, Serialize};
-    #[cfg(all(test, any(feature = "jsonschema")))]
+
+    // The Zod schema of u64 / i64 values, limited to the JS safe-integer range by `safe_ints`
+    #[cfg(all(feature = "zod", feature = "safe_ints"))]
+    const WIDE_INT_ZOD: &str = "z.number().int().safe()";
+    #[cfg(all(feature = "zod", not(feature = "safe_ints")))]
+    const WIDE_INT_ZOD: &str = "z.number().int()";
+    #[cfg(all(test, feature = "jsonschema"))]
     use serde_json::Value;
     #[cfg(all(
         test,
@@ -92,10 +98,10 @@ mod tests {
         let zod_schema = LargeNumbersJson::zod_schema();
         assert!(zod_schema.contains("large_unsigned: z.number().int()"));
         assert!(zod_schema.contains("large_signed: z.number().int()"));
-        assert!(zod_schema.contains("optional_large_unsigned: z.number().int().or(z.undefined())"));
-        assert!(zod_schema.contains("optional_large_signed: z.number().int().or(z.undefined())"));
-        assert!(zod_schema.contains("array_of_u64: z.array(z.number().int())"));
-        assert!(zod_schema.contains("array_of_i64: z.array(z.number().int())"));
+        assert!(zod_schema.contains(&format!("optional_large_unsigned: {WIDE_INT_ZOD}.or(z.undefined())")));
+        assert!(zod_schema.contains(&format!("optional_large_signed: {WIDE_INT_ZOD}.or(z.undefined())")));
+        assert!(zod_schema.contains(&format!("array_of_u64: z.array({WIDE_INT_ZOD})")));
+        assert!(zod_schema.contains(&format!("array_of_i64: z.array({WIDE_INT_ZOD})")));
     }
 
     #[cfg(all(
@@ -364,17 +370,17 @@ mod tests {
 
         // Optional Zod schemas
         assert!(zod_schema.contains("opt_i8: z.number().int().or(z.undefined())"));
-        assert!(zod_schema.contains("opt_u64: z.number().int().or(z.undefined())"));
+        assert!(zod_schema.contains(&format!("opt_u64: {WIDE_INT_ZOD}.or(z.undefined())")));
         assert!(zod_schema.contains("opt_f64: z.number().or(z.undefined())")); // No .int() for float
 
         // Array Zod schemas
         assert!(zod_schema.contains("array_i8: z.array(z.number().int())"));
-        assert!(zod_schema.contains("array_u64: z.array(z.number().int())"));
+        assert!(zod_schema.contains(&format!("array_u64: z.array({WIDE_INT_ZOD})")));
         assert!(zod_schema.contains("array_f64: z.array(z.number())")); // No .int() for float
 
         // HashMap Zod schemas
         assert!(zod_schema.contains("map_to_i8: z.record(z.string(), z.number().int())"));
-        assert!(zod_schema.contains("map_to_u64: z.record(z.string(), z.number().int())"));
+        assert!(zod_schema.contains(&format!("map_to_u64: z.record(z.string(), {WIDE_INT_ZOD})")));
         assert!(zod_schema.contains("map_to_f64: z.record(z.string(), z.number())")); // No .int() for float
 
         // HashMap with array Zod schemas
@@ -383,8 +389,208 @@ mod tests {
         );
         assert!(
             zod_schema
-                .contains("map_to_u64_array: z.record(z.string(), z.array(z.number().int()))")
+                .contains(&format!("map_to_u64_array: z.record(z.string(), z.array({WIDE_INT_ZOD}))"))
         );
         assert!(zod_schema.contains("map_to_f64_array: z.record(z.string(), z.array(z.number()))")); // No .int() for float
     }
+
+    #[cfg(all(
+        test,
+        any(feature = "typescript", feature = "jsonschema", feature = "zod")
+    ))]
+    // Test NonZero* integers, which serialize as plain numbers
+    #[model_schema()]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq)]
+    struct CountersJson {
+        count: std::num::NonZeroU32,
+        offset: std::num::NonZeroI64,
+        level: Option<std::num::NonZeroU8>,
+        weights: Vec<std::num::NonZeroU16>,
+        generic_count: std::num::NonZero<u64>,
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_non_zero_json_schema() {
+        let schema = CountersJson::json_schema();
+
+        let properties = schema["properties"].as_object().unwrap();
+
+        // Unsigned NonZero types use a lower bound of 1
+        assert_eq!(properties["count"]["type"], "integer");
+        assert_eq!(properties["count"]["minimum"], 1);
+        assert_eq!(properties["level"]["type"], "integer");
+        assert_eq!(properties["level"]["minimum"], 1);
+        assert_eq!(properties["generic_count"]["minimum"], 1);
+
+        // Signed NonZero types exclude zero explicitly
+        assert_eq!(properties["offset"]["type"], "integer");
+        assert_eq!(properties["offset"]["not"]["const"], 0);
+        #[cfg(not(feature = "safe_ints"))]
+        assert!(properties["offset"].get("minimum").is_none());
+        #[cfg(feature = "safe_ints")]
+        assert_eq!(properties["offset"]["minimum"], -9007199254740991i64);
+
+        // Arrays carry the constraint on their items
+        assert_eq!(properties["weights"]["type"], "array");
+        assert_eq!(properties["weights"]["items"]["minimum"], 1);
+
+        let required = schema["required"].as_array().unwrap();
+        assert!(required.contains(&Value::String("count".to_string())));
+        assert!(!required.contains(&Value::String("level".to_string())));
+    }
+
+    #[test]
+    #[cfg(feature = "typescript")]
+    fn test_non_zero_ts_definition() {
+        let ts_definition = CountersJson::ts_definition();
+
+        assert!(ts_definition.contains("count: number;"));
+        assert!(ts_definition.contains("offset: number;"));
+        assert!(ts_definition.contains("level: number | undefined;"));
+        assert!(ts_definition.contains("weights: Array<number>;"));
+        assert!(ts_definition.contains("generic_count: number;"));
+        assert!(!ts_definition.contains("NonZero"));
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_non_zero_zod_schema() {
+        let zod_schema = CountersJson::zod_schema();
+
+        assert!(zod_schema.contains("count: z.number().int().min(1),"));
+        assert!(zod_schema.contains(&format!("offset: {WIDE_INT_ZOD}.refine(n => n !== 0),")));
+        assert!(zod_schema.contains("level: z.number().int().min(1).or(z.undefined()),"));
+        assert!(zod_schema.contains("weights: z.array(z.number().int().min(1)),"));
+        assert!(zod_schema.contains(&format!("generic_count: {WIDE_INT_ZOD}.min(1),")));
+        assert!(!zod_schema.contains("NonZero"));
+    }
+
+    #[test]
+    #[cfg(all(feature = "jsonschema", feature = "float_precision_hints"))]
+    fn test_float_precision_hints_json_schema() {
+        let schema = PrimitiveTypesShowcaseJson::json_schema();
+        let properties = schema["properties"].as_object().unwrap();
+
+        assert_eq!(properties["float_single"], serde_json::json!({ "type": "number", "format": "float" }));
+        assert_eq!(properties["float_double"], serde_json::json!({ "type": "number", "format": "double" }));
+        assert_eq!(properties["opt_f64"]["format"], "double");
+        assert_eq!(properties["array_f64"]["items"]["format"], "double");
+        assert_eq!(properties["opt_array_f64"]["items"]["format"], "double");
+    }
+
+    #[test]
+    #[cfg(all(feature = "jsonschema", not(feature = "float_precision_hints")))]
+    fn test_float_json_schema_has_no_format_by_default() {
+        let schema = PrimitiveTypesShowcaseJson::json_schema();
+        let properties = schema["properties"].as_object().unwrap();
+
+        assert_eq!(properties["float_single"], serde_json::json!({ "type": "number" }));
+        assert!(properties["array_f64"]["items"].get("format").is_none());
+    }
+
+    // serde_json::Number holds either an integer or a float
+    #[cfg(all(
+        test,
+        any(feature = "typescript", feature = "jsonschema", feature = "zod")
+    ))]
+    #[model_schema()]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq)]
+    struct MeasurementJson {
+        value: serde_json::Number,
+        samples: Vec<serde_json::Number>,
+        previous: Option<serde_json::Number>,
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_json_number_json_schema() {
+        let schema = MeasurementJson::json_schema();
+        let properties = schema["properties"].as_object().unwrap();
+
+        assert_eq!(properties["value"], serde_json::json!({ "type": "number" }));
+        assert_eq!(properties["samples"]["type"], "array");
+        assert_eq!(properties["samples"]["items"], serde_json::json!({ "type": "number" }));
+        assert_eq!(properties["previous"], serde_json::json!({ "type": "number" }));
+    }
+
+    #[test]
+    #[cfg(feature = "typescript")]
+    fn test_json_number_ts_definition() {
+        let ts_definition = MeasurementJson::ts_definition();
+
+        assert!(ts_definition.contains("value: number;"));
+        assert!(ts_definition.contains("samples: Array<number>;"));
+        assert!(ts_definition.contains("previous: number | undefined;"));
+        assert!(!ts_definition.contains("Number"));
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_json_number_zod_schema() {
+        let zod_schema = MeasurementJson::zod_schema();
+
+        assert!(zod_schema.contains("value: z.number(),"));
+        assert!(zod_schema.contains("samples: z.array(z.number()),"));
+        assert!(!zod_schema.contains("Number$Schema"));
+    }
+
+    // 64-bit IDs and counters that a JS client parses into a number
+    #[cfg(all(
+        test,
+        any(feature = "typescript", feature = "jsonschema", feature = "zod")
+    ))]
+    #[model_schema()]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq)]
+    struct LedgerEntryJson {
+        entry_id: u64,
+        balance_cents: i64,
+        seats: u32,
+        delta: i32,
+        previous_id: Option<u64>,
+        #[cfg_attr(feature = "serde", serde(default))]
+        transfer_ids: Vec<u64>,
+    }
+
+    #[test]
+    #[cfg(all(feature = "zod", feature = "safe_ints"))]
+    fn test_safe_ints_zod_schema() {
+        let zod_schema = LedgerEntryJson::zod_schema();
+
+        assert!(zod_schema.contains("entry_id: z.number().int().safe(),"));
+        assert!(zod_schema.contains("balance_cents: z.number().int().safe(),"));
+        assert!(zod_schema.contains("previous_id: z.number().int().safe().or(z.undefined()),"));
+        assert!(zod_schema.contains("transfer_ids: z.array(z.number().int().safe())"));
+        // 32-bit integers always fit
+        assert!(zod_schema.contains("seats: z.number().int(),"));
+        assert!(zod_schema.contains("delta: z.number().int(),"));
+    }
+
+    #[test]
+    #[cfg(all(feature = "jsonschema", feature = "safe_ints"))]
+    fn test_safe_ints_json_schema() {
+        let schema = LedgerEntryJson::json_schema();
+        let properties = schema["properties"].as_object().unwrap();
+
+        assert_eq!(properties["entry_id"], serde_json::json!({ "type": "integer", "maximum": 9007199254740991i64 }));
+        assert_eq!(
+            properties["balance_cents"],
+            serde_json::json!({ "type": "integer", "minimum": -9007199254740991i64, "maximum": 9007199254740991i64 })
+        );
+        assert_eq!(properties["transfer_ids"]["items"]["maximum"], 9007199254740991i64);
+        assert_eq!(properties["seats"], serde_json::json!({ "type": "integer" }));
+        assert_eq!(properties["delta"], serde_json::json!({ "type": "integer" }));
+    }
+
+    #[test]
+    #[cfg(all(feature = "zod", not(feature = "safe_ints")))]
+    fn test_wide_integers_are_not_safe_checked_by_default() {
+        let zod_schema = LedgerEntryJson::zod_schema();
+
+        assert!(zod_schema.contains("entry_id: z.number().int(),"));
+        assert!(!zod_schema.contains(".safe()"));
+    }
 }
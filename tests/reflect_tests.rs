@@ -0,0 +1,59 @@
+#[cfg(all(test, feature = "reflect"))]
+mod tests {
+    use std::collections::HashMap;
+    use tixschema::model_schema;
+
+    #[model_schema()]
+    #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize))]
+    struct AddressJson {
+        street: String,
+    }
+
+    #[model_schema()]
+    #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+    struct SignupFormJson {
+        display_name: String,
+        age: Option<u32>,
+        newsletter: bool,
+        interests: Vec<String>,
+        address: AddressJson,
+        answers: HashMap<String, f64>,
+    }
+
+    #[test]
+    fn test_ts_fields_pairs() {
+        let fields = SignupFormJson::ts_fields();
+        let names = fields.iter().map(|(name, _)| name.as_str()).collect::<Vec<_>>();
+        let types = fields.iter().map(|(_, ts_type)| ts_type.as_str()).collect::<Vec<_>>();
+
+        assert_eq!(
+            types,
+            vec![
+                "string",
+                "number | undefined",
+                "boolean",
+                "Array<string>",
+                "Address",
+                "Partial<Record<string, number>>",
+            ]
+        );
+
+        // Names are the serialized ones
+        #[cfg(feature = "serde")]
+        assert_eq!(names, vec!["displayName", "age", "newsletter", "interests", "address", "answers"]);
+        #[cfg(not(feature = "serde"))]
+        assert_eq!(names, vec!["display_name", "age", "newsletter", "interests", "address", "answers"]);
+    }
+
+    #[test]
+    #[cfg(feature = "typescript")]
+    fn test_ts_fields_match_ts_definition() {
+        let ts_definition = SignupFormJson::ts_definition();
+        for (name, ts_type) in SignupFormJson::ts_fields() {
+            assert!(ts_definition.contains(&format!("{name}: {ts_type};")), "{name}: {ts_type}");
+        }
+    }
+}
@@ -0,0 +1,472 @@
+// Round-trip tests: serialize Rust instances with serde_json and validate the output against
+// the generated `json_schema()`. This catches casing and optionality mismatches between what
+// serde emits and what the schemas describe.
+//
+// The validator below is a small vendored subset of JSON Schema covering the keywords the
+// macro emits (type, properties, required, additionalProperties, propertyNames, items, enum,
+// const, oneOf, anyOf, not, minimum, minLength, pattern and dependentRequired).
+#[cfg(all(test, feature = "jsonschema", feature = "serde"))]
+mod tests {
+    use serde::{Deserialize, Serialize};
+    use serde_json::{Value, json};
+    use std::collections::{BTreeMap, HashMap};
+    use std::num::NonZeroU32;
+    use tixschema::model_schema;
+
+    fn type_matches(expected: &str, value: &Value) -> bool {
+        match expected {
+            "object" => value.is_object(),
+            "array" => value.is_array(),
+            "string" => value.is_string(),
+            "boolean" => value.is_boolean(),
+            "null" => value.is_null(),
+            "number" => value.is_number(),
+            "integer" => value.is_i64() || value.is_u64(),
+            other => panic!("unsupported type in schema: {other}"),
+        }
+    }
+
+    fn validate(schema: &Value, value: &Value, path: &str, errors: &mut Vec<String>) {
+        let schema = match schema {
+            Value::Bool(true) => return,
+            Value::Bool(false) => {
+                errors.push(format!("{path}: no value is allowed"));
+                return;
+            }
+            Value::Object(schema) => schema,
+            other => panic!("invalid schema at {path}: {other}"),
+        };
+
+        for (keyword, expected) in schema {
+            match keyword.as_str() {
+                "type" => {
+                    let types = match expected {
+                        Value::Array(types) => types.iter().filter_map(Value::as_str).collect(),
+                        other => vec![other.as_str().unwrap()],
+                    };
+                    if !types.iter().any(|t| type_matches(t, value)) {
+                        errors.push(format!("{path}: expected {expected}, got {value}"));
+                    }
+                }
+                "properties" => {
+                    if let Some(object) = value.as_object() {
+                        for (key, sub_schema) in expected.as_object().unwrap() {
+                            if let Some(sub_value) = object.get(key) {
+                                validate(sub_schema, sub_value, &format!("{path}.{key}"), errors);
+                            }
+                        }
+                    }
+                }
+                "required" => {
+                    if let Some(object) = value.as_object() {
+                        for key in expected.as_array().unwrap() {
+                            if !object.contains_key(key.as_str().unwrap()) {
+                                errors.push(format!("{path}: missing required {key}"));
+                            }
+                        }
+                    }
+                }
+                "additionalProperties" => {
+                    if let Some(object) = value.as_object() {
+                        let known = schema.get("properties").and_then(Value::as_object);
+                        for (key, sub_value) in object {
+                            if !known.is_some_and(|known| known.contains_key(key)) {
+                                validate(expected, sub_value, &format!("{path}.{key}"), errors);
+                            }
+                        }
+                    }
+                }
+                "propertyNames" => {
+                    if let Some(object) = value.as_object() {
+                        for key in object.keys() {
+                            validate(expected, &json!(key), &format!("{path}[{key}]"), errors);
+                        }
+                    }
+                }
+                "items" => {
+                    if let Some(items) = value.as_array() {
+                        for (index, item) in items.iter().enumerate() {
+                            validate(expected, item, &format!("{path}[{index}]"), errors);
+                        }
+                    }
+                }
+                "enum" => {
+                    if !expected.as_array().unwrap().contains(value) {
+                        errors.push(format!("{path}: {value} is not one of {expected}"));
+                    }
+                }
+                "const" => {
+                    if expected != value {
+                        errors.push(format!("{path}: expected {expected}, got {value}"));
+                    }
+                }
+                "oneOf" => {
+                    let matching = expected
+                        .as_array()
+                        .unwrap()
+                        .iter()
+                        .filter(|branch| {
+                            let mut branch_errors = Vec::new();
+                            validate(branch, value, path, &mut branch_errors);
+                            branch_errors.is_empty()
+                        })
+                        .count();
+                    if matching != 1 {
+                        errors.push(format!("{path}: {value} matches {matching} oneOf branches"));
+                    }
+                }
+                "anyOf" => {
+                    let matches_any = expected.as_array().unwrap().iter().any(|branch| {
+                        let mut branch_errors = Vec::new();
+                        validate(branch, value, path, &mut branch_errors);
+                        branch_errors.is_empty()
+                    });
+                    if !matches_any {
+                        errors.push(format!("{path}: {value} matches no anyOf branch"));
+                    }
+                }
+                "not" => {
+                    let mut branch_errors = Vec::new();
+                    validate(expected, value, path, &mut branch_errors);
+                    if branch_errors.is_empty() {
+                        errors.push(format!("{path}: {value} matches a `not` schema"));
+                    }
+                }
+                "minimum" => {
+                    if let Some(number) = value.as_f64()
+                        && number < expected.as_f64().unwrap()
+                    {
+                        errors.push(format!("{path}: {value} is below {expected}"));
+                    }
+                }
+                "minLength" => {
+                    if let Some(text) = value.as_str()
+                        && (text.chars().count() as u64) < expected.as_u64().unwrap()
+                    {
+                        errors.push(format!("{path}: {value} is shorter than {expected}"));
+                    }
+                }
+                "pattern" => {
+                    let pattern = regex::Regex::new(expected.as_str().unwrap()).unwrap();
+                    if let Some(text) = value.as_str()
+                        && !pattern.is_match(text)
+                    {
+                        errors.push(format!("{path}: {value} doesn't match {expected}"));
+                    }
+                }
+                "dependentRequired" => {
+                    if let Some(object) = value.as_object() {
+                        for (trigger, dependents) in expected.as_object().unwrap() {
+                            if !object.contains_key(trigger) {
+                                continue;
+                            }
+                            for dependent in dependents.as_array().unwrap() {
+                                if !object.contains_key(dependent.as_str().unwrap()) {
+                                    errors.push(format!("{path}: {trigger} requires {dependent}"));
+                                }
+                            }
+                        }
+                    }
+                }
+                // Annotations don't constrain the value
+                "format" | "$comment" | "deprecated" | "readOnly" | "writeOnly" | "description" => {}
+                other => panic!("unsupported keyword in schema at {path}: {other}"),
+            }
+        }
+    }
+
+    fn validation_errors<T: Serialize>(schema: &Value, instance: &T) -> Vec<String> {
+        let value = serde_json::to_value(instance).unwrap();
+        let mut errors = Vec::new();
+        validate(schema, &value, "$", &mut errors);
+        errors
+    }
+
+    fn assert_round_trip<T: Serialize>(schema: &Value, instance: &T) {
+        let errors = validation_errors(schema, instance);
+        assert!(
+            errors.is_empty(),
+            "serde output {} doesn't match the schema:\n{}",
+            serde_json::to_string_pretty(&serde_json::to_value(instance).unwrap()).unwrap(),
+            errors.join("\n")
+        );
+    }
+
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    struct UserWithSerdeJson {
+        user_id: String,
+        first_name: String,
+        #[serde(rename = "emailAddress")]
+        email: String,
+        is_verified: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        nickname: Option<String>,
+    }
+
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+    #[serde(rename_all = "lowercase")]
+    enum UserStatusJson {
+        Active,
+        Inactive,
+    }
+
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    struct AddressJson {
+        street: String,
+        city: String,
+        zip_code: String,
+    }
+
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[serde(tag = "type", rename_all = "camelCase", rename_all_fields = "camelCase")]
+    enum PaymentMethodJson {
+        CreditCard { card_number: String, expiry_month: u8 },
+        BankTransfer { account_number: String },
+        Cash,
+    }
+
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    struct CustomerJson {
+        id: String,
+        status: UserStatusJson,
+        address: AddressJson,
+        previous_addresses: Vec<AddressJson>,
+        payment: PaymentMethodJson,
+        tags: Vec<String>,
+        scores: HashMap<String, Vec<u32>>,
+        balance: f64,
+        visits: NonZeroU32,
+        totals: HashMap<UserStatusJson, AddressJson>,
+        previous_by_label: HashMap<String, Option<AddressJson>>,
+    }
+
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    struct OpenAttributesJson {
+        id: String,
+        #[serde(flatten)]
+        extra: HashMap<String, Vec<String>>,
+    }
+
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    struct ShippingAddressJson {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        country: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[model_schema_prop(required_with = "country")]
+        region: Option<String>,
+    }
+
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    struct RowLabelsJson {
+        labels: BTreeMap<u32, String>,
+        offsets: HashMap<i16, Vec<AddressJson>>,
+    }
+
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    struct OptionalNoteJson {
+        note: Option<String>,
+    }
+
+    #[model_schema(optionals_nullable)]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    struct NullableNoteJson {
+        note: Option<String>,
+    }
+
+    fn address(city: &str) -> AddressJson {
+        AddressJson {
+            street: "1 Main St".to_string(),
+            city: city.to_string(),
+            zip_code: "12345".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_serde_renames_round_trip() {
+        let schema = UserWithSerdeJson::json_schema();
+        let mut user = UserWithSerdeJson {
+            user_id: "u1".to_string(),
+            first_name: "Ada".to_string(),
+            email: "ada@example.com".to_string(),
+            is_verified: true,
+            nickname: None,
+        };
+        assert_round_trip(&schema, &user);
+
+        user.nickname = Some("ada".to_string());
+        assert_round_trip(&schema, &user);
+    }
+
+    #[test]
+    fn test_enums_round_trip() {
+        assert_round_trip(&UserStatusJson::json_schema(), &UserStatusJson::Active);
+        assert_round_trip(&UserStatusJson::json_schema(), &UserStatusJson::Inactive);
+
+        let schema = PaymentMethodJson::json_schema();
+        assert_round_trip(
+            &schema,
+            &PaymentMethodJson::CreditCard {
+                card_number: "4111".to_string(),
+                expiry_month: 12,
+            },
+        );
+        assert_round_trip(
+            &schema,
+            &PaymentMethodJson::BankTransfer {
+                account_number: "DE00".to_string(),
+            },
+        );
+        assert_round_trip(&schema, &PaymentMethodJson::Cash);
+    }
+
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[serde(untagged)]
+    enum SeatPickJson {
+        Count(u32),
+        Seat { label: String },
+        Any,
+    }
+
+    #[test]
+    fn test_untagged_enum_round_trip() {
+        let schema = SeatPickJson::json_schema();
+        assert_round_trip(&schema, &SeatPickJson::Count(2));
+        assert_round_trip(&schema, &SeatPickJson::Seat { label: "A1".to_string() });
+        assert_round_trip(&schema, &SeatPickJson::Any);
+
+        // The struct branch only accepts objects
+        for value in [json!("A1"), json!(true)] {
+            let mut errors = Vec::new();
+            validate(&schema, &value, "$", &mut errors);
+            assert_eq!(errors, vec![format!("$: {value} matches no anyOf branch")]);
+        }
+    }
+
+    #[test]
+    fn test_nested_collections_round_trip() {
+        let customer = CustomerJson {
+            id: "c1".to_string(),
+            status: UserStatusJson::Active,
+            address: address("Berlin"),
+            previous_addresses: vec![address("Paris"), address("Rome")],
+            payment: PaymentMethodJson::Cash,
+            tags: vec!["vip".to_string()],
+            scores: [("2024".to_string(), vec![1, 2, 3])].into_iter().collect(),
+            balance: 12.5,
+            visits: NonZeroU32::new(3).unwrap(),
+            totals: [(UserStatusJson::Inactive, address("Oslo"))].into_iter().collect(),
+            previous_by_label: [("home".to_string(), Some(address("Rome"))), ("work".to_string(), None)]
+                .into_iter()
+                .collect(),
+        };
+        assert_round_trip(&CustomerJson::json_schema(), &customer);
+    }
+
+    #[test]
+    fn test_integer_keyed_maps_round_trip() {
+        let schema = RowLabelsJson::json_schema();
+        let rows = RowLabelsJson {
+            labels: BTreeMap::from([(0, "front".to_string()), (12, "back".to_string())]),
+            offsets: HashMap::from([(-3, vec![address("Oslo")])]),
+        };
+        assert_round_trip(&schema, &rows);
+
+        let mut errors = Vec::new();
+        validate(&schema, &json!({ "labels": { "front": "A", "7": 1 }, "offsets": { "01": [] } }), "$", &mut errors);
+        errors.sort();
+        assert_eq!(
+            errors,
+            vec![
+                "$.labels.7: expected \"string\", got 1".to_string(),
+                "$.labels[front]: \"front\" doesn't match \"^(0|[1-9][0-9]*)$\"".to_string(),
+                "$.offsets[01]: \"01\" doesn't match \"^(0|-?[1-9][0-9]*)$\"".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_flattened_map_round_trip() {
+        let attributes = OpenAttributesJson {
+            id: "a1".to_string(),
+            extra: [("colors".to_string(), vec!["red".to_string()])].into_iter().collect(),
+        };
+        assert_round_trip(&OpenAttributesJson::json_schema(), &attributes);
+    }
+
+    #[test]
+    fn test_required_with_round_trip() {
+        let schema = ShippingAddressJson::json_schema();
+        assert_round_trip(
+            &schema,
+            &ShippingAddressJson {
+                country: Some("DE".to_string()),
+                region: Some("BE".to_string()),
+            },
+        );
+        assert_round_trip(&schema, &ShippingAddressJson { country: None, region: None });
+
+        // The harness reports instances the schema rejects
+        let errors = validation_errors(
+            &schema,
+            &ShippingAddressJson {
+                country: Some("DE".to_string()),
+                region: None,
+            },
+        );
+        assert_eq!(errors, vec!["$: country requires \"region\"".to_string()]);
+    }
+
+    // KNOWN ISSUE: with the default optional style, optional fields are described as "may be
+    // absent", but serde writes `None` as `null` unless the field is skipped with
+    // `skip_serializing_if`. Such types need `optionals_nullable` (see the test below). When this
+    // is fixed, this test should start failing and be replaced by a round trip.
+    #[test]
+    fn test_known_issue_default_optionals_reject_serde_null() {
+        let schema = OptionalNoteJson::json_schema();
+        assert_round_trip(&schema, &OptionalNoteJson { note: Some("hi".to_string()) });
+
+        let errors = validation_errors(&schema, &OptionalNoteJson { note: None });
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].starts_with("$.note: expected \"string\", got null"));
+    }
+
+    #[test]
+    fn test_nullable_optionals_round_trip() {
+        let schema = NullableNoteJson::json_schema();
+        assert_round_trip(&schema, &NullableNoteJson { note: Some("hi".to_string()) });
+        assert_round_trip(&schema, &NullableNoteJson { note: None });
+    }
+
+    #[cfg(feature = "object_id")]
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    struct RealDocumentJson {
+        id: mongodb::bson::oid::ObjectId,
+        references: Vec<mongodb::bson::oid::ObjectId>,
+        metadata: HashMap<String, mongodb::bson::oid::ObjectId>,
+    }
+
+    #[test]
+    #[cfg(feature = "object_id")]
+    fn test_object_id_round_trip() {
+        use mongodb::bson::oid::ObjectId;
+
+        let document = RealDocumentJson {
+            id: ObjectId::new(),
+            references: vec![ObjectId::new(), ObjectId::new()],
+            metadata: [("template".to_string(), ObjectId::new())].into_iter().collect(),
+        };
+        assert_round_trip(&RealDocumentJson::json_schema(), &document);
+    }
+}
@@ -73,4 +73,702 @@ mod tests {
         assert!(zod_schema.contains("createdAt: z.string()"));
         assert!(zod_schema.contains("isVerified: z.boolean()"));
     }
-} 
\ No newline at end of file
+
+    // Test borrowed fields, which serialize like their owned counterparts
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    struct BorrowedRecordJson<'a> {
+        name: &'a str,
+        #[serde(borrow)]
+        label: std::borrow::Cow<'a, str>,
+        payload: &'a [u8],
+        alias: Option<&'a str>,
+    }
+
+    #[test]
+    #[cfg(feature = "typescript")]
+    fn test_borrowed_fields_ts_definition() {
+        let ts_definition = BorrowedRecordJson::ts_definition();
+
+        assert!(ts_definition.contains("export type BorrowedRecord = {"));
+        assert!(ts_definition.contains("name: string;"));
+        assert!(ts_definition.contains("label: string;"));
+        assert!(ts_definition.contains("payload: Array<number>;"));
+        assert!(ts_definition.contains("alias: string | undefined;"));
+        assert!(!ts_definition.contains("str$Schema"));
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_borrowed_fields_zod_schema() {
+        let zod_schema = BorrowedRecordJson::zod_schema();
+
+        assert!(zod_schema.contains("name: z.string(),"));
+        assert!(zod_schema.contains("label: z.string(),"));
+        assert!(zod_schema.contains("payload: z.array(z.number().int()),"));
+        assert!(!zod_schema.contains("str$Schema"));
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_borrowed_fields_json_schema_matches_serde_output() {
+        let schema = BorrowedRecordJson::json_schema();
+        let properties = schema["properties"].as_object().unwrap();
+
+        assert_eq!(properties["name"]["type"], "string");
+        assert_eq!(properties["label"]["type"], "string");
+        assert_eq!(properties["payload"]["type"], "array");
+        assert_eq!(properties["payload"]["items"]["type"], "integer");
+
+        let record = BorrowedRecordJson {
+            name: "a",
+            label: "b".into(),
+            payload: &[1, 2],
+            alias: None,
+        };
+        let value = serde_json::to_value(&record).unwrap();
+        assert_eq!(value["name"], "a");
+        assert_eq!(value["label"], "b");
+        assert_eq!(value["payload"], serde_json::json!([1, 2]));
+    }
+
+    // camel_schema_keys only asserts the serde casing, it never renames keys itself
+    #[cfg(feature = "serde")]
+    #[model_schema(camel_schema_keys)]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    struct CamelKeysJson {
+        display_name: String,
+        #[serde(rename = "url")]
+        home_page: Option<String>,
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde", feature = "zod", feature = "jsonschema"))]
+    fn test_camel_schema_keys_matches_serialized_names() {
+        let zod_schema = CamelKeysJson::zod_schema();
+        assert!(zod_schema.contains("displayName: z.string(),"));
+        assert!(zod_schema.contains("url: z.string().or(z.undefined()),"));
+
+        let schema = CamelKeysJson::json_schema();
+        let properties = schema["properties"].as_object().unwrap();
+        let value = serde_json::to_value(CamelKeysJson {
+            display_name: "Ada".to_string(),
+            home_page: Some("https://example.com".to_string()),
+        })
+        .unwrap();
+        for key in value.as_object().unwrap().keys() {
+            assert!(properties.contains_key(key));
+        }
+    }
+
+    // Open-object pattern: known fields plus a flattened catch-all map
+    #[cfg(feature = "serde")]
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    struct OpenAttributesJson {
+        id: String,
+        count: Option<u32>,
+        #[serde(flatten)]
+        extra: std::collections::HashMap<String, Vec<String>>,
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde", feature = "jsonschema"))]
+    fn test_flattened_map_json_schema() {
+        let schema = OpenAttributesJson::json_schema();
+        let properties = schema["properties"].as_object().unwrap();
+        let required = schema["required"].as_array().unwrap();
+
+        assert_eq!(
+            schema["additionalProperties"],
+            serde_json::json!({ "type": "array", "items": { "type": "string" } })
+        );
+        assert!(properties.contains_key("id"));
+        assert!(!properties.contains_key("extra"));
+        assert!(!required.contains(&serde_json::json!("extra")));
+
+        let value = serde_json::to_value(OpenAttributesJson {
+            id: "1".to_string(),
+            count: None,
+            extra: [("colors".to_string(), vec!["red".to_string()])].into(),
+        })
+        .unwrap();
+        assert_eq!(value["colors"], serde_json::json!(["red"]));
+        assert!(value.get("extra").is_none());
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde", feature = "zod"))]
+    fn test_flattened_map_zod_catchall() {
+        let zod_schema = OpenAttributesJson::zod_schema();
+
+        assert!(zod_schema.contains("id: z.string(),"));
+        assert!(!zod_schema.contains("extra:"));
+        assert!(zod_schema.ends_with("}).catchall(z.array(z.string()));"));
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde", feature = "typescript"))]
+    fn test_flattened_map_ts_index_signature() {
+        let ts_definition = OpenAttributesJson::ts_definition();
+
+        assert!(ts_definition.contains("id: string;"));
+        assert!(ts_definition.contains("Additional properties: Array<string>"));
+        assert!(ts_definition.contains("[key: string]: unknown;"));
+        assert!(!ts_definition.contains("extra:"));
+    }
+
+    // Shared audit fields spliced into other documents with a flattened struct
+    #[cfg(feature = "serde")]
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    struct AuditFieldsJson {
+        created_by: String,
+        revision: u32,
+    }
+
+    #[cfg(feature = "serde")]
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    struct TicketNoteJson {
+        body: String,
+        #[serde(flatten)]
+        audit: AuditFieldsJson,
+    }
+
+    /// Keys a JSON object can have under `schema` when it closes with `unevaluatedProperties:
+    /// false`: its own `properties` and those of its `allOf` subschemas, minus what's required.
+    #[cfg(all(feature = "serde", feature = "jsonschema"))]
+    fn evaluates(schema: &serde_json::Value, value: &serde_json::Value) -> bool {
+        fn collect(schema: &serde_json::Value, known: &mut Vec<String>, required: &mut Vec<String>) {
+            if let Some(properties) = schema["properties"].as_object() {
+                known.extend(properties.keys().cloned());
+            }
+            if let Some(keys) = schema["required"].as_array() {
+                required.extend(keys.iter().map(|k| k.as_str().unwrap().to_string()));
+            }
+            for subschema in schema["allOf"].as_array().into_iter().flatten() {
+                // A subschema closing on its own keys would reject the others'
+                assert!(subschema.get("additionalProperties").is_none());
+                collect(subschema, known, required);
+            }
+        }
+
+        let (mut known, mut required) = (Vec::new(), Vec::new());
+        collect(schema, &mut known, &mut required);
+        assert_eq!(schema["unevaluatedProperties"], serde_json::json!(false));
+        let object = value.as_object().unwrap();
+        object.keys().all(|key| known.contains(key)) && required.iter().all(|key| object.contains_key(key))
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde", feature = "jsonschema"))]
+    fn test_flattened_struct_json_schema_composes_with_all_of() {
+        let schema = TicketNoteJson::json_schema();
+
+        assert!(schema.get("additionalProperties").is_none());
+        assert_eq!(schema["properties"], serde_json::json!({ "body": { "type": "string" } }));
+        assert_eq!(schema["required"], serde_json::json!(["body"]));
+
+        let mut audit = AuditFieldsJson::json_schema();
+        audit.as_object_mut().unwrap().remove("additionalProperties");
+        assert_eq!(schema["allOf"], serde_json::json!([audit]));
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde", feature = "jsonschema"))]
+    fn test_flattened_struct_json_schema_rejects_unknown_keys() {
+        let schema = TicketNoteJson::json_schema();
+        let note = serde_json::to_value(TicketNoteJson {
+            body: "Refund issued".to_string(),
+            audit: AuditFieldsJson { created_by: "ana".to_string(), revision: 2 },
+        })
+        .unwrap();
+
+        // Base and child keys side by side
+        assert_eq!(note, serde_json::json!({ "body": "Refund issued", "created_by": "ana", "revision": 2 }));
+        assert!(evaluates(&schema, &note));
+
+        let mut unknown = note.clone();
+        unknown["priority"] = serde_json::json!("high");
+        assert!(!evaluates(&schema, &unknown));
+
+        let mut missing_base_key = note.clone();
+        missing_base_key.as_object_mut().unwrap().remove("revision");
+        assert!(!evaluates(&schema, &missing_base_key));
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde", feature = "typescript", feature = "zod"))]
+    fn test_flattened_struct_ts_and_zod() {
+        let ts_definition = TicketNoteJson::ts_definition();
+        assert!(ts_definition.contains("export type TicketNote = AuditFields & {"));
+        assert!(ts_definition.contains("body: string;"));
+        assert!(!ts_definition.contains("audit"));
+
+        let zod_schema = TicketNoteJson::zod_schema();
+        assert!(zod_schema.contains("z.strictObject({\n  ...AuditFields$Schema.shape,\n  body: z.string(),"));
+        assert_eq!(TicketNoteJson::schema_dependencies(), vec!["AuditFields".to_string()]);
+    }
+
+    // Flattened keys keep the casing of the struct they come from, as serde writes them
+    #[cfg(feature = "serde")]
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    struct CamelAuditJson {
+        created_by: String,
+        last_revision: u32,
+    }
+
+    #[cfg(feature = "serde")]
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    struct RefundRequestJson {
+        order_id: String,
+        #[serde(flatten)]
+        audit: CamelAuditJson,
+    }
+
+    // `strict` warns about the flattened `AuditFields`, whose keys stay snake_case; the warning
+    // is a deprecation lint, silenced here like any other
+    #[cfg(feature = "serde")]
+    #[model_schema(strict)]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    #[allow(deprecated)]
+    struct ChargebackJson {
+        order_id: String,
+        #[serde(flatten)]
+        audit: AuditFieldsJson,
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde", feature = "jsonschema"))]
+    fn test_flattened_struct_matching_casing() {
+        let request = serde_json::to_value(RefundRequestJson {
+            order_id: "o-1".to_string(),
+            audit: CamelAuditJson { created_by: "ana".to_string(), last_revision: 3 },
+        })
+        .unwrap();
+        assert_eq!(request, serde_json::json!({ "orderId": "o-1", "createdBy": "ana", "lastRevision": 3 }));
+
+        let schema = RefundRequestJson::json_schema();
+        assert!(evaluates(&schema, &request));
+        assert_eq!(schema["allOf"][0]["required"], serde_json::json!(["createdBy", "lastRevision"]));
+
+        // Snake-cased keys are neither the parent's nor the base's
+        let snake = serde_json::json!({ "order_id": "o-1", "created_by": "ana", "last_revision": 3 });
+        assert!(!evaluates(&schema, &snake));
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde", feature = "jsonschema"))]
+    fn test_flattened_struct_mismatching_casing() {
+        let chargeback = serde_json::to_value(ChargebackJson {
+            order_id: "o-1".to_string(),
+            audit: AuditFieldsJson { created_by: "ana".to_string(), revision: 1 },
+        })
+        .unwrap();
+        // serde mixes the casings, and the schema follows it
+        assert_eq!(chargeback, serde_json::json!({ "orderId": "o-1", "created_by": "ana", "revision": 1 }));
+        assert!(evaluates(&ChargebackJson::json_schema(), &chargeback));
+
+        let camel = serde_json::json!({ "orderId": "o-1", "createdBy": "ana", "revision": 1 });
+        assert!(!evaluates(&ChargebackJson::json_schema(), &camel));
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde", feature = "typescript"))]
+    fn test_flattened_struct_casing_ts_definition() {
+        let ts_definition = RefundRequestJson::ts_definition();
+        assert!(ts_definition.contains("export type RefundRequest = CamelAudit & {"));
+        assert!(ts_definition.contains("orderId: string;"));
+        assert!(!ts_definition.contains("order_id"));
+        assert!(CamelAuditJson::ts_definition().contains("createdBy: string;"));
+
+        let ts_definition = ChargebackJson::ts_definition();
+        assert!(ts_definition.contains("export type Chargeback = AuditFields & {"));
+        assert!(AuditFieldsJson::ts_definition().contains("created_by: string;"));
+    }
+
+    // rename_all doesn't propagate to nested types: the map values need their own.
+    // `strict` warns about `items_by_sku`, since the macro can't see InventoryItemJson's
+    // attributes. The warning is checked here, so it's silenced like any other lint.
+    #[cfg(feature = "serde")]
+    #[model_schema(strict)]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    #[allow(deprecated)]
+    struct InventoryJson {
+        warehouse_id: String,
+        items_by_sku: std::collections::HashMap<String, InventoryItemJson>,
+    }
+
+    #[cfg(feature = "serde")]
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    struct InventoryItemJson {
+        unit_count: u32,
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde", feature = "typescript"))]
+    fn test_strict_keeps_generated_output() {
+        let ts_definition = InventoryJson::ts_definition();
+        assert!(ts_definition.contains("warehouseId: string;"));
+        assert!(ts_definition.contains("itemsBySku: Partial<Record<string, InventoryItem>>;"));
+        assert!(InventoryItemJson::ts_definition().contains("unitCount: number;"));
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde", feature = "jsonschema"))]
+    fn test_nested_map_value_uses_its_own_casing() {
+        let item = InventoryItemJson { unit_count: 3 };
+        let value = serde_json::to_value(InventoryJson {
+            warehouse_id: "w1".to_string(),
+            items_by_sku: [("sku-1".to_string(), item)].into_iter().collect(),
+        })
+        .unwrap();
+
+        let item_schema = InventoryItemJson::json_schema();
+        let item_properties = item_schema["properties"].as_object().unwrap();
+        for key in value["itemsBySku"]["sku-1"].as_object().unwrap().keys() {
+            assert!(item_properties.contains_key(key));
+        }
+    }
+
+    // Fields serde never writes are left out; their types aren't looked at either
+    #[cfg(feature = "serde")]
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    struct SessionJson {
+        user_id: String,
+        #[serde(skip)]
+        last_seen: Option<std::time::Instant>,
+        #[serde(skip_serializing)]
+        #[model_schema_prop(min = 8)]
+        password_hash: String,
+        #[serde(skip_deserializing)]
+        issued_by: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        device_name: Option<String>,
+    }
+
+    #[cfg(feature = "serde")]
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[serde(tag = "type")]
+    #[allow(dead_code)]
+    enum SessionEventJson {
+        Opened { session_id: String, #[serde(skip)] opened_at: Option<std::time::Instant> },
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde", feature = "jsonschema"))]
+    fn test_skipped_fields_json_schema() {
+        let schema = SessionJson::json_schema();
+        let properties = schema["properties"].as_object().unwrap();
+        assert_eq!(properties.keys().collect::<Vec<_>>(), vec!["deviceName", "issuedBy", "userId"]);
+        assert_eq!(schema["required"], serde_json::json!(["userId", "issuedBy"]));
+
+        let value = serde_json::to_value(SessionJson {
+            user_id: "u1".to_string(),
+            last_seen: Some(std::time::Instant::now()),
+            password_hash: "secret-hash".to_string(),
+            issued_by: "login".to_string(),
+            device_name: None,
+        })
+        .unwrap();
+        for key in value.as_object().unwrap().keys() {
+            assert!(properties.contains_key(key));
+        }
+
+        let event = SessionEventJson::json_schema();
+        let variant = &event["oneOf"][0];
+        assert!(variant["properties"].get("opened_at").is_none());
+        assert!(!variant["required"].as_array().unwrap().contains(&serde_json::json!("opened_at")));
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde", feature = "typescript"))]
+    fn test_skipped_fields_ts_definition() {
+        let ts_definition = SessionJson::ts_definition();
+        assert!(ts_definition.contains("userId: string;"));
+        assert!(ts_definition.contains("issuedBy: string;"));
+        assert!(ts_definition.contains("deviceName: string | undefined;"));
+        assert!(!ts_definition.contains("lastSeen"));
+        assert!(!ts_definition.contains("passwordHash"));
+        assert!(!SessionEventJson::ts_definition().contains("opened_at"));
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde", feature = "zod"))]
+    fn test_skipped_fields_zod_schema() {
+        let zod_schema = SessionJson::zod_schema();
+        assert!(zod_schema.contains("userId: z.string(),"));
+        assert!(!zod_schema.contains("lastSeen"));
+        assert!(!zod_schema.contains("passwordHash"));
+        assert!(!zod_schema.contains("min(8)"));
+    }
+
+    // The same fields in the shape serde reads: `skip_deserializing` fields are the ones left out
+    #[cfg(feature = "serde")]
+    #[model_schema(deserialize_shape)]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    struct SessionInputJson {
+        user_id: String,
+        #[serde(skip)]
+        last_seen: Option<std::time::Instant>,
+        #[serde(skip_serializing)]
+        password: String,
+        #[serde(skip_deserializing)]
+        issued_by: String,
+    }
+
+    #[cfg(feature = "serde")]
+    #[model_schema(deserialize_shape)]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[serde(tag = "type")]
+    #[allow(dead_code)]
+    enum SessionCommandJson {
+        Open { #[serde(skip_serializing)] token: String, #[serde(skip_deserializing)] opened_by: String },
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde", feature = "jsonschema"))]
+    fn test_deserialize_shape_json_schema() {
+        let schema = SessionInputJson::json_schema();
+        let properties = schema["properties"].as_object().unwrap();
+        assert_eq!(properties.keys().collect::<Vec<_>>(), vec!["password", "userId"]);
+        assert_eq!(schema["required"], serde_json::json!(["userId", "password"]));
+
+        // Every property the schema requires is one serde reads
+        let input: SessionInputJson = serde_json::from_value(serde_json::json!({ "userId": "u1", "password": "pw" })).unwrap();
+        assert_eq!(input.password, "pw");
+        assert_eq!(input.issued_by, "");
+
+        let variant = &SessionCommandJson::json_schema()["oneOf"][0];
+        assert!(variant["properties"].get("token").is_some());
+        assert!(variant["properties"].get("opened_by").is_none());
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde", feature = "typescript"))]
+    fn test_deserialize_shape_ts_definition() {
+        let ts_definition = SessionInputJson::ts_definition();
+        assert!(ts_definition.contains("userId: string;"));
+        assert!(ts_definition.contains("password: string;"));
+        assert!(!ts_definition.contains("issuedBy"));
+        assert!(!ts_definition.contains("lastSeen"));
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde", feature = "zod"))]
+    fn test_deserialize_shape_zod_schema() {
+        let zod_schema = SessionInputJson::zod_schema();
+        assert!(zod_schema.contains("password: z.string(),"));
+        assert!(!zod_schema.contains("issuedBy"));
+
+        let command = SessionCommandJson::zod_schema();
+        assert!(command.contains("token: z.string(),"));
+        assert!(!command.contains("opened_by"));
+    }
+
+    fn default_page_size() -> u32 {
+        25
+    }
+
+    // Fields serde fills in when missing may be left out of the input
+    #[cfg(feature = "serde")]
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    struct EventSearchJson {
+        query: String,
+        #[serde(default)]
+        include_past: bool,
+        #[serde(default = "default_page_size")]
+        page_size: u32,
+        #[serde(default)]
+        categories: Vec<String>,
+        // Keys serde takes a value for, before `default`, don't hide it
+        #[serde(alias = "maxPrice", default)]
+        price_cap: u32,
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde", feature = "jsonschema"))]
+    fn test_default_fields_json_schema() {
+        let schema = EventSearchJson::json_schema();
+        assert_eq!(schema["required"], serde_json::json!(["query"]));
+        assert_eq!(schema["properties"]["pageSize"]["type"], "integer");
+
+        let search: EventSearchJson = serde_json::from_value(serde_json::json!({ "query": "jazz" })).unwrap();
+        assert_eq!(search.page_size, 25);
+        assert!(!search.include_past);
+        assert!(search.categories.is_empty());
+        assert_eq!(schema["properties"]["priceCap"]["type"], "integer");
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde", feature = "typescript"))]
+    fn test_default_fields_ts_definition() {
+        let ts_definition = EventSearchJson::ts_definition();
+        assert!(ts_definition.contains("query: string;"));
+        assert!(ts_definition.contains("includePast: boolean | undefined;"));
+        assert!(ts_definition.contains("pageSize: number | undefined;"));
+        assert!(ts_definition.contains("categories: Array<string> | undefined;"));
+        assert!(ts_definition.contains("priceCap: number | undefined;"));
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde", feature = "zod"))]
+    fn test_default_fields_zod_schema() {
+        let zod_schema = EventSearchJson::zod_schema();
+        assert!(zod_schema.contains("query: z.string(),"));
+        assert!(zod_schema.contains("includePast: z.boolean().or(z.undefined()),"));
+        assert!(zod_schema.contains("pageSize: z.number().int().or(z.undefined()),"));
+        assert!(zod_schema.contains("categories: z.array(z.string()).or(z.undefined()),"));
+    }
+
+    // Every serde casing applies, to field names and variant names alike
+    #[cfg(feature = "serde")]
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "kebab-case")]
+    struct AttendeeJson {
+        first_name: String,
+        last_name: String,
+        badge_status: BadgeStatusJson,
+    }
+
+    #[cfg(feature = "serde")]
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    enum BadgeStatusJson {
+        NotPrinted,
+        PickedUp,
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde", feature = "typescript"))]
+    fn test_kebab_case_ts_definition() {
+        let ts_definition = AttendeeJson::ts_definition();
+        assert!(ts_definition.contains("\"first-name\": string;"));
+        assert!(ts_definition.contains("\"badge-status\": BadgeStatus;"));
+        assert!(BadgeStatusJson::ts_definition().contains("\"NOT_PRINTED\" | \"PICKED_UP\""));
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde", feature = "zod"))]
+    fn test_kebab_case_zod_schema() {
+        let zod_schema = AttendeeJson::zod_schema();
+        assert!(zod_schema.contains("\"first-name\": z.string(),"));
+        assert!(zod_schema.contains("\"last-name\": z.string(),"));
+        assert!(zod_schema.contains("\"badge-status\": BadgeStatus$Schema,"));
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde", feature = "jsonschema"))]
+    fn test_kebab_case_json_schema() {
+        let schema = AttendeeJson::json_schema();
+        let properties = schema["properties"].as_object().unwrap();
+        assert_eq!(properties.keys().collect::<Vec<_>>(), vec!["badge-status", "first-name", "last-name"]);
+        assert_eq!(properties["badge-status"]["enum"], serde_json::json!(["NOT_PRINTED", "PICKED_UP"]));
+
+        let value = serde_json::to_value(AttendeeJson {
+            first_name: "Ada".to_string(),
+            last_name: "Lovelace".to_string(),
+            badge_status: BadgeStatusJson::PickedUp,
+        })
+        .unwrap();
+        assert_eq!(value["badge-status"], "PICKED_UP");
+        for key in value.as_object().unwrap().keys() {
+            assert!(properties.contains_key(key));
+        }
+    }
+
+    // Transparent structs are written as their only field, so they're aliases of its type
+    #[cfg(feature = "serde")]
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[serde(transparent)]
+    struct OrderIdJson(String);
+
+    #[cfg(feature = "serde")]
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[serde(transparent)]
+    struct SeatCountJson {
+        count: u64,
+    }
+
+    #[cfg(feature = "serde")]
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[serde(transparent)]
+    struct TagListJson {
+        tags: Vec<String>,
+        #[serde(skip)]
+        cached_label: Option<String>,
+    }
+
+    #[cfg(feature = "serde")]
+    #[model_schema()]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    struct OrderRefJson {
+        id: OrderIdJson,
+        seats: SeatCountJson,
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde", feature = "typescript"))]
+    fn test_transparent_struct_ts_definition_is_alias() {
+        assert!(OrderIdJson::ts_definition().ends_with("export type OrderId = string;"));
+        assert!(SeatCountJson::ts_definition().ends_with("export type SeatCount = number;"));
+        assert!(TagListJson::ts_definition().ends_with("export type TagList = Array<string>;"));
+        assert!(!TagListJson::ts_definition().contains("cached_label"));
+
+        let ts_definition = OrderRefJson::ts_definition();
+        assert!(ts_definition.contains("id: OrderId;"));
+        assert!(ts_definition.contains("seats: SeatCount;"));
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde", feature = "zod"))]
+    fn test_transparent_struct_zod_schema() {
+        assert!(OrderIdJson::zod_schema().contains("OrderId$Schema"));
+        assert!(OrderIdJson::zod_schema().ends_with(" = z.string();"));
+        assert!(TagListJson::zod_schema().ends_with(" = z.array(z.string());"));
+        assert!(!OrderIdJson::zod_schema().contains("strictObject"));
+        assert!(OrderRefJson::zod_schema().contains("id: OrderId$Schema,"));
+        assert_eq!(OrderRefJson::schema_dependencies(), vec!["OrderId", "SeatCount"]);
+        assert!(OrderIdJson::schema_dependencies().is_empty());
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde", feature = "jsonschema"))]
+    fn test_transparent_struct_json_schema_matches_serde_output() {
+        assert_eq!(OrderIdJson::json_schema(), serde_json::json!({ "type": "string" }));
+        assert_eq!(SeatCountJson::json_schema()["type"], "integer");
+        assert_eq!(
+            TagListJson::json_schema(),
+            serde_json::json!({ "type": "array", "items": { "type": "string" } })
+        );
+
+        let order = OrderRefJson { id: OrderIdJson("ord_1".to_string()), seats: SeatCountJson { count: 2 } };
+        let value = serde_json::to_value(&order).unwrap();
+        assert_eq!(value, serde_json::json!({ "id": "ord_1", "seats": 2 }));
+
+        let properties = &OrderRefJson::json_schema()["properties"];
+        assert_eq!(properties["id"], OrderIdJson::json_schema());
+        assert_eq!(properties["seats"], SeatCountJson::json_schema());
+    }
+}
@@ -0,0 +1,9 @@
+use tixschema::model_schema;
+
+#[model_schema()]
+struct SettingJson {
+    #[model_schema_prop(as = "Vec<String>")]
+    values: String,
+}
+
+fn main() {}
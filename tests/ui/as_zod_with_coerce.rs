@@ -0,0 +1,10 @@
+use tixschema::model_schema;
+
+#[model_schema()]
+struct EventJson {
+    #[model_schema_prop(coerce)]
+    #[model_schema_prop(as_ts = "Date", as_zod = "z.date()")]
+    starts_at: String,
+}
+
+fn main() {}
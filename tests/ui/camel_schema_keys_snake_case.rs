@@ -0,0 +1,10 @@
+use tixschema::model_schema;
+
+#[model_schema(camel_schema_keys)]
+struct AccountJson {
+    display_name: String,
+    email: String,
+    created_at: u64,
+}
+
+fn main() {}
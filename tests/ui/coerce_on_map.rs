@@ -0,0 +1,9 @@
+use tixschema::model_schema;
+
+#[model_schema()]
+struct FilterJson {
+    #[model_schema_prop(coerce)]
+    limits: std::collections::HashMap<String, u32>,
+}
+
+fn main() {}
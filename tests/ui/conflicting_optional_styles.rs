@@ -0,0 +1,9 @@
+use tixschema::model_schema;
+
+#[model_schema(optionals_absent, optional_style = "null")]
+struct TicketJson {
+    id: String,
+    seat: Option<String>,
+}
+
+fn main() {}
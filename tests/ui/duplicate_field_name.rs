@@ -0,0 +1,12 @@
+use serde::Serialize;
+use tixschema::model_schema;
+
+#[model_schema()]
+#[derive(Serialize)]
+struct ContactJson {
+    email: String,
+    #[serde(rename = "email")]
+    backup_email: String,
+}
+
+fn main() {}
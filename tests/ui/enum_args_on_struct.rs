@@ -0,0 +1,8 @@
+use tixschema::model_schema;
+
+#[model_schema(tag = "kind")]
+struct TicketJson {
+    id: String,
+}
+
+fn main() {}
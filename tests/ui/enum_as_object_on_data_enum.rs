@@ -0,0 +1,9 @@
+use tixschema::model_schema;
+
+#[model_schema(enum_as_object = "kind")]
+enum ShapeJson {
+    Circle { radius: f64 },
+    Square { side: f64 },
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use tixschema::model_schema;
+
+#[model_schema()]
+struct ThemeJson {
+    #[model_schema_prop(enum_values = ["1", "2"])]
+    contrast: u8,
+}
+
+fn main() {}
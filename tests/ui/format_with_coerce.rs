@@ -0,0 +1,9 @@
+use tixschema::model_schema;
+
+#[model_schema()]
+struct ContactJson {
+    #[model_schema_prop(format = "email", coerce)]
+    email: String,
+}
+
+fn main() {}
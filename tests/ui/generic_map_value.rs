@@ -0,0 +1,10 @@
+use tixschema::model_schema;
+
+struct Page<T>(T);
+
+#[model_schema()]
+struct CatalogJson {
+    pages: std::collections::HashMap<String, Page<u32>>,
+}
+
+fn main() {}
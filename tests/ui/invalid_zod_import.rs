@@ -0,0 +1,8 @@
+use tixschema::model_schema;
+
+#[model_schema(zod_import = "my-zod")]
+struct TicketJson {
+    id: String,
+}
+
+fn main() {}
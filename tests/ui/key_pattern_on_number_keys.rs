@@ -0,0 +1,9 @@
+use tixschema::model_schema;
+
+#[model_schema()]
+struct InventoryJson {
+    #[model_schema_prop(keyPattern = "^[0-9]+$")]
+    stock: std::collections::BTreeMap<u32, u32>,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use tixschema::model_schema;
+
+#[model_schema(tag = "kind")]
+enum GateEventJson {
+    Opened {
+        #[model_schema_prop(literal = "true")]
+        accessible: Option<bool>,
+    },
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use tixschema::model_schema;
+
+#[model_schema()]
+struct PriceTierJson {
+    name: String,
+    #[model_schema_prop(literal = "5")]
+    seats: u32,
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+use serde::Serialize;
+use tixschema::model_schema;
+
+#[model_schema()]
+#[derive(Serialize)]
+struct ReportJson {
+    title: String,
+}
+
+impl ReportJson {
+    // Clashes with the generated method; rename it with
+    // `#[model_schema(json_schema = "...")]`
+    pub fn json_schema() -> String {
+        String::new()
+    }
+}
+
+fn main() {}
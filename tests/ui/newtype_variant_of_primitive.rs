@@ -0,0 +1,9 @@
+use tixschema::model_schema;
+
+#[model_schema(tag = "kind")]
+enum CounterJson {
+    Reset { at: String },
+    Count(u32),
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+#![deny(deprecated)]
+
+use tixschema::model_schema;
+
+#[model_schema()]
+struct UserJson {
+    id: String,
+}
+
+fn main() {
+    let _ = UserJson { id: String::new() }.id;
+}
@@ -0,0 +1,43 @@
+// Built as a `#![no_std]` library by `test_no_std_typescript_only_builds`, not as a ui case.
+#![no_std]
+
+extern crate alloc;
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use tixschema::{model_schema, model_schema_alias, model_schema_bundle, model_schema_union};
+
+#[model_schema()]
+pub struct AddressJson {
+    pub city: String,
+    pub zip: Option<u32>,
+}
+
+/// A registered user
+#[model_schema()]
+pub struct UserJson {
+    pub name: String,
+    pub tags: Vec<String>,
+    pub address: AddressJson,
+}
+
+#[model_schema()]
+pub enum StatusJson {
+    Active,
+    Inactive,
+}
+
+#[model_schema(tag = "type")]
+pub enum EventJson {
+    Created { id: String },
+    Deleted,
+}
+
+model_schema_union!(pub RecordJson = AddressJson | UserJson);
+model_schema_alias!(pub AccountJson = UserJson);
+
+pub fn declarations() -> String {
+    model_schema_bundle!(UserJson, AddressJson, StatusJson, EventJson, RecordJson, AccountJson)
+}
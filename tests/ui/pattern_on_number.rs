@@ -0,0 +1,9 @@
+use tixschema::model_schema;
+
+#[model_schema()]
+struct ProductJson {
+    #[model_schema_prop(pattern = "^[0-9]+$")]
+    sku: u64,
+}
+
+fn main() {}
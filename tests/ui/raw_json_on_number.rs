@@ -0,0 +1,9 @@
+use tixschema::model_schema;
+
+#[model_schema()]
+struct AuditEntryJson {
+    #[model_schema_prop(raw_json)]
+    payload_size: u64,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use tixschema::model_schema;
+
+#[model_schema()]
+struct CredentialsJson {
+    #[model_schema_prop(read_only, write_only)]
+    password: String,
+}
+
+fn main() {}
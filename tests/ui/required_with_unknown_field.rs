@@ -0,0 +1,10 @@
+use tixschema::model_schema;
+
+#[model_schema()]
+struct ShippingJson {
+    country: Option<String>,
+    #[model_schema_prop(required_with = "county")]
+    postal_code: Option<String>,
+}
+
+fn main() {}
@@ -0,0 +1,32 @@
+#![deny(deprecated)]
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use tixschema::model_schema;
+
+#[model_schema()]
+#[derive(Serialize, Deserialize)]
+struct StockJson {
+    unit_count: u32,
+}
+
+#[model_schema(strict)]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WarehouseJson {
+    warehouse_id: String,
+    stock_by_sku: HashMap<String, StockJson>,
+}
+
+// Checked by hand, so the warning is silenced
+#[model_schema(strict)]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(deprecated)]
+struct DepotJson {
+    depot_id: String,
+    stock: StockJson,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use tixschema::model_schema;
+
+#[model_schema(tag = "kind")]
+enum ShapeJson {
+    Circle { radius: f64 },
+    Label { kind: String, text: String },
+}
+
+fn main() {}
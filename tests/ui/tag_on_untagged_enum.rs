@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+use tixschema::model_schema;
+
+#[model_schema(tag = "kind")]
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum AmountJson {
+    Cents(u64),
+    Text(String),
+}
+
+fn main() {}
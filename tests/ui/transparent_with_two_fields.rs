@@ -0,0 +1,12 @@
+use serde::Serialize;
+use tixschema::model_schema;
+
+#[model_schema()]
+#[derive(Serialize)]
+#[serde(transparent)]
+struct OrderIdJson {
+    value: String,
+    prefix: String,
+}
+
+fn main() {}
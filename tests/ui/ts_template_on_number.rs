@@ -0,0 +1,9 @@
+use tixschema::model_schema;
+
+#[model_schema()]
+struct InvoiceJson {
+    #[model_schema_prop(ts_template = "${number}")]
+    total: u32,
+}
+
+fn main() {}
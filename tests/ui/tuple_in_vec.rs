@@ -0,0 +1,8 @@
+use tixschema::model_schema;
+
+#[model_schema()]
+struct PairsJson {
+    pairs: Vec<Vec<(u32, String)>>,
+}
+
+fn main() {}
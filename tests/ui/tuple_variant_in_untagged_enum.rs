@@ -0,0 +1,11 @@
+use tixschema::model_schema;
+
+#[model_schema()]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+enum ShapeJson {
+    Circle { radius: f64 },
+    Point(f32, f32),
+}
+
+fn main() {}
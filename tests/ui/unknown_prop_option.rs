@@ -0,0 +1,9 @@
+use tixschema::model_schema;
+
+#[model_schema()]
+struct ArticleJson {
+    #[model_schema_prop(readonly)]
+    slug: String,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use tixschema::model_schema;
+
+#[model_schema(rename_all = "camelcase")]
+struct ProfileJson {
+    display_name: String,
+}
+
+fn main() {}
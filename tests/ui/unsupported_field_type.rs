@@ -0,0 +1,10 @@
+use tixschema::model_schema;
+
+struct Wrapper<T>(T);
+
+#[model_schema()]
+struct HandlerJson {
+    callback: Wrapper<String>,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use tixschema::model_schema;
+
+#[model_schema()]
+union NumberJson {
+    int: u32,
+    float: f32,
+}
+
+fn main() {}
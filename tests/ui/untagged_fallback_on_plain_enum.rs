@@ -0,0 +1,9 @@
+use tixschema::model_schema;
+
+#[model_schema(untagged_fallback)]
+enum StatusJson {
+    Active,
+    Suspended,
+}
+
+fn main() {}
@@ -0,0 +1,71 @@
+// Compile-fail tests for the macro's diagnostics: every `tests/ui/**/*.rs` case must fail to
+// build with exactly the errors in the `.stderr` file next to it. The cases are built by
+// `trybuild` with the features of the current test run.
+//
+// Run with `TRYBUILD=overwrite` to (re)write the `.stderr` files after changing a message.
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "typescript")]
+    use std::fs;
+    #[cfg(feature = "typescript")]
+    use std::path::{Path, PathBuf};
+    #[cfg(feature = "typescript")]
+    use std::process::Command;
+
+    /// Builds `tests/ui/no_std/typescript_only.rs` as a `#![no_std]` library using tixschema with
+    /// only the `typescript` feature and without a serde_json dependency, so the generated
+    /// `ts_definition()` code must get by with `core` and `alloc`.
+    #[test]
+    #[cfg(feature = "typescript")]
+    fn test_no_std_typescript_only_builds() {
+        let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let target_dir = std::env::var_os("CARGO_TARGET_DIR")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| manifest_dir.join("target"));
+        let project_dir = target_dir.join("tests/ui/no_std");
+        fs::create_dir_all(&project_dir).unwrap();
+
+        let manifest = format!(
+            "[package]\nname = \"tixschema-no-std\"\nversion = \"0.0.0\"\nedition = \"2024\"\npublish = false\n\n\
+             [lib]\npath = \"{}\"\n\n\
+             [dependencies]\n\
+             tixschema = {{ path = \"{}\", default-features = false, features = [\"typescript\"] }}\n\n\
+             [workspace]\n",
+            manifest_dir.join("tests/ui/no_std/typescript_only.rs").display(),
+            manifest_dir.display()
+        );
+        fs::write(project_dir.join("Cargo.toml"), manifest).unwrap();
+        fs::copy(manifest_dir.join("Cargo.lock"), project_dir.join("Cargo.lock")).unwrap();
+
+        let output = Command::new(option_env!("CARGO").unwrap_or("cargo"))
+            .current_dir(&project_dir)
+            .args(["check", "--quiet", "--offline", "--lib", "--target-dir"])
+            .arg(target_dir.join("tests/ui/target"))
+            .env_remove("CARGO_TARGET_DIR")
+            .output()
+            .unwrap();
+        let stderr = String::from_utf8(output.stderr).unwrap();
+        assert!(output.status.success(), "no_std build failed:\n{stderr}");
+        assert!(!stderr.contains("warning"), "no_std build warned:\n{stderr}");
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde", feature = "jsonschema"))]
+    fn test_ui_errors() {
+        trybuild::TestCases::new().compile_fail("tests/ui/*.rs");
+    }
+
+    /// A user method named like a generated one. The follow-up errors depend on which generated
+    /// methods call it, so this only runs with all the output features.
+    #[test]
+    #[cfg(all(feature = "serde", feature = "typescript", feature = "zod", feature = "jsonschema"))]
+    fn test_ui_method_collision() {
+        trybuild::TestCases::new().compile_fail("tests/ui/method_collision/*.rs");
+    }
+
+    #[test]
+    #[cfg(not(any(feature = "typescript", feature = "zod", feature = "jsonschema")))]
+    fn test_ui_missing_output_feature() {
+        trybuild::TestCases::new().compile_fail("tests/ui/no_output_feature/*.rs");
+    }
+}
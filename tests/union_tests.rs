@@ -0,0 +1,85 @@
+#[cfg(test)]
+mod tests {
+    #[cfg(all(test, feature = "serde"))]
+    use serde::{Deserialize, Serialize};
+    #[cfg(all(
+        test,
+        any(feature = "typescript", feature = "jsonschema", feature = "zod")
+    ))]
+    use tixschema::{model_schema, model_schema_union};
+
+    #[cfg(all(
+        test,
+        any(feature = "typescript", feature = "jsonschema", feature = "zod")
+    ))]
+    #[model_schema()]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "camelCase"))]
+    #[derive(Debug, Clone, PartialEq)]
+    enum UserEventJson {
+        UserCreated { user_id: String },
+        UserDeleted { user_id: String },
+    }
+
+    #[cfg(all(
+        test,
+        any(feature = "typescript", feature = "jsonschema", feature = "zod")
+    ))]
+    #[model_schema()]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "camelCase"))]
+    #[derive(Debug, Clone, PartialEq)]
+    enum BillingEventJson {
+        InvoicePaid { amount: u32 },
+    }
+
+    #[cfg(all(
+        test,
+        any(feature = "typescript", feature = "jsonschema", feature = "zod")
+    ))]
+    model_schema_union!(AllEventsJson = UserEventJson | BillingEventJson);
+
+    #[test]
+    #[cfg(feature = "typescript")]
+    fn test_union_ts_definition() {
+        let ts_definition = AllEventsJson::ts_definition();
+
+        assert!(ts_definition.starts_with("/**\n"));
+        assert!(ts_definition.contains("\n**/\nexport type AllEvents"));
+        assert!(ts_definition.contains("export type AllEvents = UserEvent | BillingEvent;"));
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_union_zod_schema() {
+        let zod_schema = AllEventsJson::zod_schema();
+
+        assert!(zod_schema.contains("AllEvents$Schema"));
+        assert!(zod_schema.contains("z.union([UserEvent$Schema, BillingEvent$Schema]);"));
+
+        // The member schemas referenced by the union are the generated ones
+        assert!(UserEventJson::zod_schema().contains("export const UserEvent$Schema"));
+        assert!(BillingEventJson::zod_schema().contains("export const BillingEvent$Schema"));
+    }
+
+    #[test]
+    #[cfg(all(feature = "typescript", feature = "zod"))]
+    fn test_union_zod_schema_typed() {
+        let zod_schema = AllEventsJson::zod_schema();
+
+        assert!(zod_schema.starts_with(
+            "export const AllEvents$Schema: ZodType<AllEvents> = z.union(["
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_union_json_schema() {
+        let schema = AllEventsJson::json_schema();
+
+        let one_of = schema["oneOf"].as_array().unwrap();
+        assert_eq!(one_of.len(), 2);
+        assert_eq!(one_of[0], UserEventJson::json_schema());
+        assert_eq!(one_of[1], BillingEventJson::json_schema());
+    }
+}
@@ -0,0 +1,170 @@
+#[cfg(all(test, feature = "uuid"))]
+mod tests {
+    #[cfg(all(test, feature = "serde"))]
+    use serde::{Deserialize, Serialize};
+    #[cfg(all(
+        test,
+        any(feature = "typescript", feature = "jsonschema", feature = "zod")
+    ))]
+    use std::collections::HashMap;
+    #[cfg(all(
+        test,
+        any(feature = "typescript", feature = "jsonschema", feature = "zod", feature = "serde")
+    ))]
+    use tixschema::model_schema;
+    #[cfg(all(
+        test,
+        any(feature = "typescript", feature = "jsonschema", feature = "zod")
+    ))]
+    use uuid::Uuid;
+
+    #[cfg(all(
+        test,
+        any(feature = "typescript", feature = "jsonschema", feature = "zod")
+    ))]
+    #[model_schema()]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq)]
+    struct SeatHoldJson {
+        id: Uuid,
+        session_id: Option<Uuid>,
+        seat_ids: Vec<Uuid>,
+        labels: HashMap<String, Uuid>,
+        holders: HashMap<Uuid, String>,
+    }
+
+    #[test]
+    #[cfg(feature = "typescript")]
+    fn test_uuid_typescript_types() {
+        let ts_definition = SeatHoldJson::ts_definition();
+
+        assert!(ts_definition.contains("id: string;"));
+        assert!(ts_definition.contains("session_id: string | undefined;"));
+        assert!(ts_definition.contains("seat_ids: Array<string>;"));
+        assert!(ts_definition.contains("labels: Partial<Record<string, string>>;"));
+        assert!(ts_definition.contains("holders: Partial<Record<string, string>>;"));
+        assert!(!ts_definition.contains("Uuid"));
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_uuid_zod_schema() {
+        let zod_schema = SeatHoldJson::zod_schema();
+
+        assert!(zod_schema.contains("id: z.uuid(),"));
+        assert!(zod_schema.contains("session_id: z.uuid().or(z.undefined()),"));
+        assert!(zod_schema.contains("seat_ids: z.array(z.uuid()),"));
+        assert!(zod_schema.contains("labels: z.record(z.string(), z.uuid()),"));
+        assert!(zod_schema.contains("holders: z.record(z.uuid(), z.string()),"));
+        assert!(!zod_schema.contains("Uuid$Schema"));
+    }
+
+    #[test]
+    #[cfg(any(feature = "typescript", feature = "zod"))]
+    fn test_uuid_is_not_a_dependency() {
+        assert!(SeatHoldJson::schema_dependencies().is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_uuid_json_schema() {
+        let schema = SeatHoldJson::json_schema();
+        let properties = &schema["properties"];
+        let uuid_schema = serde_json::json!({ "type": "string", "format": "uuid" });
+
+        assert_eq!(properties["id"], uuid_schema);
+        assert_eq!(properties["session_id"], uuid_schema);
+        assert_eq!(properties["seat_ids"], serde_json::json!({ "type": "array", "items": uuid_schema }));
+        assert_eq!(properties["labels"]["additionalProperties"], uuid_schema);
+        assert_eq!(
+            properties["holders"],
+            serde_json::json!({
+                "type": "object",
+                "additionalProperties": { "type": "string" },
+                "propertyNames": uuid_schema
+            })
+        );
+        assert_eq!(schema["required"], serde_json::json!(["id", "seat_ids", "labels", "holders"]));
+    }
+
+    // Uuid map values inside sequences and options
+    #[cfg(all(
+        test,
+        any(feature = "typescript", feature = "jsonschema", feature = "zod")
+    ))]
+    #[model_schema()]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq)]
+    struct SeatMapJson {
+        aliases: HashMap<String, Vec<Uuid>>,
+        retired: HashMap<String, Option<Uuid>>,
+    }
+
+    #[test]
+    #[cfg(feature = "jsonschema")]
+    fn test_uuid_nested_map_value_json_schema() {
+        let schema = SeatMapJson::json_schema();
+        let properties = &schema["properties"];
+        let uuid_schema = serde_json::json!({ "type": "string", "format": "uuid" });
+
+        assert_eq!(
+            properties["aliases"]["additionalProperties"],
+            serde_json::json!({ "type": "array", "items": uuid_schema })
+        );
+        assert_eq!(
+            properties["retired"]["additionalProperties"],
+            serde_json::json!({ "anyOf": [uuid_schema, { "type": "null" }] })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "zod")]
+    fn test_uuid_nested_map_value_zod_schema() {
+        let zod_schema = SeatMapJson::zod_schema();
+        assert!(zod_schema.contains("aliases: z.record(z.string(), z.array(z.uuid())),"));
+        assert!(zod_schema.contains("retired: z.record(z.string(), z.uuid().or(z.null())),"));
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde", feature = "jsonschema"))]
+    fn test_uuid_round_trip() {
+        let id = Uuid::from_u128(0x6f1c_2c5e_8a3b_4c1d_9e7f_0a1b_2c3d_4e5f);
+        let hold = SeatHoldJson {
+            id,
+            session_id: None,
+            seat_ids: vec![id],
+            labels: HashMap::from([("front".to_string(), id)]),
+            holders: HashMap::from([(id, "ana".to_string())]),
+        };
+        let value = serde_json::to_value(&hold).unwrap();
+
+        assert_eq!(value["id"], "6f1c2c5e-8a3b-4c1d-9e7f-0a1b2c3d4e5f");
+        assert_eq!(value["holders"]["6f1c2c5e-8a3b-4c1d-9e7f-0a1b2c3d4e5f"], "ana");
+        assert_eq!(serde_json::from_value::<SeatHoldJson>(value).unwrap(), hold);
+    }
+}
+
+// Without the `uuid` feature, a type named `Uuid` is the user's own. The JSON schema of a nested
+// type is looked up through its `...Json` Rust type, hence no `jsonschema` here.
+#[cfg(all(test, not(feature = "uuid"), feature = "typescript", not(feature = "jsonschema")))]
+mod own_types {
+    use tixschema::model_schema;
+
+    #[allow(dead_code)]
+    struct Uuid {
+        high: u64,
+        low: u64,
+    }
+
+    #[model_schema()]
+    #[allow(dead_code)]
+    struct TicketJson {
+        id: Uuid,
+    }
+
+    #[test]
+    fn test_own_uuid_is_a_sibling_type() {
+        assert!(TicketJson::ts_definition().contains("id: Uuid;"));
+        assert_eq!(TicketJson::schema_dependencies(), vec!["Uuid"]);
+    }
+}
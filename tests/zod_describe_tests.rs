@@ -0,0 +1,48 @@
+#[cfg(all(test, feature = "zod"))]
+mod tests {
+    use tixschema::model_schema;
+
+    #[model_schema()]
+    #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[allow(dead_code)]
+    struct TicketTierJson {
+        /// Name shown on the "checkout" page
+        pub name: String,
+        /// Price in cents.
+        /// Taxes are added at checkout.
+        pub price: u32,
+        /// Seats held back for the box office
+        pub held_seats: Option<Vec<String>>,
+        pub sort_order: u16,
+    }
+
+    #[test]
+    #[cfg(feature = "zod_describe")]
+    fn test_documented_fields_are_described() {
+        let zod_schema = TicketTierJson::zod_schema();
+
+        assert!(zod_schema.contains("name: z.string().describe(\"Name shown on the \\\"checkout\\\" page\"),"));
+        assert!(
+            zod_schema.contains("price: z.number().int().describe(\"Price in cents.\\nTaxes are added at checkout.\"),")
+        );
+        assert!(zod_schema.contains(
+            "held_seats: z.array(z.string()).or(z.undefined()).describe(\"Seats held back for the box office\"),"
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "zod_describe")]
+    fn test_undocumented_fields_are_not_described() {
+        let zod_schema = TicketTierJson::zod_schema();
+
+        assert!(zod_schema.contains("sort_order: z.number().int(),"));
+        assert!(!zod_schema.contains("sort_order: z.number().int().describe("));
+    }
+
+    #[test]
+    #[cfg(not(feature = "zod_describe"))]
+    fn test_zod_describe_is_opt_in() {
+        assert!(!TicketTierJson::zod_schema().contains(".describe("));
+    }
+}
@@ -0,0 +1,140 @@
+#[cfg(all(test, feature = "zod"))]
+mod tests {
+    use tixschema::{model_schema, model_schema_union};
+
+    #[model_schema(zod_import = "zod")]
+    #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[allow(dead_code)]
+    struct BoxOfficeJson {
+        name: String,
+        windows: Vec<u16>,
+        phone: Option<String>,
+        #[model_schema_prop(enum_values = ["z.east", "west"])]
+        wing: String,
+        #[model_schema_prop(pattern = "^z.[a-z]+$")]
+        code: String,
+    }
+
+    // A `/` in an `as_zod` expression is division, not the start of a regex
+    #[model_schema(zod_import = "zod", extern_map(Ledger = "Ledger"))]
+    #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[allow(dead_code)]
+    struct TillJson {
+        #[model_schema_prop(as_zod = "zod.number().refine((n) => n / 100 <= 1)")]
+        fill: f64,
+        cashier: String,
+        ledger: Ledger,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    struct Ledger;
+
+    #[model_schema(zod_import = "zod")]
+    #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[allow(dead_code)]
+    enum WindowStateJson {
+        Open,
+        Closed,
+    }
+
+    #[model_schema(tag = "type", zod_import = "lib.z")]
+    #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(tag = "type"))]
+    #[allow(dead_code)]
+    enum WindowEventJson {
+        Opened { at: String },
+        Closed { at: String, reason: Option<String> },
+    }
+
+    #[model_schema()]
+    #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[allow(dead_code)]
+    struct TurnstileJson {
+        gate: String,
+    }
+
+    /// Whether `schema` still calls Zod as `z`, outside of string and regex literals.
+    fn calls_default_import(schema: &str) -> bool {
+        let code = regex::Regex::new(r#""[^"]*"|/\^[^/]*/"#).unwrap().replace_all(schema, "");
+        regex::Regex::new(r"(^|[^\w$.])z\.").unwrap().is_match(&code)
+    }
+
+    #[test]
+    fn test_struct_uses_custom_import() {
+        let zod_schema = BoxOfficeJson::zod_schema();
+
+        assert!(zod_schema.contains("= zod.strictObject({"));
+        assert!(zod_schema.contains("name: zod.string(),"));
+        assert!(zod_schema.contains("windows: zod.array(zod.number().int()),"));
+        assert!(zod_schema.contains("phone: zod.string().or(zod.undefined()),"));
+        assert!(!calls_default_import(&zod_schema));
+    }
+
+    #[test]
+    fn test_literals_keep_their_text() {
+        let zod_schema = BoxOfficeJson::zod_schema();
+
+        assert!(zod_schema.contains("wing: zod.enum([\"z.east\", \"west\"]),"));
+        assert!(zod_schema.contains("code: zod.string().regex(/^z.[a-z]+$/),"));
+    }
+
+    #[test]
+    fn test_enums_use_custom_import() {
+        assert!(WindowStateJson::zod_schema().contains("= zod.enum([\"Open\", \"Closed\"])"));
+
+        let zod_schema = WindowEventJson::zod_schema();
+        assert!(zod_schema.contains("lib.z.discriminatedUnion(\"type\", ["));
+        assert!(zod_schema.contains("type: lib.z.literal(\"Opened\"),"));
+        assert!(zod_schema.contains("reason: lib.z.string().or(lib.z.undefined()),"));
+        assert!(!calls_default_import(&zod_schema));
+    }
+
+    #[test]
+    fn test_division_in_as_zod() {
+        let zod_schema = TillJson::zod_schema();
+
+        assert!(zod_schema.contains("fill: zod.number().refine((n) => n / 100 <= 1),"));
+        assert!(zod_schema.contains("cashier: zod.string(),"));
+        assert!(zod_schema.contains("ledger: zod.custom"));
+        assert!(!calls_default_import(&zod_schema));
+    }
+
+    #[test]
+    fn test_union_uses_first_member_import() {
+        model_schema_union!(WindowJson = WindowEventJson | WindowStateJson);
+
+        assert!(WindowJson::zod_schema().contains("= lib.z.union([WindowEvent$Schema, WindowState$Schema]);"));
+    }
+
+    #[test]
+    fn test_default_import_is_z() {
+        let zod_schema = TurnstileJson::zod_schema();
+
+        assert!(zod_schema.contains("= z.strictObject({"));
+        assert!(zod_schema.contains("gate: z.string(),"));
+    }
+
+    #[cfg(not(all(feature = "typescript", feature = "jsonschema")))]
+    #[model_schema(zod_import = "zod")]
+    #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[allow(dead_code)]
+    struct QueueJson {
+        next: Option<Box<QueueJson>>,
+    }
+
+    #[test]
+    #[cfg(not(all(feature = "typescript", feature = "jsonschema")))]
+    fn test_bundle_lazy_uses_custom_import() {
+        let bundle = tixschema::model_schema_bundle!(QueueJson);
+
+        assert!(bundle.contains("zod.lazy(() => Queue$Schema)"));
+        assert!(!calls_default_import(&bundle));
+    }
+}